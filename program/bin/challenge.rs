@@ -0,0 +1,615 @@
+use std::{
+    error::Error,
+    io, process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use challenge::{
+    audit::{audit_solutions, AuditOptions},
+    challenge_id,
+    cli::{
+        read_solutions, read_solutions_from_path, render_audit,
+        render_challenge, render_challenger, render_challengers,
+        render_challenges, render_plan, render_sent, OutputFormat,
+    },
+    client::{ChallengeClient, ChallengeClientError},
+    ixs::{self, AdmitChallengerIx, CreateChallengeOptions},
+    manifest::{self, Manifest, Plan},
+    state::{
+        Challenge, Challenger, Redeem, SolutionNormalization, SoulboundRedeem,
+    },
+};
+use clap::{crate_version, App, AppSettings, Arg, ArgMatches, SubCommand};
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+};
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+const DEFAULT_URL: &str = "http://localhost:8899";
+
+fn id_arg() -> Arg<'static, 'static> {
+    Arg::with_name("id")
+        .value_name("ID")
+        .required(true)
+        .help("Id of the challenge")
+}
+
+fn creator_arg() -> Arg<'static, 'static> {
+    Arg::with_name("creator")
+        .long("creator")
+        .value_name("PUBKEY")
+        .takes_value(true)
+        .help("Creator of the challenge [default: pubkey of the keypair]")
+}
+
+fn solutions_arg() -> Arg<'static, 'static> {
+    Arg::with_name("solutions")
+        .long("solutions")
+        .value_name("FILE")
+        .takes_value(true)
+        .help("File with one solution per line, reads stdin if omitted or '-'")
+}
+
+fn normalize_arg() -> Arg<'static, 'static> {
+    Arg::with_name("normalize")
+        .long("normalize")
+        .help("Normalizes solutions (NFKC, case and whitespace) before hashing")
+}
+
+fn manifest_arg() -> Arg<'static, 'static> {
+    Arg::with_name("manifest")
+        .value_name("MANIFEST")
+        .required(true)
+        .help("TOML or YAML manifest describing the challenge")
+}
+
+fn app() -> App<'static, 'static> {
+    App::new("challenge")
+        .version(crate_version!())
+        .about("Creates, solves and inspects challenges")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("url")
+                .long("url")
+                .short("u")
+                .value_name("URL")
+                .takes_value(true)
+                .global(true)
+                .default_value(DEFAULT_URL)
+                .help("JSON RPC URL of the cluster"),
+        )
+        .arg(
+            Arg::with_name("keypair")
+                .long("keypair")
+                .short("k")
+                .value_name("FILE")
+                .takes_value(true)
+                .global(true)
+                .help(
+                    "Keypair paying for and signing transactions [default: ~/.config/solana/id.json]",
+                ),
+        )
+        .arg(
+            Arg::with_name("output")
+                .long("output")
+                .short("o")
+                .value_name("FORMAT")
+                .takes_value(true)
+                .global(true)
+                .possible_values(&["text", "json"])
+                .default_value("text")
+                .help("Format in which PDAs and state are printed"),
+        )
+        .subcommand(
+            SubCommand::with_name("create")
+                .about("Creates a challenge with the keypair as creator")
+                .arg(id_arg())
+                .arg(
+                    Arg::with_name("admit_cost")
+                        .long("admit-cost")
+                        .value_name("LAMPORTS")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Lamports each challenger pays to be admitted"),
+                )
+                .arg(
+                    Arg::with_name("tries_per_admit")
+                        .long("tries-per-admit")
+                        .value_name("TRIES")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Solutions a challenger can send per admission"),
+                )
+                .arg(solutions_arg())
+                .arg(normalize_arg())
+                .arg(Arg::with_name("soulbound").long("soulbound").help(
+                    "Mints non-transferable Token-2022 redeem tokens",
+                )),
+        )
+        .subcommand(
+            SubCommand::with_name("add-solutions")
+                .about("Adds solutions to a challenge of the keypair")
+                .arg(id_arg())
+                .arg(solutions_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("start")
+                .about("Starts a challenge of the keypair")
+                .arg(id_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("admit")
+                .about("Admits a challenger, paying the admit cost with the keypair")
+                .arg(id_arg())
+                .arg(creator_arg())
+                .arg(
+                    Arg::with_name("challenger")
+                        .long("challenger")
+                        .value_name("PUBKEY")
+                        .takes_value(true)
+                        .help("Challenger to admit [default: pubkey of the keypair]"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("redeem")
+                .about("Sends a solution for the current solution as the keypair")
+                .arg(id_arg())
+                .arg(creator_arg())
+                .arg(
+                    Arg::with_name("solution")
+                        .value_name("SOLUTION")
+                        .help("Solution in clear text, reads the first line of stdin if omitted"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("show")
+                .about("Shows the state of a challenge or one of its challengers")
+                .arg(id_arg())
+                .arg(creator_arg())
+                .arg(
+                    Arg::with_name("challenger")
+                        .long("challenger")
+                        .value_name("PUBKEY")
+                        .takes_value(true)
+                        .help("Shows the challenger account of this wallet instead"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("plan")
+                .about("Shows the changes needed to bring a challenge of the keypair in line with a manifest")
+                .arg(manifest_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("apply")
+                .about("Sends the instructions needed to bring a challenge of the keypair in line with a manifest")
+                .arg(manifest_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("audit")
+                .about("Reports solutions that are easy to crack from their public hashes")
+                .arg(solutions_arg())
+                .arg(normalize_arg())
+                .arg(
+                    Arg::with_name("min_entropy")
+                        .long("min-entropy")
+                        .value_name("BITS")
+                        .takes_value(true)
+                        .help("Solutions with less estimated entropy are weak [default: 48]"),
+                )
+                .arg(
+                    Arg::with_name("max_length")
+                        .long("max-length")
+                        .value_name("CHARS")
+                        .takes_value(true)
+                        .help("Brute forces solutions up to this many characters [default: 4]"),
+                )
+                .arg(Arg::with_name("deny_weak").long("deny-weak").help(
+                    "Fails if any solution is weak",
+                )),
+        )
+        .subcommand(
+            SubCommand::with_name("list")
+                .about("Lists the challenges of a creator or the challengers of one challenge")
+                .arg(
+                    Arg::with_name("id")
+                        .value_name("ID")
+                        .help("Lists the challengers of this challenge instead"),
+                )
+                .arg(creator_arg()),
+        )
+}
+
+fn main() {
+    if let Err(err) = run(app().get_matches()) {
+        eprintln!("Error: {}", err);
+        process::exit(1);
+    }
+}
+
+/// Settings shared by all subcommands.
+struct Context {
+    client: ChallengeClient,
+    keypair_path: Option<String>,
+    format: OutputFormat,
+}
+
+impl Context {
+    /// Reads the keypair only once a command needs it, so that inspecting challenges
+    /// of another creator works without one.
+    fn keypair(&self) -> CliResult<Keypair> {
+        let path = match &self.keypair_path {
+            Some(path) => path.to_string(),
+            None => format!(
+                "{}/.config/solana/id.json",
+                std::env::var("HOME").unwrap_or_else(|_| ".".to_string())
+            ),
+        };
+        read_keypair_file(&path).map_err(|err| {
+            format!("Failed to read keypair {}: {}", path, err).into()
+        })
+    }
+
+    /// The creator provided via `--creator`, falling back to the keypair.
+    fn creator(&self, matches: &ArgMatches) -> CliResult<Pubkey> {
+        match pubkey_of(matches, "creator")? {
+            Some(creator) => Ok(creator),
+            None => Ok(self.keypair()?.pubkey()),
+        }
+    }
+}
+
+fn run(matches: ArgMatches) -> CliResult<()> {
+    let (command, sub) = matches.subcommand();
+    let sub = sub.expect("subcommand is required");
+
+    let url = sub.value_of("url").unwrap_or(DEFAULT_URL);
+    let ctx = Context {
+        client: ChallengeClient::new(RpcClient::new_with_commitment(
+            url.to_string(),
+            CommitmentConfig::confirmed(),
+        )),
+        keypair_path: sub.value_of("keypair").map(String::from),
+        format: sub.value_of("output").unwrap_or("text").parse()?,
+    };
+
+    let output = match command {
+        "create" => create(&ctx, sub)?,
+        "add-solutions" => add_solutions(&ctx, sub)?,
+        "start" => start(&ctx, sub)?,
+        "admit" => admit(&ctx, sub)?,
+        "redeem" => redeem(&ctx, sub)?,
+        "show" => show(&ctx, sub)?,
+        "plan" => plan(&ctx, sub)?,
+        "apply" => apply(&ctx, sub)?,
+        "audit" => audit(&ctx, sub)?,
+        "list" => list(&ctx, sub)?,
+        _ => unreachable!("unknown subcommand {}", command),
+    };
+    print!("{}", output);
+    if ctx.format == OutputFormat::Json {
+        println!();
+    }
+    Ok(())
+}
+
+// -----------------
+// Commands
+// -----------------
+fn create(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let id = matches.value_of("id").unwrap().to_string();
+    let admit_cost = matches.value_of("admit_cost").unwrap().parse()?;
+    let tries_per_admit =
+        matches.value_of("tries_per_admit").unwrap().parse()?;
+    let solutions = read_solutions_from_path(matches.value_of("solutions"))?;
+    let normalization = normalization_of(matches);
+    warn_weak_solutions(&solutions, &normalization);
+    let soulbound = matches
+        .is_present("soulbound")
+        .then(SoulboundRedeem::default);
+
+    let creator = keypair.pubkey();
+    let ixs = ixs::create_challenge_chunked(
+        creator,
+        creator,
+        id.clone(),
+        admit_cost,
+        tries_per_admit,
+        solutions.iter().map(String::as_str).collect(),
+        CreateChallengeOptions {
+            normalization,
+            soulbound,
+            ..Default::default()
+        },
+    )?;
+    let signatures = ixs
+        .into_iter()
+        .map(|ix| ctx.client.send(&[ix], &keypair, &[]))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, &id);
+    let redeem = Redeem::new(challenge_pda).pda;
+    Ok(render_sent(
+        &[("challenge", challenge_pda), ("redeem", redeem)],
+        &signatures,
+        ctx.format,
+    ))
+}
+
+fn add_solutions(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let id = matches.value_of("id").unwrap();
+    let solutions = read_solutions_from_path(matches.value_of("solutions"))?;
+
+    let challenge = ctx.client.get_challenge_for(&keypair.pubkey(), id)?;
+    warn_weak_solutions(&solutions, &challenge.normalization);
+    let ix = ixs::add_challenge_solutions(
+        keypair.pubkey(),
+        &challenge,
+        solutions.iter().map(String::as_str).collect(),
+    )?;
+    let signature = ctx.client.send(&[ix], &keypair, &[])?;
+    Ok(render_sent(
+        &[("challenge", Challenge::pda_for(&keypair.pubkey(), id).0)],
+        &[signature],
+        ctx.format,
+    ))
+}
+
+fn start(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let id = matches.value_of("id").unwrap().to_string();
+    let (challenge_pda, _) = Challenge::pda_for(&keypair.pubkey(), &id);
+
+    let ix = ixs::start_challenge(keypair.pubkey(), id)?;
+    let signature = ctx.client.send(&[ix], &keypair, &[])?;
+    Ok(render_sent(
+        &[("challenge", challenge_pda)],
+        &[signature],
+        ctx.format,
+    ))
+}
+
+fn admit(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let id = matches.value_of("id").unwrap();
+    let creator = ctx.creator(matches)?;
+    let challenger =
+        pubkey_of(matches, "challenger")?.unwrap_or_else(|| keypair.pubkey());
+
+    let challenge = ctx.client.get_challenge_for(&creator, id)?;
+    let required_token_program = challenge
+        .required_mint
+        .map(|mint| ctx.client.get_token_program_of_mint(&mint))
+        .transpose()?;
+    let mut admit_ix = ixs::admit_challenger(
+        keypair.pubkey(),
+        &challenge,
+        challenger,
+        required_token_program,
+    )?;
+    if let Some(config) = ctx.client.get_program_config()? {
+        admit_ix = admit_ix.with_protocol_fee(&config);
+    }
+    let AdmitChallengerIx {
+        ix,
+        challenge_pda,
+        challenger_pda,
+        ..
+    } = admit_ix;
+    let signature = ctx.client.send(&[ix], &keypair, &[])?;
+    Ok(render_sent(
+        &[("challenge", challenge_pda), ("challenger", challenger_pda)],
+        &[signature],
+        ctx.format,
+    ))
+}
+
+fn redeem(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let id = matches.value_of("id").unwrap();
+    let creator = ctx.creator(matches)?;
+    let solution = match matches.value_of("solution") {
+        Some(solution) => solution.to_string(),
+        None => read_solutions(io::stdin().lock())?
+            .into_iter()
+            .next()
+            .ok_or("No solution provided via stdin")?,
+    };
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, id);
+    let signature =
+        ctx.client
+            .redeem(&keypair, &challenge_pda, &keypair, &solution)?;
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge_id(),
+        &challenge_pda,
+        &keypair.pubkey(),
+    );
+    Ok(render_sent(
+        &[("challenge", challenge_pda), ("challenger", challenger_pda)],
+        &[signature],
+        ctx.format,
+    ))
+}
+
+fn show(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let id = matches.value_of("id").unwrap();
+    let creator = ctx.creator(matches)?;
+    let (challenge_pda, _) = Challenge::pda_for(&creator, id);
+
+    match pubkey_of(matches, "challenger")? {
+        Some(wallet) => {
+            let (challenger_pda, _) =
+                Challenger::shank_pda(&challenge_id(), &challenge_pda, &wallet);
+            let challenger = ctx
+                .client
+                .get_challenger(&challenge_pda, &wallet)?
+                .ok_or_else(|| {
+                    format!("{} was not admitted to the challenge", wallet)
+                })?;
+            Ok(render_challenger(&challenger_pda, &challenger, ctx.format))
+        }
+        None => {
+            let challenge = ctx.client.get_challenge(&challenge_pda)?;
+            Ok(render_challenge(&challenge, ctx.format))
+        }
+    }
+}
+
+fn list(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let creator = ctx.creator(matches)?;
+    match matches.value_of("id") {
+        Some(id) => {
+            let (challenge_pda, _) = Challenge::pda_for(&creator, id);
+            let challengers = ctx.client.find_challengers(&challenge_pda)?;
+            Ok(render_challengers(&challengers, ctx.format))
+        }
+        None => {
+            let challenges = ctx.client.find_challenges_by_creator(&creator)?;
+            Ok(render_challenges(&challenges, ctx.format))
+        }
+    }
+}
+
+fn plan_manifest(
+    ctx: &Context,
+    keypair: &Keypair,
+    matches: &ArgMatches,
+) -> CliResult<Plan> {
+    let manifest = Manifest::from_path(matches.value_of("manifest").unwrap())?;
+    let solutions = manifest.read_solutions()?;
+
+    let creator = keypair.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, &manifest.id);
+    let on_chain = match ctx.client.get_challenge(&challenge_pda) {
+        Ok(challenge) => Some(challenge),
+        Err(ChallengeClientError::AccountNotFound(_)) => None,
+        Err(err) => return Err(err.into()),
+    };
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+
+    Ok(manifest::plan(
+        &manifest,
+        &solutions,
+        creator,
+        creator,
+        on_chain.as_ref(),
+        now,
+    )?)
+}
+
+fn plan(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let plan = plan_manifest(ctx, &keypair, matches)?;
+    Ok(render_plan(&plan, ctx.format))
+}
+
+fn apply(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let keypair = ctx.keypair()?;
+    let plan = plan_manifest(ctx, &keypair, matches)?;
+    if !plan.conflicts.is_empty() {
+        return Err(format!(
+            "Manifest conflicts with the challenge on chain:\n{}",
+            render_plan(&plan, OutputFormat::Text)
+        )
+        .into());
+    }
+
+    let signatures = plan
+        .instructions
+        .iter()
+        .map(|ix| ctx.client.send(&[ix.clone()], &keypair, &[]))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(match ctx.format {
+        OutputFormat::Text => format!(
+            "{}{}",
+            render_plan(&plan, ctx.format),
+            render_sent(&[], &signatures, ctx.format)
+        ),
+        OutputFormat::Json => render_sent(&[], &signatures, ctx.format),
+    })
+}
+
+fn audit(ctx: &Context, matches: &ArgMatches) -> CliResult<String> {
+    let solutions = read_solutions_from_path(matches.value_of("solutions"))?;
+    let mut options = AuditOptions {
+        normalization: normalization_of(matches),
+        ..Default::default()
+    };
+    if let Some(min_entropy) = matches.value_of("min_entropy") {
+        options.min_entropy_bits = min_entropy.parse()?;
+    }
+    if let Some(max_length) = matches.value_of("max_length") {
+        options.brute_force_max_len = max_length.parse()?;
+    }
+
+    let audits = audit_solutions(
+        &solutions.iter().map(String::as_str).collect::<Vec<_>>(),
+        &options,
+    );
+    let weak = audits.iter().filter(|audit| audit.is_weak()).count();
+    if weak > 0 && matches.is_present("deny_weak") {
+        return Err(format!(
+            "{} of {} solutions are weak:\n{}",
+            weak,
+            audits.len(),
+            render_audit(&audits, OutputFormat::Text)
+        )
+        .into());
+    }
+    Ok(render_audit(&audits, ctx.format))
+}
+
+// -----------------
+// Helpers
+// -----------------
+fn normalization_of(matches: &ArgMatches) -> SolutionNormalization {
+    if matches.is_present("normalize") {
+        SolutionNormalization {
+            nfkc: true,
+            case_fold: true,
+            trim_whitespace: true,
+            collapse_whitespace: true,
+        }
+    } else {
+        SolutionNormalization::default()
+    }
+}
+
+/// Reports weak solutions on stderr before they are added to a challenge.
+fn warn_weak_solutions(
+    solutions: &[String],
+    normalization: &SolutionNormalization,
+) {
+    let options = AuditOptions {
+        normalization: *normalization,
+        ..Default::default()
+    };
+    let audits = audit_solutions(
+        &solutions.iter().map(String::as_str).collect::<Vec<_>>(),
+        &options,
+    );
+    for audit in audits.iter().filter(|audit| audit.is_weak()) {
+        eprint!(
+            "Warning: solution {}",
+            render_audit(&[audit.clone()], OutputFormat::Text)
+        );
+    }
+}
+
+fn pubkey_of(matches: &ArgMatches, name: &str) -> CliResult<Option<Pubkey>> {
+    matches
+        .value_of(name)
+        .map(|value| {
+            value.parse::<Pubkey>().map_err(|err| {
+                format!("Invalid {} '{}': {}", name, value, err).into()
+            })
+        })
+        .transpose()
+}
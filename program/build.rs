@@ -0,0 +1,356 @@
+//! Generates typed accounts and instruction builders from the `#[account]` attributes
+//! of `ChallengeInstruction` so that the account metas sent by clients cannot drift from
+//! the accounts the program declares.
+//!
+//! For each instruction variant a `<Variant>Accounts` struct is emitted with one field per
+//! account, `Option<Pubkey>` for optional ones, which renders its `AccountMeta`s in the
+//! declared order and builds the instruction from the variant's fields.
+//! Optional accounts that are not provided are passed as the program id so that every
+//! account is found at its declared index. Accounts that are not declared, i.e. fee
+//! recipients, follow after the declared ones.
+//! The output is included by `src/ixs.rs`.
+
+use std::{env, fmt::Write as _, fs, path::Path};
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Attribute, Fields, Item, ItemEnum, LitInt, LitStr, Variant};
+
+const IXS_PATH: &str = "src/ixs.rs";
+const INSTRUCTION_ENUM: &str = "ChallengeInstruction";
+
+struct AccountAttr {
+    index: usize,
+    name: String,
+    writable: bool,
+    signer: bool,
+    optional: bool,
+    desc: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", IXS_PATH);
+
+    let src = fs::read_to_string(IXS_PATH)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", IXS_PATH, err));
+    let file = syn::parse_file(&src)
+        .unwrap_or_else(|err| panic!("Failed to parse {}: {}", IXS_PATH, err));
+    let instruction = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(item) if item.ident == INSTRUCTION_ENUM => Some(item),
+            _ => None,
+        })
+        .unwrap_or_else(|| {
+            panic!("{} not found in {}", INSTRUCTION_ENUM, IXS_PATH)
+        });
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(
+        Path::new(&out_dir).join("ixs_accounts.rs"),
+        render_instruction(instruction),
+    )
+    .expect("Failed to write generated accounts");
+}
+
+// -----------------
+// Parse
+// -----------------
+fn account_attrs(variant: &Variant) -> Vec<AccountAttr> {
+    let attrs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("account"))
+        .map(|attr| parse_account_attr(variant, attr))
+        .collect::<Vec<_>>();
+
+    for (idx, attr) in attrs.iter().enumerate() {
+        if attr.index != idx {
+            panic!(
+                "{}::{} declares account '{}' at index {}, expected {}",
+                INSTRUCTION_ENUM, variant.ident, attr.name, attr.index, idx
+            );
+        }
+        if attrs[..idx].iter().any(|x| x.name == attr.name) {
+            panic!(
+                "{}::{} declares account '{}' more than once",
+                INSTRUCTION_ENUM, variant.ident, attr.name
+            );
+        }
+    }
+    attrs
+}
+
+/// Parses `#[account(0, name = "payer", mut, sig, optional, desc = "...")]`.
+fn parse_account_attr(variant: &Variant, attr: &Attribute) -> AccountAttr {
+    let fail = |msg: &str| -> ! {
+        panic!(
+            "Invalid #[account] attribute of {}::{}: {}",
+            INSTRUCTION_ENUM, variant.ident, msg
+        )
+    };
+
+    let args = match attr.tokens.clone().into_iter().next() {
+        Some(TokenTree::Group(group)) => group.stream(),
+        _ => fail("expected arguments in parentheses"),
+    };
+
+    let mut index = None;
+    let mut name = None;
+    let mut desc = String::new();
+    let (mut writable, mut signer, mut optional) = (false, false, false);
+    for arg in split_args(args) {
+        match arg.as_slice() {
+            [TokenTree::Literal(lit)] => {
+                let lit = syn::parse2::<LitInt>(lit.to_token_stream())
+                    .unwrap_or_else(|_| fail("expected the account index"));
+                index = Some(lit.base10_parse().unwrap_or_else(|_| {
+                    fail("account index needs to be a number")
+                }));
+            }
+            [TokenTree::Ident(ident)] => match ident.to_string().as_str() {
+                "mut" | "writable" | "write" => writable = true,
+                "sig" | "signer" | "sign" => signer = true,
+                "optional" | "option" | "opt" => optional = true,
+                other => fail(&format!("unknown flag '{}'", other)),
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(eq), TokenTree::Literal(lit)]
+                if eq.as_char() == '=' =>
+            {
+                let value = syn::parse2::<LitStr>(lit.to_token_stream())
+                    .unwrap_or_else(|_| fail("expected a string value"))
+                    .value();
+                match key.to_string().as_str() {
+                    "name" => name = Some(value),
+                    "desc" | "description" => desc = value,
+                    other => fail(&format!("unknown key '{}'", other)),
+                }
+            }
+            _ => fail("unexpected argument"),
+        }
+    }
+
+    AccountAttr {
+        index: index.unwrap_or_else(|| fail("missing account index")),
+        name: name.unwrap_or_else(|| fail("missing account name")),
+        writable,
+        signer,
+        optional,
+        desc,
+    }
+}
+
+fn split_args(tokens: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut args = vec![vec![]];
+    for tt in tokens {
+        match &tt {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                args.push(vec![])
+            }
+            _ => args.last_mut().unwrap().push(tt),
+        }
+    }
+    args.retain(|arg| !arg.is_empty());
+    args
+}
+
+// -----------------
+// Render
+// -----------------
+fn render_instruction(instruction: &ItemEnum) -> String {
+    let mut s = String::from(
+        "// Generated by build.rs from the #[account] attributes of ChallengeInstruction.\n",
+    );
+    let mut match_arms = String::new();
+    let mut name_arms = String::new();
+
+    for variant in &instruction.variants {
+        let attrs = account_attrs(variant);
+        let accounts = format!("{}Accounts", variant.ident);
+        render_accounts(&mut s, variant, &accounts, &attrs);
+
+        let pattern = match variant.fields {
+            Fields::Unit => "",
+            Fields::Named(_) => " { .. }",
+            Fields::Unnamed(_) => "(..)",
+        };
+        writeln!(
+            match_arms,
+            "            {}::{}{} => {}::ATTRS,",
+            INSTRUCTION_ENUM, variant.ident, pattern, accounts
+        )
+        .unwrap();
+        writeln!(
+            name_arms,
+            "            {}::{}{} => {:?},",
+            INSTRUCTION_ENUM,
+            variant.ident,
+            pattern,
+            variant.ident.to_string()
+        )
+        .unwrap();
+    }
+
+    write!(
+        s,
+        r#"
+impl {instruction} {{
+    /// The name of the instruction variant.
+    pub fn name(&self) -> &'static str {{
+        match self {{
+{name_arms}        }}
+    }}
+
+    /// The accounts of the instruction as declared by its `#[account]` attributes.
+    pub fn account_attrs(&self) -> &'static [crate::shank_utils::ShankAccountAttr] {{
+        match self {{
+{match_arms}        }}
+    }}
+}}
+"#,
+        instruction = INSTRUCTION_ENUM,
+        match_arms = match_arms,
+        name_arms = name_arms,
+    )
+    .unwrap();
+    s
+}
+
+fn render_accounts(
+    s: &mut String,
+    variant: &Variant,
+    accounts: &str,
+    attrs: &[AccountAttr],
+) {
+    let ix = format!("{}::{}", INSTRUCTION_ENUM, variant.ident);
+
+    // struct
+    writeln!(
+        s,
+        "\n/// Accounts of [{}] as declared by its `#[account]` attributes.",
+        ix
+    )
+    .unwrap();
+    writeln!(s, "#[derive(Clone, Copy, Debug, PartialEq, Eq)]").unwrap();
+    writeln!(s, "pub struct {} {{", accounts).unwrap();
+    for attr in attrs {
+        if !attr.desc.is_empty() {
+            writeln!(s, "    /// {}", attr.desc).unwrap();
+        }
+        let ty = if attr.optional {
+            "Option<::solana_program::pubkey::Pubkey>"
+        } else {
+            "::solana_program::pubkey::Pubkey"
+        };
+        writeln!(s, "    pub {}: {},", attr.name, ty).unwrap();
+    }
+    writeln!(s, "}}").unwrap();
+
+    writeln!(s, "\nimpl {} {{", accounts).unwrap();
+
+    // attrs
+    writeln!(
+        s,
+        "    pub const ATTRS: &'static [crate::shank_utils::ShankAccountAttr] = &["
+    )
+    .unwrap();
+    for attr in attrs {
+        writeln!(
+            s,
+            "        crate::shank_utils::ShankAccountAttr {{ index: {}, name: {:?}, writable: {}, signer: {}, optional: {}, desc: {:?} }},",
+            attr.index, attr.name, attr.writable, attr.signer, attr.optional, attr.desc
+        )
+        .unwrap();
+    }
+    writeln!(s, "    ];").unwrap();
+
+    // account metas
+    let meta = |attr: &AccountAttr, pubkey: &str| {
+        format!(
+            "::solana_program::instruction::AccountMeta::{}({}, {})",
+            if attr.writable { "new" } else { "new_readonly" },
+            pubkey,
+            attr.signer
+        )
+    };
+    writeln!(
+        s,
+        "\n    /// The account metas in the declared order, passing the program id in place of\n    /// optional accounts that are `None`."
+    )
+    .unwrap();
+    writeln!(
+        s,
+        "    pub fn to_account_metas(&self) -> Vec<::solana_program::instruction::AccountMeta> {{"
+    )
+    .unwrap();
+    writeln!(s, "        vec![").unwrap();
+    for attr in attrs {
+        if attr.optional {
+            writeln!(
+                s,
+                "            match self.{} {{\n                Some(pubkey) => {},\n                None => ::solana_program::instruction::AccountMeta::new_readonly(crate::challenge_id(), false),\n            }},",
+                attr.name,
+                meta(attr, "pubkey")
+            )
+            .unwrap();
+        } else {
+            writeln!(
+                s,
+                "            {},",
+                meta(attr, &format!("self.{}", attr.name))
+            )
+            .unwrap();
+        }
+    }
+    writeln!(s, "        ]").unwrap();
+    writeln!(s, "    }}").unwrap();
+
+    // instruction builder
+    let (params, data) = match &variant.fields {
+        Fields::Unit => (String::new(), ix.clone()),
+        Fields::Named(fields) => {
+            let names = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap().to_string())
+                .collect::<Vec<_>>();
+            let params = fields
+                .named
+                .iter()
+                .zip(&names)
+                .map(|(field, name)| {
+                    format!(", {}: {}", name, field.ty.to_token_stream())
+                })
+                .collect::<String>();
+            (params, format!("{} {{ {} }}", ix, names.join(", ")))
+        }
+        Fields::Unnamed(_) => {
+            panic!("{} needs to have named fields to generate its builder", ix)
+        }
+    };
+    writeln!(
+        s,
+        "\n    /// Builds the [{}] instruction with these accounts.",
+        ix
+    )
+    .unwrap();
+    if variant.fields.len() > 6 {
+        writeln!(s, "    #[allow(clippy::too_many_arguments)]").unwrap();
+    }
+    writeln!(
+        s,
+        r#"    pub fn instruction(&self{params}) -> Result<::solana_program::instruction::Instruction, ::solana_program::program_error::ProgramError> {{
+        Ok(::solana_program::instruction::Instruction {{
+            program_id: crate::challenge_id(),
+            accounts: self.to_account_metas(),
+            data: ::borsh::BorshSerialize::try_to_vec(&{data})?,
+        }})
+    }}
+}}"#,
+        params = params,
+        data = data,
+    )
+    .unwrap();
+}
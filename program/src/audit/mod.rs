@@ -0,0 +1,465 @@
+//! Audits solutions in clear text before a creator hashes them into a challenge.
+//!
+//! Without [crate::state::SolutionHashing] the stored solutions are public
+//! `sha256(sha256(solution))` hashes which anyone can attack offline, so short answers,
+//! dictionary words and common patterns are cracked in seconds.
+//! Each solution is normalized the way the challenge would, its entropy is estimated
+//! and a bounded brute force is run against its stored hash.
+//!
+//! Only available off-chain, the `audit` command of the CLI renders its results.
+
+use std::fmt;
+
+use solana_program::hash::hash;
+
+use crate::{
+    state::SolutionNormalization,
+    utils::{hash_solutions, normalize_solution},
+    Solution,
+};
+
+const PASSWORDS: &str = include_str!("passwords.txt");
+const WORDS: &str = include_str!("words.txt");
+
+const KEYBOARD_ROWS: [&str; 5] = [
+    "qwertyuiop",
+    "asdfghjkl",
+    "zxcvbnm",
+    "1234567890",
+    "qazwsxedcrfvtgbyhnujmikolp",
+];
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const PUNCTUATION: &str = " !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// Solutions with less estimated entropy are considered weak.
+pub const DEFAULT_MIN_ENTROPY_BITS: f64 = 48.0;
+
+/// Guesses per second of an attacker hashing candidates on a single GPU.
+pub const DEFAULT_GUESSES_PER_SECOND: f64 = 1e9;
+
+/// Configures how solutions are audited.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditOptions {
+    /// The normalization the challenge applies before hashing solutions.
+    pub normalization: SolutionNormalization,
+    /// Solutions with less estimated entropy are reported as weak.
+    pub min_entropy_bits: f64,
+    /// Solutions up to this many characters are brute forced.
+    pub brute_force_max_len: usize,
+    /// Upper bound of candidates hashed while brute forcing one solution.
+    pub brute_force_max_tries: u64,
+    /// Guesses per second assumed when estimating the time to crack a solution.
+    pub guesses_per_second: f64,
+}
+
+impl Default for AuditOptions {
+    fn default() -> Self {
+        Self {
+            normalization: SolutionNormalization::default(),
+            min_entropy_bits: DEFAULT_MIN_ENTROPY_BITS,
+            brute_force_max_len: 4,
+            brute_force_max_tries: 1_000_000,
+            guesses_per_second: DEFAULT_GUESSES_PER_SECOND,
+        }
+    }
+}
+
+/// A pattern which makes a solution easier to guess than its length suggests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    /// A character or short sequence repeated, i.e. `aaaa` or `abcabc`.
+    Repeated,
+    /// Consecutive characters, i.e. `abcde` or `54321`.
+    Sequence,
+    /// Adjacent keys on a keyboard, i.e. `asdfgh`.
+    Keyboard,
+    /// A year or a date, i.e. `1999` or `20231224`.
+    Date,
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Repeated => write!(f, "repeated characters"),
+            Pattern::Sequence => write!(f, "sequence of characters"),
+            Pattern::Keyboard => write!(f, "keyboard pattern"),
+            Pattern::Date => write!(f, "date"),
+        }
+    }
+}
+
+/// Something found while auditing a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Finding {
+    /// The solution is empty once normalized.
+    Empty,
+    /// The solution normalizes to the same as the one at [index].
+    Duplicate { index: usize },
+    /// The solution is one of the bundled common passwords.
+    CommonPassword,
+    /// The solution consists of [words] of the bundled dictionaries.
+    DictionaryWords { words: usize },
+    /// The solution matches a [Pattern].
+    Pattern(Pattern),
+    /// The estimated entropy is below the configured minimum.
+    LowEntropy { bits: f64, min_bits: f64 },
+    /// The stored hash of the solution was cracked after [tries] candidates.
+    BruteForced { tries: u64 },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::Empty => write!(f, "empty once normalized"),
+            Finding::Duplicate { index } => {
+                write!(f, "same as solution {} once normalized", index)
+            }
+            Finding::CommonPassword => write!(f, "common password"),
+            Finding::DictionaryWords { words } => {
+                write!(f, "{} dictionary word(s)", words)
+            }
+            Finding::Pattern(pattern) => write!(f, "{}", pattern),
+            Finding::LowEntropy { bits, min_bits } => write!(
+                f,
+                "{:.1} bits of entropy, less than {:.1}",
+                bits, min_bits
+            ),
+            Finding::BruteForced { tries } => {
+                write!(f, "brute forced after {} tries", tries)
+            }
+        }
+    }
+}
+
+/// The result of auditing one solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolutionAudit {
+    /// The index of the solution in the audited solutions.
+    pub index: usize,
+    /// Estimated entropy of the normalized solution taking dictionaries and patterns
+    /// into account.
+    pub entropy_bits: f64,
+    /// Estimated seconds it takes on average to guess the solution.
+    pub crack_seconds: f64,
+    pub findings: Vec<Finding>,
+}
+
+impl SolutionAudit {
+    /// Weak solutions should not be used for a challenge.
+    /// Dictionary words and patterns only make a solution weak if they reduce its
+    /// entropy below the minimum.
+    pub fn is_weak(&self) -> bool {
+        self.findings.iter().any(|finding| {
+            matches!(
+                finding,
+                Finding::Empty
+                    | Finding::Duplicate { .. }
+                    | Finding::LowEntropy { .. }
+                    | Finding::BruteForced { .. }
+            )
+        })
+    }
+}
+
+/// Audits the [solutions] a creator is about to add to a challenge, see [audit_solution].
+/// Solutions that normalize to the same as a previous one are reported as duplicates.
+pub fn audit_solutions(
+    solutions: &[&str],
+    options: &AuditOptions,
+) -> Vec<SolutionAudit> {
+    let normalized = solutions
+        .iter()
+        .map(|s| normalize_solution(s, &options.normalization))
+        .collect::<Vec<_>>();
+
+    solutions
+        .iter()
+        .enumerate()
+        .map(|(index, solution)| {
+            let mut audit = audit_solution(solution, options);
+            audit.index = index;
+            if let Some(first) = normalized[..index]
+                .iter()
+                .position(|s| *s == normalized[index])
+            {
+                audit
+                    .findings
+                    .insert(0, Finding::Duplicate { index: first });
+            }
+            audit
+        })
+        .collect()
+}
+
+/// Audits a single [solution] in clear text.
+///
+/// The entropy of the normalized solution is estimated from the characters it uses,
+/// lowered if it consists of dictionary words or matches a [Pattern].
+/// Solutions up to [AuditOptions::brute_force_max_len] characters are brute forced
+/// against their stored hash.
+pub fn audit_solution(solution: &str, options: &AuditOptions) -> SolutionAudit {
+    let normalized = normalize_solution(solution, &options.normalization);
+    let mut findings = vec![];
+
+    if normalized.is_empty() {
+        return SolutionAudit {
+            index: 0,
+            entropy_bits: 0.0,
+            crack_seconds: 0.0,
+            findings: vec![Finding::Empty],
+        };
+    }
+
+    let mut entropy_bits = charset_bits(&normalized);
+    if let Some((bits, finding)) = dictionary_bits(&normalized) {
+        entropy_bits = entropy_bits.min(bits);
+        findings.push(finding);
+    }
+    if let Some((bits, pattern)) = pattern_bits(&normalized) {
+        entropy_bits = entropy_bits.min(bits);
+        findings.push(Finding::Pattern(pattern));
+    }
+    if entropy_bits < options.min_entropy_bits {
+        findings.push(Finding::LowEntropy {
+            bits: entropy_bits,
+            min_bits: options.min_entropy_bits,
+        });
+    }
+
+    let stored = hash_solutions(&[solution], &options.normalization)[0];
+    if let Some(tries) = brute_force(&normalized, &stored, options) {
+        findings.push(Finding::BruteForced { tries });
+    }
+
+    SolutionAudit {
+        index: 0,
+        entropy_bits,
+        crack_seconds: 2f64.powf(entropy_bits - 1.0)
+            / options.guesses_per_second,
+        findings,
+    }
+}
+
+// -----------------
+// Entropy
+// -----------------
+
+/// Characters an attacker would have to try for each character of [s].
+fn pool_size(s: &str) -> f64 {
+    let has = |set: &str| s.chars().any(|c| set.contains(c));
+    let mut pool = 0;
+    if has(LOWERCASE) {
+        pool += LOWERCASE.len();
+    }
+    if has(UPPERCASE) {
+        pool += UPPERCASE.len();
+    }
+    if has(DIGITS) {
+        pool += DIGITS.len();
+    }
+    if has(PUNCTUATION) {
+        pool += PUNCTUATION.len();
+    }
+    if !s.is_ascii() {
+        pool += 100;
+    }
+    pool as f64
+}
+
+fn charset_bits(s: &str) -> f64 {
+    s.chars().count() as f64 * pool_size(s).log2()
+}
+
+fn is_common_password(s: &str) -> bool {
+    PASSWORDS.lines().any(|password| password == s)
+}
+
+fn is_word(s: &str) -> bool {
+    WORDS.lines().any(|word| word == s) || is_common_password(s)
+}
+
+/// Estimates the entropy of [s] if it consists only of words of the bundled
+/// dictionaries, each optionally followed by digits and punctuation.
+fn dictionary_bits(s: &str) -> Option<(f64, Finding)> {
+    let lowercase = s.to_lowercase();
+    let case_bits = if lowercase != s { 1.0 } else { 0.0 };
+
+    if is_common_password(&lowercase) {
+        return Some((
+            (PASSWORDS.lines().count() as f64).log2() + case_bits,
+            Finding::CommonPassword,
+        ));
+    }
+
+    let dictionary_size =
+        (PASSWORDS.lines().count() + WORDS.lines().count()) as f64;
+    let mut bits = case_bits;
+    let mut words = 0;
+    for token in lowercase
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|token| !token.is_empty())
+    {
+        let core = token.trim_end_matches(|c: char| {
+            c.is_ascii_digit() || PUNCTUATION.contains(c)
+        });
+        if !core.is_empty() {
+            if !is_word(core) {
+                return None;
+            }
+            bits += dictionary_size.log2();
+            words += 1;
+        }
+        for c in token[core.len()..].chars() {
+            bits += if c.is_ascii_digit() {
+                (DIGITS.len() as f64).log2()
+            } else {
+                (PUNCTUATION.len() as f64).log2()
+            };
+        }
+    }
+
+    if words == 0 {
+        None
+    } else {
+        Some((bits, Finding::DictionaryWords { words }))
+    }
+}
+
+/// Estimates the entropy of [s] if it matches a [Pattern].
+fn pattern_bits(s: &str) -> Option<(f64, Pattern)> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let len = chars.len();
+    let len_bits = (len as f64).log2();
+
+    if let Some(period) = (1..len)
+        .filter(|period| len % period == 0)
+        .find(|period| chars.chunks(*period).all(|c| c == &chars[..*period]))
+    {
+        let unit = chars[..period].iter().collect::<String>();
+        return Some((charset_bits(&unit) + len_bits, Pattern::Repeated));
+    }
+
+    if len >= 3 {
+        let step = chars[1] as i64 - chars[0] as i64;
+        if step.abs() == 1
+            && chars.windows(2).all(|w| w[1] as i64 - w[0] as i64 == step)
+        {
+            return Some((
+                pool_size(s).log2() + len_bits + 1.0,
+                Pattern::Sequence,
+            ));
+        }
+    }
+
+    if len >= 4 {
+        let lowercase = s.to_lowercase();
+        let reversed = lowercase.chars().rev().collect::<String>();
+        if KEYBOARD_ROWS
+            .iter()
+            .any(|row| row.contains(&lowercase) || row.contains(&reversed))
+        {
+            let positions: usize = KEYBOARD_ROWS.iter().map(|r| r.len()).sum();
+            return Some((
+                (positions as f64).log2() + len_bits + 1.0,
+                Pattern::Keyboard,
+            ));
+        }
+    }
+
+    if is_date(s) {
+        // any day within two centuries
+        return Some(((200.0 * 366.0f64).log2(), Pattern::Date));
+    }
+
+    None
+}
+
+fn is_date(s: &str) -> bool {
+    if !s.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let num = |range: std::ops::Range<usize>| -> u32 {
+        s[range].parse().unwrap_or(0)
+    };
+    let is_year = |year: u32| (1900..2100).contains(&year);
+    let is_month = |month: u32| (1..=12).contains(&month);
+    let is_day = |day: u32| (1..=31).contains(&day);
+
+    match s.len() {
+        4 => is_year(num(0..4)),
+        8 => {
+            // yyyymmdd, ddmmyyyy or mmddyyyy
+            (is_year(num(0..4)) && is_month(num(4..6)) && is_day(num(6..8)))
+                || (is_year(num(4..8))
+                    && ((is_day(num(0..2)) && is_month(num(2..4)))
+                        || (is_month(num(0..2)) && is_day(num(2..4)))))
+        }
+        _ => false,
+    }
+}
+
+// -----------------
+// Brute Force
+// -----------------
+
+/// Tries all candidates made of the character classes used by [normalized] up to
+/// [AuditOptions::brute_force_max_len] characters, shortest first, returning the
+/// amount of tries it took to find the one matching [stored].
+fn brute_force(
+    normalized: &str,
+    stored: &Solution,
+    options: &AuditOptions,
+) -> Option<u64> {
+    let len = normalized.chars().count();
+    if len > options.brute_force_max_len || !normalized.is_ascii() {
+        return None;
+    }
+
+    let alphabet = [LOWERCASE, UPPERCASE, DIGITS, PUNCTUATION]
+        .iter()
+        .filter(|set| normalized.chars().any(|c| set.contains(c)))
+        .flat_map(|set| set.bytes())
+        .collect::<Vec<u8>>();
+    // i.e. whitespace only solutions when not normalized
+    if alphabet.is_empty() {
+        return None;
+    }
+
+    let mut tries = 0;
+    for candidate_len in 1..=len {
+        let mut indexes = vec![0; candidate_len];
+        let mut candidate = vec![alphabet[0]; candidate_len];
+        loop {
+            if tries >= options.brute_force_max_tries {
+                return None;
+            }
+            tries += 1;
+            if hash(&hash(&candidate).to_bytes()).to_bytes() == *stored {
+                return Some(tries);
+            }
+
+            // advance to the next candidate like an odometer
+            let mut pos = candidate_len;
+            let exhausted = loop {
+                if pos == 0 {
+                    break true;
+                }
+                pos -= 1;
+                indexes[pos] += 1;
+                if indexes[pos] < alphabet.len() {
+                    candidate[pos] = alphabet[indexes[pos]];
+                    break false;
+                }
+                indexes[pos] = 0;
+                candidate[pos] = alphabet[0];
+            };
+            if exhausted {
+                break;
+            }
+        }
+    }
+    None
+}
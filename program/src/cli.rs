@@ -0,0 +1,439 @@
+//! Reading input and rendering output for the `challenge` CLI, kept apart from the
+//! binary so that it can be used against any cluster or bank.
+//!
+//! Only available with the `cli` feature.
+
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    str::FromStr,
+};
+
+use serde_json::{json, Value};
+use solana_program::{hash::Hash, pubkey::Pubkey};
+use solana_sdk::signature::Signature;
+
+use crate::{
+    audit::SolutionAudit,
+    decode::{DecodedInstruction, DecodedTransactionInstruction},
+    manifest::Plan,
+    state::{Challenge, Challenger, FeeRecipient, HasPda},
+};
+
+/// How the CLI prints PDAs and account state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(format!("Unknown output format '{}'", s)),
+        }
+    }
+}
+
+// -----------------
+// Solutions Input
+// -----------------
+
+/// Reads one solution in clear text per line, skipping empty lines.
+pub fn read_solutions<R: BufRead>(reader: R) -> io::Result<Vec<String>> {
+    let mut solutions = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        if !line.is_empty() {
+            solutions.push(line);
+        }
+    }
+    Ok(solutions)
+}
+
+/// Reads solutions from the file at [path] or from stdin if it is `None` or `-`,
+/// see [read_solutions].
+pub fn read_solutions_from_path(path: Option<&str>) -> io::Result<Vec<String>> {
+    match path {
+        None | Some("-") => read_solutions(io::stdin().lock()),
+        Some(path) => read_solutions(BufReader::new(File::open(path)?)),
+    }
+}
+
+// -----------------
+// Rendering
+// -----------------
+
+/// Renders account state as JSON via its serde representation, i.e. solutions as hex
+/// and derived fields like the pot included.
+fn json_string<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .expect("account state should serialize to JSON")
+        .to_string()
+}
+
+/// Renders the state of the [challenge].
+pub fn render_challenge(challenge: &Challenge, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => json_string(challenge),
+        OutputFormat::Text => {
+            let mut s = String::new();
+            let mut line = |key: &str, value: &dyn std::fmt::Display| {
+                writeln!(s, "{:<16} {}", format!("{}:", key), value).unwrap();
+            };
+            line("address", &challenge.pda().0);
+            line("authority", &challenge.authority);
+            line("id", &challenge.id);
+            line("started", &challenge.started);
+            line("finished", &challenge.finished);
+            line("cancelled", &challenge.cancelled);
+            line("escrow", &challenge.escrow);
+            if challenge.escrows_admit_fees() {
+                line("escrowed_tries", &challenge.escrowed_tries);
+            }
+            line("admit_cost", &challenge.admit_cost);
+            line("tries_per_admit", &challenge.tries_per_admit);
+            line("redeem", &challenge.redeem);
+            line(
+                "solving",
+                &format!("{}/{}", challenge.solving, challenge.solutions.len()),
+            );
+            line("soulbound", &challenge.soulbound);
+            if let Some(root) = &challenge.admission_root {
+                line("admission_root", &hash_string(root));
+            }
+            if let Some(mint) = &challenge.required_mint {
+                line(
+                    "required_mint",
+                    &format!("{} ({})", mint, challenge.required_amount),
+                );
+            }
+            for FeeRecipient {
+                recipient,
+                basis_points,
+            } in &challenge.fee_recipients
+            {
+                line(
+                    "fee_recipient",
+                    &format!("{} ({})", recipient, basis_points),
+                );
+            }
+            if let Some(jackpot) = challenge.jackpot {
+                line("jackpot", &jackpot);
+            }
+            if let Some(program) = &challenge.hook_program {
+                line("hook_program", program);
+            }
+            if let Some(hashing) = &challenge.hashing {
+                line("hash_iterations", &hashing.iterations);
+            }
+            s
+        }
+    }
+}
+
+/// Renders the state of the [challenger] at [address].
+pub fn render_challenger(
+    address: &Pubkey,
+    challenger: &Challenger,
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => json_string(challenger),
+        OutputFormat::Text => {
+            let mut s = String::new();
+            let mut line = |key: &str, value: &dyn std::fmt::Display| {
+                writeln!(s, "{:<16} {}", format!("{}:", key), value).unwrap();
+            };
+            line("address", address);
+            line("authority", &challenger.authority);
+            line("challenge_pda", &challenger.challenge_pda);
+            line("tries_remaining", &challenger.tries_remaining);
+            line("redeemed", &challenger.redeemed);
+            line("solved", &format!("{:?}", challenger.solved_indexes()));
+            line("revoked", &format!("{:?}", challenger.revoked_indexes()));
+            s
+        }
+    }
+}
+
+/// Renders the [challenges] one per line or as a JSON array.
+pub fn render_challenges(
+    challenges: &[(Pubkey, Challenge)],
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => json_string(
+            &challenges
+                .iter()
+                .map(|(_, challenge)| challenge)
+                .collect::<Vec<_>>(),
+        ),
+        OutputFormat::Text => challenges
+            .iter()
+            .map(|(address, challenge)| {
+                format!(
+                    "{} {} solving {}/{}{}\n",
+                    address,
+                    challenge.id,
+                    challenge.solving,
+                    challenge.solutions.len(),
+                    status_suffix(challenge),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Renders the [challengers] one per line or as a JSON array.
+pub fn render_challengers(
+    challengers: &[(Pubkey, Challenger)],
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => json_string(
+            &challengers
+                .iter()
+                .map(|(_, challenger)| challenger)
+                .collect::<Vec<_>>(),
+        ),
+        OutputFormat::Text => challengers
+            .iter()
+            .map(|(address, challenger)| {
+                format!(
+                    "{} {} tries {} solved {:?}\n",
+                    address,
+                    challenger.authority,
+                    challenger.tries_remaining,
+                    challenger.solved_indexes(),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Renders the addresses involved in a command together with the signatures of the
+/// transactions it sent.
+pub fn render_sent(
+    addresses: &[(&str, Pubkey)],
+    signatures: &[Signature],
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => {
+            let mut value = serde_json::Map::new();
+            for (key, address) in addresses {
+                value.insert(key.to_string(), address.to_string().into());
+            }
+            value.insert(
+                "signatures".to_string(),
+                signatures
+                    .iter()
+                    .map(|sig| sig.to_string())
+                    .collect::<Vec<_>>()
+                    .into(),
+            );
+            Value::Object(value).to_string()
+        }
+        OutputFormat::Text => {
+            let mut s = String::new();
+            for (key, address) in addresses {
+                writeln!(s, "{:<16} {}", format!("{}:", key), address).unwrap();
+            }
+            for sig in signatures {
+                writeln!(s, "{:<16} {}", "signature:", sig).unwrap();
+            }
+            s
+        }
+    }
+}
+
+/// Renders the changes and conflicts of the [plan].
+pub fn render_plan(plan: &Plan, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => json!({
+            "changes": plan
+                .changes
+                .iter()
+                .map(|change| change.to_string())
+                .collect::<Vec<_>>(),
+            "conflicts": plan
+                .conflicts
+                .iter()
+                .map(|conflict| conflict.to_string())
+                .collect::<Vec<_>>(),
+            "deferred_start": plan.deferred_start,
+            "transactions": plan.instructions.len(),
+        })
+        .to_string(),
+        OutputFormat::Text => {
+            let mut s = String::new();
+            if plan.is_empty() {
+                writeln!(s, "No changes, the challenge matches the manifest")
+                    .unwrap();
+            }
+            for change in &plan.changes {
+                writeln!(s, "+ {}", change).unwrap();
+            }
+            if let Some(start_at) = plan.deferred_start {
+                writeln!(s, "~ start challenge at {}", start_at).unwrap();
+            }
+            for conflict in &plan.conflicts {
+                writeln!(s, "! {}", conflict).unwrap();
+            }
+            s
+        }
+    }
+}
+
+/// Renders the [audits] of solutions, only referring to them by index so that the
+/// report does not leak them.
+pub fn render_audit(audits: &[SolutionAudit], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => Value::from(
+            audits
+                .iter()
+                .map(|audit| {
+                    json!({
+                        "index": audit.index,
+                        "weak": audit.is_weak(),
+                        "entropy_bits": audit.entropy_bits,
+                        "crack_seconds": audit.crack_seconds,
+                        "findings": audit
+                            .findings
+                            .iter()
+                            .map(|finding| finding.to_string())
+                            .collect::<Vec<_>>(),
+                    })
+                })
+                .collect::<Vec<_>>(),
+        )
+        .to_string(),
+        OutputFormat::Text => {
+            let mut s = String::new();
+            for audit in audits {
+                write!(
+                    s,
+                    "{} {:<4} {:>5.1} bits, cracked {}",
+                    audit.index,
+                    if audit.is_weak() { "weak" } else { "ok" },
+                    audit.entropy_bits,
+                    duration_string(audit.crack_seconds),
+                )
+                .unwrap();
+                for finding in &audit.findings {
+                    write!(s, "; {}", finding).unwrap();
+                }
+                writeln!(s).unwrap();
+            }
+            s
+        }
+    }
+}
+
+/// Renders the challenge instructions decoded from a transaction, inner instructions
+/// are labeled with the index of the top level instruction that invoked them.
+pub fn render_decoded_transaction(
+    instructions: &[DecodedTransactionInstruction],
+    format: OutputFormat,
+) -> String {
+    match format {
+        OutputFormat::Json => Value::from(
+            instructions
+                .iter()
+                .map(|ix| {
+                    let mut value = match &ix.decoded {
+                        Ok(decoded) => decoded_instruction_json(decoded),
+                        Err(err) => json!({ "error": err.to_string() }),
+                    };
+                    value["index"] = ix.index.into();
+                    value["inner_index"] = ix.inner_index.into();
+                    value
+                })
+                .collect::<Vec<_>>(),
+        )
+        .to_string(),
+        OutputFormat::Text => {
+            let mut s = String::new();
+            for ix in instructions {
+                let label = match ix.inner_index {
+                    Some(inner_index) => {
+                        format!("#{}.{}", ix.index, inner_index)
+                    }
+                    None => format!("#{}", ix.index),
+                };
+                match &ix.decoded {
+                    Ok(decoded) => write!(s, "{} {}", label, decoded).unwrap(),
+                    Err(err) => writeln!(s, "{} {}", label, err).unwrap(),
+                }
+            }
+            s
+        }
+    }
+}
+
+/// The [decoded] instruction with its args and named accounts.
+pub fn decoded_instruction_json(decoded: &DecodedInstruction) -> Value {
+    let mut args = serde_json::Map::new();
+    for (name, value) in &decoded.args {
+        args.insert(name.to_string(), value.clone().into());
+    }
+    json!({
+        "instruction": decoded.name(),
+        "args": args,
+        "accounts": decoded
+            .accounts
+            .iter()
+            .map(|acc| {
+                json!({
+                    "name": acc.name,
+                    "pubkey": acc.pubkey.to_string(),
+                    "writable": acc.writable,
+                    "signer": acc.signer,
+                })
+            })
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn duration_string(seconds: f64) -> String {
+    const UNITS: [(&str, f64); 5] = [
+        ("years", 365.0 * 24.0 * 3600.0),
+        ("days", 24.0 * 3600.0),
+        ("hours", 3600.0),
+        ("minutes", 60.0),
+        ("seconds", 1.0),
+    ];
+    if seconds < 1.0 {
+        return "instantly".to_string();
+    }
+    if seconds >= 1e6 * UNITS[0].1 {
+        return "in more than a million years".to_string();
+    }
+    let (unit, secs) = UNITS
+        .iter()
+        .find(|(_, secs)| seconds >= *secs)
+        .expect("seconds is at least 1");
+    format!("in {:.0} {}", seconds / secs, unit)
+}
+
+fn status_suffix(challenge: &Challenge) -> &'static str {
+    if challenge.cancelled {
+        " (cancelled)"
+    } else if challenge.finished {
+        " (finished)"
+    } else if challenge.started {
+        ""
+    } else {
+        " (not started)"
+    }
+}
+
+fn hash_string(bytes: &[u8; 32]) -> String {
+    Hash::new_from_array(*bytes).to_string()
+}
@@ -0,0 +1,366 @@
+//! Client to query challenge accounts and send instructions built via [crate::ixs] from a
+//! Rust backend, wrapping an [RpcClient].
+//!
+//! Only available with the `client` feature.
+
+use borsh::BorshDeserialize;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    client_error::ClientError,
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::{
+    instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
+};
+use solana_sdk::{
+    account::Account, signature::Signature, signer::keypair::Keypair,
+    signer::Signer, transaction::Transaction,
+};
+use thiserror::Error;
+
+use crate::{
+    challenge_id, ixs,
+    state::{
+        Challenge, Challenger, HasPda, ProgramConfig, CHALLENGER_DISCRIMINATOR,
+        CHALLENGER_SIZE, CHALLENGE_DISCRIMINATOR,
+    },
+};
+
+/// Offset of [Challenge::discriminator] inside the challenge account data.
+pub const CHALLENGE_DISCRIMINATOR_OFFSET: usize = 0;
+
+/// Offset of [Challenge::authority] inside the challenge account data.
+pub const CHALLENGE_AUTHORITY_OFFSET: usize = /* discriminator */ 8;
+
+/// Offset of [Challenger::discriminator] inside the challenger account data.
+pub const CHALLENGER_DISCRIMINATOR_OFFSET: usize = 0;
+
+/// Offset of [Challenger::challenge_pda] inside the challenger account data.
+#[rustfmt::skip]
+pub const CHALLENGER_CHALLENGE_PDA_OFFSET: usize =
+    /* discriminator */  8 +
+    /* authority */     32;
+
+#[derive(Error, Debug)]
+pub enum ChallengeClientError {
+    #[error("RPC request failed: {0}")]
+    Rpc(Box<ClientError>),
+
+    #[error("Account {0} does not exist")]
+    AccountNotFound(Pubkey),
+
+    #[error("Account {0} is not owned by the challenge program")]
+    AccountNotOwnedByProgram(Pubkey),
+
+    #[error("Account {0} does not hold {1} data")]
+    InvalidAccountData(Pubkey, &'static str),
+
+    #[error("Failed to build instruction: {0}")]
+    Instruction(#[from] ProgramError),
+}
+
+impl From<ClientError> for ChallengeClientError {
+    fn from(err: ClientError) -> Self {
+        ChallengeClientError::Rpc(Box::new(err))
+    }
+}
+
+pub type ChallengeClientResult<T> = Result<T, ChallengeClientError>;
+
+/// Wraps an [RpcClient] to fetch and decode the accounts of the challenge program
+/// and send its instructions.
+pub struct ChallengeClient {
+    rpc: RpcClient,
+}
+
+impl ChallengeClient {
+    pub fn new(rpc: RpcClient) -> Self {
+        Self { rpc }
+    }
+
+    /// The wrapped [RpcClient] for requests not covered by this client.
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    // -----------------
+    // Challenge
+    // -----------------
+
+    /// Fetches and decodes the challenge at [challenge_pda].
+    pub fn get_challenge(
+        &self,
+        challenge_pda: &Pubkey,
+    ) -> ChallengeClientResult<Challenge> {
+        let account = self.get_program_account(challenge_pda)?;
+        decode_challenge(challenge_pda, &account.data)
+    }
+
+    /// Fetches and decodes the challenge the [creator] created with the given [id].
+    pub fn get_challenge_for(
+        &self,
+        creator: &Pubkey,
+        id: &str,
+    ) -> ChallengeClientResult<Challenge> {
+        let (challenge_pda, _) = Challenge::pda_for(creator, id);
+        self.get_challenge(&challenge_pda)
+    }
+
+    /// Lists all challenges whose authority is the [creator] together with their PDAs.
+    /// Accounts that do not decode as a challenge are skipped.
+    pub fn find_challenges_by_creator(
+        &self,
+        creator: &Pubkey,
+    ) -> ChallengeClientResult<Vec<(Pubkey, Challenge)>> {
+        let accounts =
+            self.get_program_accounts(challenges_by_creator_filters(creator))?;
+        Ok(accounts
+            .into_iter()
+            .filter_map(|(address, account)| {
+                decode_challenge(&address, &account.data)
+                    .ok()
+                    .map(|challenge| (address, challenge))
+            })
+            .collect())
+    }
+
+    /// Fetches the program config, returns `None` if it was not initialized.
+    pub fn get_program_config(
+        &self,
+    ) -> ChallengeClientResult<Option<ProgramConfig>> {
+        let (config_pda, _) = ProgramConfig::pda();
+        match self.get_account(&config_pda)? {
+            Some(account) => {
+                assert_owned_by_program(&config_pda, &account)?;
+                ProgramConfig::try_from_slice(&account.data)
+                    .map(Some)
+                    .map_err(|_| {
+                        ChallengeClientError::InvalidAccountData(
+                            config_pda,
+                            "program config",
+                        )
+                    })
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the token program owning the [mint], i.e. `spl_token_2022` for the
+    /// soulbound redeem mint of a challenge.
+    pub fn get_token_program_of_mint(
+        &self,
+        mint: &Pubkey,
+    ) -> ChallengeClientResult<Pubkey> {
+        self.get_account(mint)?
+            .map(|account| account.owner)
+            .ok_or(ChallengeClientError::AccountNotFound(*mint))
+    }
+
+    // -----------------
+    // Challenger
+    // -----------------
+
+    /// Fetches and decodes the challenger account of the [wallet] for the challenge
+    /// at [challenge_pda].
+    /// Returns `None` if the wallet was never admitted to the challenge.
+    pub fn get_challenger(
+        &self,
+        challenge_pda: &Pubkey,
+        wallet: &Pubkey,
+    ) -> ChallengeClientResult<Option<Challenger>> {
+        let (challenger_pda, _) =
+            Challenger::shank_pda(&challenge_id(), challenge_pda, wallet);
+        let account = match self.get_account(&challenger_pda)? {
+            Some(account) => account,
+            None => return Ok(None),
+        };
+        assert_owned_by_program(&challenger_pda, &account)?;
+        decode_challenger(&challenger_pda, &account.data).map(Some)
+    }
+
+    /// Lists all challengers admitted to the challenge at [challenge_pda] together with
+    /// their PDAs.
+    pub fn find_challengers(
+        &self,
+        challenge_pda: &Pubkey,
+    ) -> ChallengeClientResult<Vec<(Pubkey, Challenger)>> {
+        let accounts =
+            self.get_program_accounts(challengers_filters(challenge_pda))?;
+        accounts
+            .into_iter()
+            .map(|(address, account)| {
+                decode_challenger(&address, &account.data)
+                    .map(|challenger| (address, challenger))
+            })
+            .collect()
+    }
+
+    // -----------------
+    // Transactions
+    // -----------------
+
+    /// Sends the instructions built via [crate::ixs] in one transaction and waits for it
+    /// to be confirmed.
+    ///
+    /// * [payer]: pays for the transaction and signs it
+    /// * [signers]: any additional signers the instructions require
+    pub fn send(
+        &self,
+        ixs: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> ChallengeClientResult<Signature> {
+        let mut all_signers = vec![payer];
+        all_signers.extend(signers);
+
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        Ok(self.rpc.send_and_confirm_transaction(&tx)?)
+    }
+
+    /// Fetches the challenge at [challenge_pda] and sends the [solution] for its current
+    /// solution on behalf of the [challenger], see [crate::ixs::redeem_challenge].
+    /// Challenges with a hook program are not supported since they may require
+    /// additional accounts.
+    pub fn redeem(
+        &self,
+        payer: &Keypair,
+        challenge_pda: &Pubkey,
+        challenger: &Keypair,
+        solution: &str,
+    ) -> ChallengeClientResult<Signature> {
+        let challenge = self.get_challenge(challenge_pda)?;
+        let ix = ixs::redeem_challenge(
+            payer.pubkey(),
+            &challenge,
+            challenger.pubkey(),
+            solution,
+            vec![],
+        )?;
+        self.send(&[ix], payer, &[challenger])
+    }
+
+    // -----------------
+    // Helpers
+    // -----------------
+    fn get_account(
+        &self,
+        address: &Pubkey,
+    ) -> ChallengeClientResult<Option<Account>> {
+        Ok(self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value)
+    }
+
+    fn get_program_account(
+        &self,
+        address: &Pubkey,
+    ) -> ChallengeClientResult<Account> {
+        let account = self
+            .get_account(address)?
+            .ok_or(ChallengeClientError::AccountNotFound(*address))?;
+        assert_owned_by_program(address, &account)?;
+        Ok(account)
+    }
+
+    fn get_program_accounts(
+        &self,
+        filters: Vec<RpcFilterType>,
+    ) -> ChallengeClientResult<Vec<(Pubkey, Account)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        Ok(self
+            .rpc
+            .get_program_accounts_with_config(&challenge_id(), config)?)
+    }
+}
+
+/// Checks offline if the [solution] in clear text is correct for the current solution of
+/// the [challenge] without sending a transaction.
+pub fn is_solution_correct(challenge: &Challenge, solution: &str) -> bool {
+    challenge.is_solution_correct(
+        &challenge.pda().0,
+        &challenge.challenger_sends(solution),
+    )
+}
+
+/// Filters matching the accounts of all challenges created by the [creator].
+/// NOTE: challenges stored in the legacy layout only match once they are migrated, see
+/// [crate::ixs::migrate_challenge].
+pub fn challenges_by_creator_filters(creator: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            CHALLENGE_DISCRIMINATOR_OFFSET,
+            &CHALLENGE_DISCRIMINATOR,
+        )),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            CHALLENGE_AUTHORITY_OFFSET,
+            creator.as_ref(),
+        )),
+    ]
+}
+
+/// Filters matching the accounts of all challengers admitted to the challenge at
+/// [challenge_pda].
+/// NOTE: challengers stored in a legacy layout only match once they are migrated, see
+/// [crate::ixs::migrate_challenger].
+pub fn challengers_filters(challenge_pda: &Pubkey) -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(CHALLENGER_SIZE as u64),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            CHALLENGER_DISCRIMINATOR_OFFSET,
+            &CHALLENGER_DISCRIMINATOR,
+        )),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            CHALLENGER_CHALLENGE_PDA_OFFSET,
+            challenge_pda.as_ref(),
+        )),
+    ]
+}
+
+fn assert_owned_by_program(
+    address: &Pubkey,
+    account: &Account,
+) -> ChallengeClientResult<()> {
+    if account.owner != challenge_id() {
+        Err(ChallengeClientError::AccountNotOwnedByProgram(*address))
+    } else {
+        Ok(())
+    }
+}
+
+/// NOTE: Deserializes without checking that the entire slice has been consumed since
+/// challenges may reserve space for more solutions.
+/// Challenges stored in the legacy layout are upgraded in memory, see
+/// [Challenge::from_account_data].
+fn decode_challenge(
+    address: &Pubkey,
+    data: &[u8],
+) -> ChallengeClientResult<Challenge> {
+    Challenge::from_account_data(data).map_err(|_| {
+        ChallengeClientError::InvalidAccountData(*address, "challenge")
+    })
+}
+
+fn decode_challenger(
+    address: &Pubkey,
+    data: &[u8],
+) -> ChallengeClientResult<Challenger> {
+    Challenger::from_account_data(data).map_err(|_| {
+        ChallengeClientError::InvalidAccountData(*address, "challenger")
+    })
+}
@@ -0,0 +1,749 @@
+//! Helpers to invoke the challenge program from other programs.
+//!
+//! Each instruction has a struct holding the accounts it expects and a function which
+//! invokes it. The account metas are rendered by the accounts generated in [crate::ixs]
+//! from the `#[account]` attributes of [crate::ixs::ChallengeInstruction].
+//! Pass empty `signer_seeds` to `invoke` the instruction and the seeds of your program's
+//! PDAs to `invoke_signed` it on their behalf.
+//!
+//! Add the crate with the `cpi` feature to your program in order to exclude its entrypoint.
+
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    challenge_id,
+    error::ChallengeError,
+    ixs,
+    state::{
+        FeeRecipient, SolutionHashing, SolutionNormalization, SoulboundRedeem,
+    },
+    utils::assert_keys_equal,
+    Solution,
+};
+
+/// Metas of the accounts that are not declared and follow the declared accounts of an
+/// instruction, i.e. fee recipients, the pot or hook accounts.
+/// They keep the signer and writable flags they were passed with, same as when the
+/// instruction is sent directly.
+fn remaining_account_metas(infos: &[AccountInfo]) -> Vec<AccountMeta> {
+    infos
+        .iter()
+        .map(|x| {
+            if x.is_writable {
+                AccountMeta::new(*x.key, x.is_signer)
+            } else {
+                AccountMeta::new_readonly(*x.key, x.is_signer)
+            }
+        })
+        .collect()
+}
+
+/// The program account passed along with the accounts is also passed in place of
+/// optional accounts that are `None`.
+fn invoke_challenge<'a>(
+    program: &AccountInfo<'a>,
+    ix: Result<Instruction, ProgramError>,
+    mut account_infos: Vec<AccountInfo<'a>>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    assert_keys_equal(
+        program.key,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+                "Provided program ({}) is not the challenge program ({})",
+                program.key,
+                challenge_id()
+            )
+        },
+    )?;
+
+    let ix = ix?;
+    account_infos.push(program.clone());
+
+    if signer_seeds.is_empty() {
+        invoke(&ix, &account_infos)
+    } else {
+        invoke_signed(&ix, &account_infos, signer_seeds)
+    }
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+pub struct CreateChallengeAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub redeem_pda: AccountInfo<'a>,
+    pub token_program: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Creates a new challenge, see [crate::ixs::create_challenge_with_fee_recipients],
+/// [crate::ixs::create_challenge_with_capacity] and
+/// [crate::ixs::create_challenge_with_hashing].
+///
+/// * [solutions]: solutions encoded via [crate::utils::hash_solutions] or
+///   [crate::utils::hash_bound_solutions] if [hashing] is provided, in both cases
+///   normalized according to [normalization]
+#[allow(clippy::too_many_arguments)]
+pub fn create_challenge<'a>(
+    program: &AccountInfo<'a>,
+    accounts: CreateChallengeAccounts<'a>,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<Solution>,
+    fee_recipients: Vec<FeeRecipient>,
+    capacity: u8,
+    hashing: Option<SolutionHashing>,
+    normalization: SolutionNormalization,
+    soulbound: Option<SoulboundRedeem>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let CreateChallengeAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        redeem_pda,
+        token_program,
+        system_program,
+        program_config,
+    } = accounts;
+    let ix = ixs::CreateChallengeAccounts {
+        payer: *payer.key,
+        creator: *creator.key,
+        challenge_pda: *challenge_pda.key,
+        redeem_pda: *redeem_pda.key,
+        token_program: *token_program.key,
+        system_program: *system_program.key,
+        program_config: *program_config.key,
+    }
+    .instruction(
+        id,
+        admit_cost,
+        tries_per_admit,
+        *redeem_pda.key,
+        solutions,
+        fee_recipients,
+        capacity,
+        hashing,
+        normalization,
+        soulbound,
+    );
+    invoke_challenge(
+        program,
+        ix,
+        vec![
+            payer,
+            creator,
+            challenge_pda,
+            redeem_pda,
+            token_program,
+            system_program,
+            program_config,
+        ],
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Add Solutions
+// -----------------
+pub struct AddSolutionsAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Adds solutions to an existing challenge, see [crate::ixs::add_solutions].
+///
+/// * [solutions]: solutions encoded via [crate::utils::hash_solutions] or
+///   [crate::utils::hash_bound_solutions] if the challenge has solution hashing
+pub fn add_solutions<'a>(
+    program: &AccountInfo<'a>,
+    accounts: AddSolutionsAccounts<'a>,
+    id: String,
+    solutions: Vec<Solution>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let AddSolutionsAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        system_program,
+        program_config,
+    } = accounts;
+    let ix = ixs::AddSolutionsAccounts {
+        payer: *payer.key,
+        creator: *creator.key,
+        challenge_pda: *challenge_pda.key,
+        system_program: *system_program.key,
+        program_config: *program_config.key,
+    }
+    .instruction(id, solutions);
+    invoke_challenge(
+        program,
+        ix,
+        vec![
+            payer,
+            creator,
+            challenge_pda,
+            system_program,
+            program_config,
+        ],
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Start Challenge
+// -----------------
+pub struct StartChallengeAccounts<'a> {
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Starts a challenge, see [crate::ixs::start_challenge].
+pub fn start_challenge<'a>(
+    program: &AccountInfo<'a>,
+    accounts: StartChallengeAccounts<'a>,
+    id: String,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let StartChallengeAccounts {
+        creator,
+        challenge_pda,
+        program_config,
+    } = accounts;
+    let ix = ixs::StartChallengeAccounts {
+        creator: *creator.key,
+        challenge_pda: *challenge_pda.key,
+        program_config: *program_config.key,
+    }
+    .instruction(id);
+    invoke_challenge(
+        program,
+        ix,
+        vec![creator, challenge_pda, program_config],
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+pub struct AdmitChallengerAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub challenger: AccountInfo<'a>,
+    pub challenger_pda: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    /// Needs to be provided if the challenge requires a token
+    pub challenger_token_account: Option<AccountInfo<'a>>,
+    /// Needs to be provided if the program charges a protocol fee
+    pub treasury: Option<AccountInfo<'a>>,
+    pub program_config: AccountInfo<'a>,
+    /// The pot if the challenge escrows admit fees, otherwise its fee recipients
+    pub remaining_accounts: Vec<AccountInfo<'a>>,
+}
+
+/// Admits a challenger to a challenge, see [crate::ixs::admit_allowlisted_challenger].
+pub fn admit_challenger<'a>(
+    program: &AccountInfo<'a>,
+    accounts: AdmitChallengerAccounts<'a>,
+    admission_proof: Vec<[u8; 32]>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let AdmitChallengerAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        system_program,
+        challenger_token_account,
+        treasury,
+        program_config,
+        remaining_accounts,
+    } = accounts;
+
+    let ix = ixs::AdmitChallengerAccounts {
+        payer: *payer.key,
+        creator: *creator.key,
+        challenge_pda: *challenge_pda.key,
+        challenger: *challenger.key,
+        challenger_pda: *challenger_pda.key,
+        system_program: *system_program.key,
+        challenger_token_account: challenger_token_account
+            .as_ref()
+            .map(|x| *x.key),
+        treasury: treasury.as_ref().map(|x| *x.key),
+        program_config: *program_config.key,
+    }
+    .instruction(*challenge_pda.key, admission_proof)
+    .map(|mut ix| {
+        ix.accounts
+            .extend(remaining_account_metas(&remaining_accounts));
+        ix
+    });
+
+    let mut account_infos = vec![
+        payer,
+        creator,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        system_program,
+    ];
+    account_infos.extend(challenger_token_account);
+    account_infos.extend(treasury);
+    account_infos.push(program_config);
+    account_infos.extend(remaining_accounts);
+
+    invoke_challenge(program, ix, account_infos, signer_seeds)
+}
+
+// -----------------
+// Redeem
+// -----------------
+pub struct RedeemAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub challenger: AccountInfo<'a>,
+    pub challenger_pda: AccountInfo<'a>,
+    pub redeem: AccountInfo<'a>,
+    pub redeem_ata: AccountInfo<'a>,
+    pub token_program: AccountInfo<'a>,
+    pub associated_token_program: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    /// Needs to be provided if the challenge is in jackpot mode
+    pub pot: Option<AccountInfo<'a>>,
+    /// Needs to be provided if the challenge has a hook program
+    pub hook_program: Option<AccountInfo<'a>>,
+    pub program_config: AccountInfo<'a>,
+    /// The accounts passed through to the hook program, empty if the challenge has none
+    pub remaining_accounts: Vec<AccountInfo<'a>>,
+}
+
+/// Attempts to redeem by providing a solution, see [crate::ixs::redeem].
+/// The outcome can be obtained via `get_return_data` and decoded via
+/// [crate::outcome::RedeemOutcome::from_return_data].
+///
+/// * [solution]: the solution encoded via [crate::utils::hash_solution_challenger_sends]
+///   or [crate::utils::hash_salted_solution_challenger_sends] if the challenge has
+///   solution hashing
+pub fn redeem<'a>(
+    program: &AccountInfo<'a>,
+    accounts: RedeemAccounts<'a>,
+    solution: Solution,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let RedeemAccounts {
+        payer,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        redeem,
+        redeem_ata,
+        token_program,
+        associated_token_program,
+        system_program,
+        pot,
+        hook_program,
+        program_config,
+        remaining_accounts,
+    } = accounts;
+
+    let ix = ixs::RedeemAccounts {
+        payer: *payer.key,
+        challenge_pda: *challenge_pda.key,
+        challenger: *challenger.key,
+        challenger_pda: *challenger_pda.key,
+        redeem: *redeem.key,
+        redeem_ata: *redeem_ata.key,
+        token_program: *token_program.key,
+        associated_token_program: *associated_token_program.key,
+        system_program: *system_program.key,
+        pot: pot.as_ref().map(|x| *x.key),
+        hook_program: hook_program.as_ref().map(|x| *x.key),
+        program_config: *program_config.key,
+    }
+    .instruction(solution)
+    .map(|mut ix| {
+        ix.accounts
+            .extend(remaining_account_metas(&remaining_accounts));
+        ix
+    });
+
+    let mut account_infos = vec![
+        payer,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        redeem,
+        redeem_ata,
+        token_program,
+        associated_token_program,
+        system_program,
+    ];
+    account_infos.extend(pot);
+    account_infos.extend(hook_program);
+    account_infos.push(program_config);
+    account_infos.extend(remaining_accounts);
+
+    invoke_challenge(program, ix, account_infos, signer_seeds)
+}
+
+// -----------------
+// Update Challenge
+// -----------------
+pub struct UpdateChallengeAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+impl<'a> UpdateChallengeAccounts<'a> {
+    fn into_account_infos(self) -> Vec<AccountInfo<'a>> {
+        vec![
+            self.payer,
+            self.creator,
+            self.challenge_pda,
+            self.system_program,
+            self.program_config,
+        ]
+    }
+}
+
+/// Sets or removes the allowlist of a challenge, see [crate::ixs::set_admission_root].
+pub fn set_admission_root<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdateChallengeAccounts<'a>,
+    id: String,
+    admission_root: Option<[u8; 32]>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ixs::SetAdmissionRootAccounts {
+        payer: *accounts.payer.key,
+        creator: *accounts.creator.key,
+        challenge_pda: *accounts.challenge_pda.key,
+        system_program: *accounts.system_program.key,
+        program_config: *accounts.program_config.key,
+    }
+    .instruction(id, admission_root);
+    invoke_challenge(program, ix, accounts.into_account_infos(), signer_seeds)
+}
+
+/// Sets or removes the token required for admission, see [crate::ixs::set_required_token].
+pub fn set_required_token<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdateChallengeAccounts<'a>,
+    id: String,
+    required_mint: Option<Pubkey>,
+    required_amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ixs::SetRequiredTokenAccounts {
+        payer: *accounts.payer.key,
+        creator: *accounts.creator.key,
+        challenge_pda: *accounts.challenge_pda.key,
+        system_program: *accounts.system_program.key,
+        program_config: *accounts.program_config.key,
+    }
+    .instruction(id, required_mint, required_amount);
+    invoke_challenge(program, ix, accounts.into_account_infos(), signer_seeds)
+}
+
+/// Sets or removes the hook program of a challenge, see [crate::ixs::set_hook_program].
+pub fn set_hook_program<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdateChallengeAccounts<'a>,
+    id: String,
+    hook_program: Option<Pubkey>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ixs::SetHookProgramAccounts {
+        payer: *accounts.payer.key,
+        creator: *accounts.creator.key,
+        challenge_pda: *accounts.challenge_pda.key,
+        system_program: *accounts.system_program.key,
+        program_config: *accounts.program_config.key,
+    }
+    .instruction(id, hook_program);
+    invoke_challenge(program, ix, accounts.into_account_infos(), signer_seeds)
+}
+
+// -----------------
+// Jackpot and Escrow
+// -----------------
+pub struct UpdatePotAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub pot: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+impl<'a> UpdatePotAccounts<'a> {
+    fn into_account_infos(self) -> Vec<AccountInfo<'a>> {
+        vec![
+            self.payer,
+            self.creator,
+            self.challenge_pda,
+            self.pot,
+            self.system_program,
+            self.program_config,
+        ]
+    }
+}
+
+/// Puts the challenge into jackpot mode or takes it out of it, see [crate::ixs::set_jackpot].
+pub fn set_jackpot<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdatePotAccounts<'a>,
+    id: String,
+    jackpot: Option<u16>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ixs::SetJackpotAccounts {
+        payer: *accounts.payer.key,
+        creator: *accounts.creator.key,
+        challenge_pda: *accounts.challenge_pda.key,
+        pot: *accounts.pot.key,
+        system_program: *accounts.system_program.key,
+        program_config: *accounts.program_config.key,
+    }
+    .instruction(id, jackpot);
+    invoke_challenge(program, ix, accounts.into_account_infos(), signer_seeds)
+}
+
+/// Enables or disables escrowing admit fees, see [crate::ixs::set_escrow].
+pub fn set_escrow<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdatePotAccounts<'a>,
+    id: String,
+    escrow: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ixs::SetEscrowAccounts {
+        payer: *accounts.payer.key,
+        creator: *accounts.creator.key,
+        challenge_pda: *accounts.challenge_pda.key,
+        pot: *accounts.pot.key,
+        system_program: *accounts.system_program.key,
+        program_config: *accounts.program_config.key,
+    }
+    .instruction(id, escrow);
+    invoke_challenge(program, ix, accounts.into_account_infos(), signer_seeds)
+}
+
+// -----------------
+// Cancel Challenge
+// -----------------
+pub struct CancelChallengeAccounts<'a> {
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Cancels a challenge, see [crate::ixs::cancel_challenge].
+pub fn cancel_challenge<'a>(
+    program: &AccountInfo<'a>,
+    accounts: CancelChallengeAccounts<'a>,
+    id: String,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let CancelChallengeAccounts {
+        creator,
+        challenge_pda,
+        program_config,
+    } = accounts;
+    let ix = ixs::CancelChallengeAccounts {
+        creator: *creator.key,
+        challenge_pda: *challenge_pda.key,
+        program_config: *program_config.key,
+    }
+    .instruction(id);
+    invoke_challenge(
+        program,
+        ix,
+        vec![creator, challenge_pda, program_config],
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Claim Refund
+// -----------------
+pub struct ClaimRefundAccounts<'a> {
+    pub challenge_pda: AccountInfo<'a>,
+    pub challenger: AccountInfo<'a>,
+    pub challenger_pda: AccountInfo<'a>,
+    pub pot: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Refunds the unused tries of a challenger, see [crate::ixs::claim_refund].
+pub fn claim_refund<'a>(
+    program: &AccountInfo<'a>,
+    accounts: ClaimRefundAccounts<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ClaimRefundAccounts {
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        pot,
+        program_config,
+    } = accounts;
+    let ix = ixs::ClaimRefundAccounts {
+        challenge_pda: *challenge_pda.key,
+        challenger: *challenger.key,
+        challenger_pda: *challenger_pda.key,
+        pot: *pot.key,
+        program_config: *program_config.key,
+    }
+    .instruction();
+    invoke_challenge(
+        program,
+        ix,
+        vec![
+            challenge_pda,
+            challenger,
+            challenger_pda,
+            pot,
+            program_config,
+        ],
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Withdraw Escrow
+// -----------------
+pub struct WithdrawEscrowAccounts<'a> {
+    pub creator: AccountInfo<'a>,
+    pub challenge_pda: AccountInfo<'a>,
+    pub pot: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Transfers the escrowed admit fees to the creator, see [crate::ixs::withdraw_escrow].
+pub fn withdraw_escrow<'a>(
+    program: &AccountInfo<'a>,
+    accounts: WithdrawEscrowAccounts<'a>,
+    id: String,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let WithdrawEscrowAccounts {
+        creator,
+        challenge_pda,
+        pot,
+        program_config,
+    } = accounts;
+    let ix = ixs::WithdrawEscrowAccounts {
+        creator: *creator.key,
+        challenge_pda: *challenge_pda.key,
+        pot: *pot.key,
+        program_config: *program_config.key,
+    }
+    .instruction(id);
+    invoke_challenge(
+        program,
+        ix,
+        vec![creator, challenge_pda, pot, program_config],
+        signer_seeds,
+    )
+}
+
+// -----------------
+// Program Config
+// -----------------
+pub struct InitConfigAccounts<'a> {
+    pub payer: AccountInfo<'a>,
+    pub admin: AccountInfo<'a>,
+    pub program_data: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Initializes the program config, see [crate::ixs::init_config].
+pub fn init_config<'a>(
+    program: &AccountInfo<'a>,
+    accounts: InitConfigAccounts<'a>,
+    protocol_fee_basis_points: u16,
+    treasury: Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let InitConfigAccounts {
+        payer,
+        admin,
+        program_data,
+        system_program,
+        program_config,
+    } = accounts;
+    let ix = ixs::InitConfigAccounts {
+        payer: *payer.key,
+        admin: *admin.key,
+        program_data: *program_data.key,
+        system_program: *system_program.key,
+        program_config: *program_config.key,
+    }
+    .instruction(protocol_fee_basis_points, treasury);
+    invoke_challenge(
+        program,
+        ix,
+        vec![payer, admin, program_data, system_program, program_config],
+        signer_seeds,
+    )
+}
+
+pub struct UpdateConfigAccounts<'a> {
+    pub admin: AccountInfo<'a>,
+    pub program_config: AccountInfo<'a>,
+}
+
+/// Replaces the program config, see [crate::ixs::update_config].
+pub fn update_config<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdateConfigAccounts<'a>,
+    admin: Pubkey,
+    protocol_fee_basis_points: u16,
+    treasury: Pubkey,
+    paused: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let UpdateConfigAccounts {
+        admin: admin_info,
+        program_config,
+    } = accounts;
+    let ix = ixs::UpdateConfigAccounts {
+        admin: *admin_info.key,
+        program_config: *program_config.key,
+    }
+    .instruction(admin, protocol_fee_basis_points, treasury, paused);
+    invoke_challenge(
+        program,
+        ix,
+        vec![admin_info, program_config],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,464 @@
+//! Decodes instructions of the challenge program from raw instruction data and account
+//! keys into a typed view for inspectors and audit logs.
+//!
+//! Accounts are named after the `#[account]` attributes of [ChallengeInstruction] by
+//! their position since every declared account is passed at its declared index.
+//! The accounts that follow the declared ones are named after what the instruction
+//! expects there, i.e. the fee recipients or pot of `AdmitChallenger` and the accounts
+//! `Redeem` passes through to the hook program.
+
+use std::fmt;
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    instruction::{AccountMeta, CompiledInstruction, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+};
+use thiserror::Error;
+
+use crate::{
+    challenge_id,
+    ixs::ChallengeInstruction,
+    state::{FeeRecipient, Pot, SolutionHashing, SolutionNormalization},
+    utils::hex_string,
+};
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("Instruction is not for the challenge program but for {0}")]
+    WrongProgram(Pubkey),
+
+    #[error("Invalid instruction data: {0}")]
+    InvalidData(String),
+
+    #[error("{name} needs at least {expected} accounts, got {actual}")]
+    NotEnoughAccounts {
+        name: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("Account index {0} is out of bounds of the transaction accounts")]
+    InvalidAccountIndex(u8),
+}
+
+/// An account passed to an instruction named by its position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedAccount {
+    pub name: &'static str,
+    pub pubkey: Pubkey,
+    pub writable: bool,
+    pub signer: bool,
+}
+
+/// The typed view of an instruction of the challenge program.
+#[derive(Debug)]
+pub struct DecodedInstruction {
+    pub instruction: ChallengeInstruction,
+
+    /// The arguments of the instruction rendered for display, byte arrays like
+    /// solution hashes are rendered as hex.
+    pub args: Vec<(&'static str, String)>,
+
+    /// The accounts in the order they were passed to the instruction.
+    pub accounts: Vec<DecodedAccount>,
+}
+
+impl DecodedInstruction {
+    /// The name of the instruction variant, i.e. `Redeem`.
+    pub fn name(&self) -> &'static str {
+        self.instruction.name()
+    }
+
+    /// The pubkey of the first account with the [name].
+    pub fn account(&self, name: &str) -> Option<Pubkey> {
+        self.accounts
+            .iter()
+            .find(|acc| acc.name == name)
+            .map(|acc| acc.pubkey)
+    }
+}
+
+impl fmt::Display for DecodedInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.name())?;
+        for (name, value) in &self.args {
+            writeln!(f, "  {:<26} {}", format!("{}:", name), value)?;
+        }
+        for acc in &self.accounts {
+            let mut flags = String::new();
+            if acc.writable {
+                flags.push_str(" mut");
+            }
+            if acc.signer {
+                flags.push_str(" sig");
+            }
+            writeln!(
+                f,
+                "  {:<26} {}{}",
+                format!("{}:", acc.name),
+                acc.pubkey,
+                flags
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A challenge instruction found in a transaction, [inner_index] is set for
+/// instructions invoked via CPI by the top level instruction at [index].
+#[derive(Debug)]
+pub struct DecodedTransactionInstruction {
+    pub index: usize,
+    pub inner_index: Option<usize>,
+    pub decoded: Result<DecodedInstruction, DecodeError>,
+}
+
+/// The instructions invoked via CPI by the top level instruction at [index], as
+/// reported in the transaction status meta.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerInstructions {
+    pub index: u8,
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+// -----------------
+// Instructions
+// -----------------
+
+/// Decodes an [ix] of the challenge program.
+pub fn decode_ix(ix: &Instruction) -> Result<DecodedInstruction, DecodeError> {
+    if ix.program_id != challenge_id() {
+        return Err(DecodeError::WrongProgram(ix.program_id));
+    }
+    decode_instruction(&ix.data, &ix.accounts)
+}
+
+/// Decodes the instruction [data] and names the [accounts] passed with it.
+pub fn decode_instruction(
+    data: &[u8],
+    accounts: &[AccountMeta],
+) -> Result<DecodedInstruction, DecodeError> {
+    let instruction = ChallengeInstruction::try_from_slice(data)
+        .map_err(|err| DecodeError::InvalidData(err.to_string()))?;
+    let names = account_names(&instruction, accounts)?;
+    let accounts = accounts
+        .iter()
+        .zip(names)
+        .map(|(meta, name)| DecodedAccount {
+            name,
+            pubkey: meta.pubkey,
+            writable: meta.is_writable,
+            signer: meta.is_signer,
+        })
+        .collect();
+
+    Ok(DecodedInstruction {
+        args: instruction_args(&instruction),
+        instruction,
+        accounts,
+    })
+}
+
+/// Names the [accounts] after the declared ones by their position and the accounts
+/// following them after what the [instruction] expects there.
+fn account_names(
+    instruction: &ChallengeInstruction,
+    accounts: &[AccountMeta],
+) -> Result<Vec<&'static str>, DecodeError> {
+    let attrs = instruction.account_attrs();
+    if accounts.len() < attrs.len() {
+        return Err(DecodeError::NotEnoughAccounts {
+            name: instruction.name(),
+            expected: attrs.len(),
+            actual: accounts.len(),
+        });
+    }
+
+    let mut names = attrs.iter().map(|attr| attr.name).collect::<Vec<_>>();
+    let remaining = &accounts[attrs.len()..];
+    match instruction {
+        // admit fees go to the pot if the challenge holds them and are split among
+        // the fee recipients otherwise
+        ChallengeInstruction::AdmitChallenger { .. } => {
+            let pot = attrs
+                .iter()
+                .position(|attr| attr.name == "challenge_pda")
+                .map(|idx| Pot::new(accounts[idx].pubkey).pda);
+            names.extend(remaining.iter().map(|meta| {
+                if pot == Some(meta.pubkey) {
+                    "pot"
+                } else {
+                    "fee_recipient"
+                }
+            }));
+        }
+        ChallengeInstruction::Redeem { .. } => {
+            names.extend(remaining.iter().map(|_| "hook_account"))
+        }
+        _ => names.extend(remaining.iter().map(|_| "remaining")),
+    }
+    Ok(names)
+}
+
+// -----------------
+// Transactions
+// -----------------
+
+/// Decodes all challenge instructions of the transaction [message] including the ones
+/// invoked via CPI reported by [inner_instructions].
+/// Instructions of other programs are skipped.
+pub fn decode_transaction(
+    message: &Message,
+    inner_instructions: &[InnerInstructions],
+) -> Vec<DecodedTransactionInstruction> {
+    let accounts = message
+        .account_keys
+        .iter()
+        .enumerate()
+        .map(|(idx, pubkey)| AccountMeta {
+            pubkey: *pubkey,
+            is_signer: message.is_signer(idx),
+            is_writable: message.is_writable(idx),
+        })
+        .collect::<Vec<_>>();
+    decode_compiled_instructions(
+        &accounts,
+        &message.instructions,
+        inner_instructions,
+    )
+}
+
+/// Decodes the challenge instructions among the compiled [instructions] and
+/// [inner_instructions] which refer to the [accounts] of the transaction by index.
+/// For versioned transactions [accounts] needs to include the addresses loaded
+/// from lookup tables.
+pub fn decode_compiled_instructions(
+    accounts: &[AccountMeta],
+    instructions: &[CompiledInstruction],
+    inner_instructions: &[InnerInstructions],
+) -> Vec<DecodedTransactionInstruction> {
+    let program_id = challenge_id();
+    let is_challenge_ix = |ix: &CompiledInstruction| {
+        accounts
+            .get(ix.program_id_index as usize)
+            .map(|acc| acc.pubkey)
+            == Some(program_id)
+    };
+
+    let mut decoded = vec![];
+    for (index, ix) in instructions.iter().enumerate() {
+        if is_challenge_ix(ix) {
+            decoded.push(DecodedTransactionInstruction {
+                index,
+                inner_index: None,
+                decoded: decode_compiled_instruction(accounts, ix),
+            });
+        }
+        let inner = inner_instructions
+            .iter()
+            .filter(|inner| inner.index as usize == index)
+            .flat_map(|inner| inner.instructions.iter());
+        for (inner_index, ix) in inner.enumerate() {
+            if is_challenge_ix(ix) {
+                decoded.push(DecodedTransactionInstruction {
+                    index,
+                    inner_index: Some(inner_index),
+                    decoded: decode_compiled_instruction(accounts, ix),
+                });
+            }
+        }
+    }
+    decoded
+}
+
+fn decode_compiled_instruction(
+    accounts: &[AccountMeta],
+    ix: &CompiledInstruction,
+) -> Result<DecodedInstruction, DecodeError> {
+    let metas = ix
+        .accounts
+        .iter()
+        .map(|idx| {
+            accounts
+                .get(*idx as usize)
+                .cloned()
+                .ok_or(DecodeError::InvalidAccountIndex(*idx))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    decode_instruction(&ix.data, &metas)
+}
+
+// -----------------
+// Args
+// -----------------
+fn instruction_args(
+    instruction: &ChallengeInstruction,
+) -> Vec<(&'static str, String)> {
+    use ChallengeInstruction::*;
+    match instruction {
+        CreateChallenge {
+            id,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solutions,
+            fee_recipients,
+            capacity,
+            hashing,
+            normalization,
+            soulbound,
+        } => vec![
+            ("id", id.clone()),
+            ("admit_cost", admit_cost.to_string()),
+            ("tries_per_admit", tries_per_admit.to_string()),
+            ("redeem", redeem.to_string()),
+            ("solutions", hex_list(solutions)),
+            ("fee_recipients", fee_recipients_string(fee_recipients)),
+            ("capacity", capacity.to_string()),
+            ("hashing", hashing_string(hashing.as_ref())),
+            ("normalization", normalization_string(normalization)),
+            (
+                "soulbound",
+                match soulbound {
+                    None => "none".to_string(),
+                    Some(soulbound) => format!(
+                        "metadata {}, {}",
+                        option_string(soulbound.metadata_address.as_ref()),
+                        if soulbound.revocable {
+                            "revocable"
+                        } else {
+                            "not revocable"
+                        }
+                    ),
+                },
+            ),
+        ],
+        AddSolutions { id, solutions } => {
+            vec![("id", id.clone()), ("solutions", hex_list(solutions))]
+        }
+        StartChallenge { id }
+        | CancelChallenge { id }
+        | WithdrawEscrow { id } => vec![("id", id.clone())],
+        AdmitChallenger {
+            challenge_pda,
+            admission_proof,
+        } => vec![
+            ("challenge_pda", challenge_pda.to_string()),
+            ("admission_proof", hex_list(admission_proof)),
+        ],
+        Redeem { solution } => vec![("solution", hex_string(solution))],
+        SetAdmissionRoot { id, admission_root } => vec![
+            ("id", id.clone()),
+            (
+                "admission_root",
+                admission_root
+                    .as_ref()
+                    .map_or_else(|| "none".to_string(), |x| hex_string(x)),
+            ),
+        ],
+        SetRequiredToken {
+            id,
+            required_mint,
+            required_amount,
+        } => vec![
+            ("id", id.clone()),
+            ("required_mint", option_string(required_mint.as_ref())),
+            ("required_amount", required_amount.to_string()),
+        ],
+        SetJackpot { id, jackpot } => vec![
+            ("id", id.clone()),
+            ("jackpot", option_string(jackpot.as_ref())),
+        ],
+        SetEscrow { id, escrow } => {
+            vec![("id", id.clone()), ("escrow", escrow.to_string())]
+        }
+        ClaimRefund => vec![],
+        InitConfig {
+            protocol_fee_basis_points,
+            treasury,
+        } => vec![
+            (
+                "protocol_fee_basis_points",
+                protocol_fee_basis_points.to_string(),
+            ),
+            ("treasury", treasury.to_string()),
+        ],
+        UpdateConfig {
+            admin,
+            protocol_fee_basis_points,
+            treasury,
+            paused,
+        } => vec![
+            ("admin", admin.to_string()),
+            (
+                "protocol_fee_basis_points",
+                protocol_fee_basis_points.to_string(),
+            ),
+            ("treasury", treasury.to_string()),
+            ("paused", paused.to_string()),
+        ],
+        SetHookProgram { id, hook_program } => vec![
+            ("id", id.clone()),
+            ("hook_program", option_string(hook_program.as_ref())),
+        ],
+        RevokeSolve {
+            id,
+            solution_index,
+            reopen,
+        } => vec![
+            ("id", id.clone()),
+            ("solution_index", solution_index.to_string()),
+            ("reopen", reopen.to_string()),
+        ],
+        MigrateChallenger => vec![],
+        MigrateChallenge => vec![],
+    }
+}
+
+fn hex_list(hashes: &[[u8; 32]]) -> String {
+    let hashes = hashes.iter().map(|x| hex_string(x)).collect::<Vec<_>>();
+    format!("[{}]", hashes.join(", "))
+}
+
+fn option_string<T: ToString>(value: Option<&T>) -> String {
+    value.map_or_else(|| "none".to_string(), |x| x.to_string())
+}
+
+fn fee_recipients_string(fee_recipients: &[FeeRecipient]) -> String {
+    let recipients = fee_recipients
+        .iter()
+        .map(|x| format!("{} {}bps", x.recipient, x.basis_points))
+        .collect::<Vec<_>>();
+    format!("[{}]", recipients.join(", "))
+}
+
+fn hashing_string(hashing: Option<&SolutionHashing>) -> String {
+    match hashing {
+        None => "none".to_string(),
+        Some(hashing) => format!(
+            "salt {}, {} iterations",
+            hex_string(&hashing.salt),
+            hashing.iterations
+        ),
+    }
+}
+
+fn normalization_string(normalization: &SolutionNormalization) -> String {
+    let steps = [
+        ("nfkc", normalization.nfkc),
+        ("case_fold", normalization.case_fold),
+        ("trim_whitespace", normalization.trim_whitespace),
+        ("collapse_whitespace", normalization.collapse_whitespace),
+    ]
+    .iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(step, _)| *step)
+    .collect::<Vec<_>>();
+    if steps.is_empty() {
+        "none".to_string()
+    } else {
+        steps.join(", ")
+    }
+}
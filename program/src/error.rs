@@ -1,9 +1,14 @@
 use num_derive::FromPrimitive;
+use num_traits::FromPrimitive as _;
 use solana_program::{
     decode_error::DecodeError,
     msg,
     program_error::{PrintProgramError, ProgramError},
 };
+#[cfg(not(target_os = "solana"))]
+use solana_sdk::{
+    instruction::InstructionError, transaction::TransactionError,
+};
 use thiserror::Error;
 
 #[derive(Clone, Debug, Eq, Error, PartialEq, FromPrimitive)]
@@ -82,6 +87,170 @@ pub enum ChallengeError {
     // -----------------
     #[error("Payer does not have sufficient lamports to fund the operation")]
     InsufficientFunds,
+
+    // -----------------
+    // Admission Allowlist
+    // -----------------
+    #[error("Challenger is not included in the allowlist of the challenge")]
+    ChallengerNotAllowlisted,
+
+    // -----------------
+    // Token Gated Admission
+    // -----------------
+    #[error("Challenge requires challengers to hold a token, but no token account was provided")]
+    RequiredTokenAccountMissing,
+
+    #[error("Challenger does not hold enough of the token required by the challenge")]
+    ChallengerLacksRequiredToken,
+
+    // -----------------
+    // Admit Fee Split
+    // -----------------
+    #[error(
+        "Amount of fee recipients exceeds maximum supported fee recipients"
+    )]
+    ExceedingMaxFeeRecipients,
+
+    #[error("The basis points of all fee recipients need to add up to 10,000")]
+    FeeRecipientsBasisPointsInvalid,
+
+    #[error("Provided fee recipient does not match the fee recipient of the challenge")]
+    FeeRecipientMismatch,
+
+    // -----------------
+    // Jackpot
+    // -----------------
+    #[error(
+        "The basis points of the jackpot share need to be between 1 and 10,000"
+    )]
+    JackpotBasisPointsInvalid,
+
+    #[error("A challenge in jackpot mode cannot split the admit fee among fee recipients")]
+    JackpotWithFeeRecipients,
+
+    // -----------------
+    // Escrow
+    // -----------------
+    #[error("A challenge escrowing admit fees cannot split them among fee recipients")]
+    EscrowWithFeeRecipients,
+
+    #[error("Challenge does not escrow admit fees")]
+    ChallengeDoesNotEscrow,
+
+    #[error("Challenge has not finished yet and its escrowed fees cannot be withdrawn")]
+    ChallengeNotYetFinished,
+
+    #[error("Challenge was cancelled and its escrowed fees are refunded to challengers")]
+    ChallengeCancelled,
+
+    #[error(
+        "Challenge was not cancelled and thus does not refund challengers"
+    )]
+    ChallengeNotCancelled,
+
+    #[error("Challenger has no unused tries to be refunded")]
+    NothingToRefund,
+
+    // -----------------
+    // Program Config
+    // -----------------
+    #[error("Program is paused and not processing instructions")]
+    ProgramPaused,
+
+    #[error("Protocol fee cannot exceed 10,000 basis points")]
+    ProtocolFeeBasisPointsInvalid,
+
+    #[error("Program config is required at its declared index, but was not provided")]
+    ProgramConfigAccountMissing,
+
+    // -----------------
+    // Account Mismatches
+    // -----------------
+    #[error("Provided program id does not match this program's id")]
+    IncorrectProgramId,
+
+    #[error("Provided PDA does not match the PDA derived for it")]
+    IncorrectPda,
+
+    #[error(
+        "Provided redeem mint does not match the redeem mint of the challenge"
+    )]
+    IncorrectRedeemMint,
+
+    #[error("Provided authority does not match the authority of the account")]
+    IncorrectAuthority,
+
+    #[error("Provided challenger belongs to a different challenge")]
+    ChallengerChallengeMismatch,
+
+    #[error(
+        "Provided treasury does not match the treasury of the program config"
+    )]
+    IncorrectTreasury,
+
+    #[error("Provided account is not owned by the expected program")]
+    IncorrectAccountOwner,
+
+    #[error("Provided token program does not match the token program of the redeem mint")]
+    IncorrectTokenProgram,
+
+    // -----------------
+    // Verify
+    // -----------------
+    #[error("Account data does not start with the expected discriminator")]
+    AccountDiscriminatorMismatch,
+
+    // -----------------
+    // Hook
+    // -----------------
+    #[error("Provided hook program does not match the hook program of the challenge")]
+    IncorrectHookProgram,
+
+    #[error("The challenge program cannot be its own hook program")]
+    InvalidHookProgram,
+
+    // -----------------
+    // Challenge Data
+    // -----------------
+    #[error("Challenge account data does not match the challenge layout")]
+    ChallengeDataInvalid,
+
+    // -----------------
+    // Solution Hashing
+    // -----------------
+    #[error(
+        "Solution hash iterations exceed the maximum supported iterations"
+    )]
+    ExceedingMaxHashIterations,
+
+    // -----------------
+    // Revoke Solve
+    // -----------------
+    #[error("The challenger did not solve the solution to revoke")]
+    SolveNotFound,
+
+    #[error(
+        "The redeem mint does not have the challenge as its permanent delegate"
+    )]
+    SolveNotRevocable,
+
+    #[error("Only the most recently solved solution of a challenge that was not cancelled can be reopened")]
+    CannotReopenSolution,
+
+    // -----------------
+    // Init Config
+    // -----------------
+    #[error("Provided account is not the ProgramData account of this program")]
+    IncorrectProgramData,
+
+    #[error("Only the upgrade authority of the program can initialize the program config")]
+    NotUpgradeAuthority,
+
+    #[error("Challenger account is stored in a legacy layout and needs to be migrated first")]
+    ChallengerNeedsMigration,
+
+    #[error("Challenge account is stored in a legacy layout and needs to be migrated first")]
+    ChallengeNeedsMigration,
 }
 
 impl PrintProgramError for ChallengeError {
@@ -98,6 +267,36 @@ impl From<ChallengeError> for ProgramError {
 
 impl<T> DecodeError<T> for ChallengeError {
     fn type_of() -> &'static str {
-        "TokenOwner Error"
+        "Challenge Error"
+    }
+}
+
+impl ChallengeError {
+    /// Resolves the error for the custom error code returned by the program.
+    pub fn from_code(code: u32) -> Option<Self> {
+        ChallengeError::from_u32(code)
+    }
+
+    /// Resolves the error from a [ProgramError] returned by the program.
+    pub fn from_program_error(err: &ProgramError) -> Option<Self> {
+        match err {
+            ProgramError::Custom(code) => ChallengeError::from_code(*code),
+            _ => None,
+        }
+    }
+
+    /// Resolves the error that caused a transaction to fail, i.e. obtained
+    /// via `BanksClientError::unwrap` or from an RPC client error.
+    /// Returns `None` if the transaction failed for a reason other than a custom
+    /// error returned by an instruction.
+    #[cfg(not(target_os = "solana"))]
+    pub fn from_transaction_error(err: &TransactionError) -> Option<Self> {
+        match err {
+            TransactionError::InstructionError(
+                _,
+                InstructionError::Custom(code),
+            ) => ChallengeError::from_code(*code),
+            _ => None,
+        }
     }
 }
@@ -0,0 +1,185 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+use crate::challenge_id;
+
+/// Prefixes the data of each event so that it can be told apart from other data
+/// logged by the program.
+pub const EVENT_DISCRIMINATOR: [u8; 8] = *b"chlgevnt";
+
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// Events emitted via `sol_log_data` whenever an instruction changes the state of a
+/// challenge, a challenger or the program config.
+/// They show up as `Program data: <base64 discriminator> <base64 event>` in the
+/// transaction logs and can be decoded via [decode_events].
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ChallengeEvent {
+    Created {
+        challenge_pda: Pubkey,
+        creator: Pubkey,
+        id: String,
+        admit_cost: u64,
+        tries_per_admit: u8,
+        solutions: u8,
+    },
+    SolutionsAdded {
+        challenge_pda: Pubkey,
+        /// The amount of solutions that were added
+        added: u8,
+        /// The amount of solutions the challenge has now
+        solutions: u8,
+    },
+    Started {
+        challenge_pda: Pubkey,
+    },
+    Admitted {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        admit_cost: u64,
+    },
+    AttemptFailed {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        tries_remaining: u8,
+    },
+    Solved {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        /// The index of the solution that was solved
+        solution_index: u8,
+    },
+    Finished {
+        challenge_pda: Pubkey,
+    },
+    Cancelled {
+        challenge_pda: Pubkey,
+    },
+    SolveRevoked {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        /// The index of the solution that was revoked
+        solution_index: u8,
+        /// Whether the solution can be solved again
+        reopened: bool,
+    },
+    AdmissionRootSet {
+        challenge_pda: Pubkey,
+        /// The root of the allowlist, `None` if admission is not restricted
+        admission_root: Option<[u8; 32]>,
+    },
+    RequiredTokenSet {
+        challenge_pda: Pubkey,
+        /// The mint of the token challengers need to hold, `None` if not required
+        required_mint: Option<Pubkey>,
+        required_amount: u64,
+    },
+    HookProgramSet {
+        challenge_pda: Pubkey,
+        hook_program: Option<Pubkey>,
+    },
+    JackpotSet {
+        challenge_pda: Pubkey,
+        /// The share of the pot paid out per solution in basis points
+        jackpot: Option<u16>,
+    },
+    EscrowSet {
+        challenge_pda: Pubkey,
+        escrow: bool,
+    },
+    RefundClaimed {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+        /// The lamports refunded from the pot
+        refund: u64,
+    },
+    EscrowWithdrawn {
+        challenge_pda: Pubkey,
+        /// The lamports withdrawn from the pot
+        withdrawal: u64,
+    },
+    ChallengerMigrated {
+        challenge_pda: Pubkey,
+        challenger: Pubkey,
+    },
+    ConfigInitialized {
+        admin: Pubkey,
+        protocol_fee_basis_points: u16,
+        treasury: Pubkey,
+    },
+    ConfigUpdated {
+        admin: Pubkey,
+        protocol_fee_basis_points: u16,
+        treasury: Pubkey,
+        paused: bool,
+    },
+    ChallengeMigrated {
+        challenge_pda: Pubkey,
+    },
+}
+
+impl ChallengeEvent {
+    /// Logs the event via `sol_log_data`, only use on-chain.
+    pub fn emit(&self) {
+        // Serializing an event into a vec cannot fail
+        let data = self.try_to_vec().unwrap();
+        sol_log_data(&[&EVENT_DISCRIMINATOR, &data]);
+    }
+
+    /// Decodes an event from the fields of a `sol_log_data` call.
+    /// Returns `None` if the fields are not an event.
+    pub fn from_fields(fields: &[Vec<u8>]) -> Option<Self> {
+        match fields {
+            [discriminator, data]
+                if discriminator.as_slice() == EVENT_DISCRIMINATOR =>
+            {
+                ChallengeEvent::try_from_slice(data).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes an event from a single `Program data: ...` log message.
+    /// Returns `None` if the message does not contain an event.
+    pub fn from_log_message(log_message: &str) -> Option<Self> {
+        let encoded = log_message.strip_prefix(PROGRAM_DATA_LOG_PREFIX)?;
+        let fields = encoded
+            .split(' ')
+            .map(base64::decode)
+            .collect::<Result<Vec<Vec<u8>>, _>>()
+            .ok()?;
+        ChallengeEvent::from_fields(&fields)
+    }
+}
+
+/// Decodes all events emitted by this program from the log messages of a transaction.
+/// Data logged by other programs, including programs invoked by this program, is ignored.
+pub fn decode_events<S: AsRef<str>>(log_messages: &[S]) -> Vec<ChallengeEvent> {
+    let program_id = challenge_id().to_string();
+    let mut invoked = Vec::<String>::new();
+    let mut events = Vec::new();
+
+    for log_message in log_messages.iter().map(AsRef::as_ref) {
+        if let Some(rest) = log_message.strip_prefix("Program ") {
+            let mut parts = rest.split(' ');
+            match (parts.next(), parts.next()) {
+                (Some(id), Some("invoke")) => {
+                    invoked.push(id.to_string());
+                    continue;
+                }
+                (Some(_), Some("success")) | (Some(_), Some("failed:")) => {
+                    invoked.pop();
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        if invoked.last() != Some(&program_id) {
+            continue;
+        }
+        if let Some(event) = ChallengeEvent::from_log_message(log_message) {
+            events.push(event);
+        }
+    }
+    events
+}
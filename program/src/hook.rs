@@ -0,0 +1,97 @@
+//! The post solve hook allows creators to run custom logic whenever a challenger solves
+//! their challenge without forking this program.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    pubkey::Pubkey,
+};
+
+/// Prefixes the data the hook program receives so that it can be told apart from
+/// the other instructions of that program.
+pub const HOOK_DISCRIMINATOR: [u8; 8] = *b"chlghook";
+
+/// The data passed to the `hook_program` of a challenge after a correct solution was
+/// redeemed.
+///
+/// The hook program receives the following accounts:
+///
+/// 0. `[]` challenge PDA
+/// 1. `[]` challenger, the wallet that solved
+/// 2. `[]` challenger PDA, already updated to include the solved solution
+/// 3. ..   the accounts appended to the `Redeem` instruction after the hook program,
+///    passed through as provided
+///
+/// The challenge accounts are not signers, thus the hook program should confirm the solve via
+/// [crate::verify::verify_solver] before acting on it.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PostSolveHook {
+    pub challenge_pda: Pubkey,
+    pub challenger: Pubkey,
+    /// The index of the solution that was solved
+    pub solution_index: u8,
+}
+
+impl PostSolveHook {
+    /// Encodes the hook as instruction data prefixed with [HOOK_DISCRIMINATOR].
+    pub fn to_instruction_data(&self) -> Vec<u8> {
+        // Serializing the hook into a vec cannot fail
+        [HOOK_DISCRIMINATOR.to_vec(), self.try_to_vec().unwrap()].concat()
+    }
+
+    /// Decodes the hook from the instruction data received by the hook program.
+    /// Returns `None` if the data is not a post solve hook.
+    pub fn from_instruction_data(data: &[u8]) -> Option<Self> {
+        match data.split_at(data.len().min(HOOK_DISCRIMINATOR.len())) {
+            (discriminator, rest) if discriminator == HOOK_DISCRIMINATOR => {
+                PostSolveHook::try_from_slice(rest).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Invokes the [hook_program_info] passing the challenge accounts followed by
+    /// the [hook_account_infos].
+    /// Challenge accounts are passed read-only and without signing.
+    pub(crate) fn invoke<'a>(
+        &self,
+        hook_program_info: &AccountInfo<'a>,
+        challenge_pda_info: &AccountInfo<'a>,
+        challenger_info: &AccountInfo<'a>,
+        challenger_pda_info: &AccountInfo<'a>,
+        hook_account_infos: &[AccountInfo<'a>],
+    ) -> ProgramResult {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(*challenge_pda_info.key, false),
+            AccountMeta::new_readonly(*challenger_info.key, false),
+            AccountMeta::new_readonly(*challenger_pda_info.key, false),
+        ];
+        accounts.extend(hook_account_infos.iter().map(|x| {
+            if x.is_writable {
+                AccountMeta::new(*x.key, x.is_signer)
+            } else {
+                AccountMeta::new_readonly(*x.key, x.is_signer)
+            }
+        }));
+
+        let mut account_infos = vec![
+            challenge_pda_info.clone(),
+            challenger_info.clone(),
+            challenger_pda_info.clone(),
+        ];
+        account_infos.extend(hook_account_infos.iter().cloned());
+        account_infos.push(hook_program_info.clone());
+
+        invoke(
+            &Instruction {
+                program_id: *hook_program_info.key,
+                accounts,
+                data: self.to_instruction_data(),
+            },
+            &account_infos,
+        )
+    }
+}
@@ -1,16 +1,27 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankInstruction;
 use solana_program::{
+    hash::HASH_BYTES,
     instruction::{AccountMeta, Instruction},
+    message::Message,
     program_error::ProgramError,
     pubkey::Pubkey,
     system_program,
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 
 use crate::{
     challenge_id,
-    state::{Challenge, Challenger, HasPda, Redeem},
-    utils::{hash_solution_challenger_sends, hash_solutions},
+    error::ChallengeError,
+    program_data_address,
+    state::{
+        Challenge, Challenger, FeeRecipient, HasPda, Pot, ProgramConfig,
+        Redeem, SolutionHashing, SolutionNormalization, SoulboundRedeem,
+    },
+    utils::{
+        hash_solution_challenger_sends, hash_solutions, hash_stored_solutions,
+    },
+    Solution,
 };
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, ShankInstruction)]
@@ -20,8 +31,9 @@ pub enum ChallengeInstruction {
     #[account(1, name = "creator", desc="challenge authority")]
     #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
     #[account(3, name = "redeem_pda", mut, desc="PDA of token to redeem for correct solution")]
-    #[account(4, name = "token_program", desc="Token Program")]
+    #[account(4, name = "token_program", desc="Token Program, Token-2022 if the redeem mint is soulbound")]
     #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "program_config", desc="PDA of the program config")]
     CreateChallenge {
         id: String,
         admit_cost: u64,
@@ -36,6 +48,27 @@ pub enum ChallengeInstruction {
         /// Transaction size is ~1,024 bytes which means if more solutions are desired they
         /// need to be added separately via the `AddSolutions` instruction.
         solutions: Vec<[u8; 32]>,
+
+        /// The accounts that share the admit cost, if empty the creator receives all of it.
+        /// Otherwise their basis points need to add up to 10,000.
+        fee_recipients: Vec<FeeRecipient>,
+
+        /// The amount of solutions to allocate space for.
+        /// If it exceeds the provided solutions the space for the remaining ones is reserved
+        /// so that solutions added later via `AddSolutions` don't need to grow the account.
+        capacity: u8,
+
+        /// If set solutions are bound to the challenge and stretched, see `Challenge::hashing`.
+        /// The provided solutions need to be hashed accordingly.
+        hashing: Option<SolutionHashing>,
+
+        /// How solutions are normalized before they are hashed, see `Challenge::normalization`.
+        /// The provided solutions need to be normalized accordingly.
+        normalization: SolutionNormalization,
+
+        /// If set the redeem mint is created under the Token-2022 program and cannot be
+        /// transferred, see `Challenge::soulbound`.
+        soulbound: Option<SoulboundRedeem>,
     },
 
     /// Appends solutions to the end of the solutions array, keeping existing solutions in place.
@@ -44,6 +77,7 @@ pub enum ChallengeInstruction {
     #[account(1, name = "creator", sig, desc="challenge authority")]
     #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
     #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "program_config", desc="PDA of the program config")]
     AddSolutions {
         id: String,
         /// The solutions to add to the challenge
@@ -53,40 +87,246 @@ pub enum ChallengeInstruction {
     #[rustfmt::skip]
     #[account(0, name = "creator", sig, desc="challenge authority")]
     #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "program_config", desc="PDA of the program config")]
     StartChallenge {
         id: String,
     },
 
+    /// Admits a challenger to the challenge and pays the admit cost.
+    /// If the challenge splits the admit cost among fee recipients, their accounts
+    /// need to be appended after the program config in the order in which they are
+    /// stored on the challenge.
+    /// If the challenge escrows admit fees or is in jackpot mode the pot needs to be
+    /// appended instead.
+    /// Optional accounts that are not needed are passed as the program id.
     #[rustfmt::skip]
     #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
     #[account(1, name = "creator", mut, desc="challenge authority")]
-    #[account(2, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
     #[account(3, name = "challenger", desc="challenger account which receives the redeemed token")]
     #[account(4, name = "challenger_pda", mut, desc="PDA for the challenger")]
     #[account(5, name = "system_program", desc="System Program")]
+    #[account(6, name = "challenger_token_account", optional, desc="token account of the challenger holding the token required by the challenge")]
+    #[account(7, name = "treasury", mut, optional, desc="receives the protocol fee")]
+    #[account(8, name = "program_config", desc="PDA of the program config")]
     AdmitChallenger {
         challenge_pda: Pubkey,
+        /// Proof that the challenger is included in the challenge's allowlist.
+        /// Only checked if the challenge has an `admission_root`, otherwise it should be empty.
+        admission_proof: Vec<[u8; 32]>,
     },
 
+    /// Attempts to redeem by providing a solution.
+    /// If the challenge has a hook program it is invoked after a correct solution with
+    /// the accounts appended after the program config passed through to it.
+    /// Optional accounts that are not needed are passed as the program id.
     #[rustfmt::skip]
     #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
     #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
-    #[account(2, name = "challenger", sig, desc="challenger account which receives the redeemed token")]
+    #[account(2, name = "challenger", mut, sig, desc="challenger account which receives the redeemed token")]
     #[account(3, name = "challenger_pda", mut, desc="PDA for the challenger")]
     #[account(4, name = "redeem", mut, desc="PDA of token to redeem for correct solution")]
     #[account(5, name = "redeem_ata", mut, desc="ATA for redeem PDA and challenger")]
-    #[account(6, name = "token_program", desc="Token Program")]
+    #[account(6, name = "token_program", desc="Token Program owning the redeem mint")]
     #[account(7, name = "associated_token_program", desc="Associated Token Program")]
     #[account(8, name = "system_program", desc="System Program")]
+    #[account(9, name = "pot", mut, optional, desc="PDA of the pot holding the admit fees of a challenge in jackpot mode")]
+    #[account(10, name = "hook_program", optional, desc="the hook program of the challenge")]
+    #[account(11, name = "program_config", desc="PDA of the program config")]
     Redeem {
         solution: [u8; 32],
     },
+
+    /// Sets or replaces the root of the allowlist of challengers that can be admitted.
+    /// Passing `None` removes the allowlist and admits anyone paying the admit cost.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "program_config", desc="PDA of the program config")]
+    SetAdmissionRoot {
+        id: String,
+        /// The root of the merkle tree of challengers allowed to be admitted
+        admission_root: Option<[u8; 32]>,
+    },
+
+    /// Sets or replaces the token that challengers need to hold in order to be admitted.
+    /// Passing `None` as the mint removes that requirement.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "program_config", desc="PDA of the program config")]
+    SetRequiredToken {
+        id: String,
+        /// The mint of the token challengers need to hold
+        required_mint: Option<Pubkey>,
+        /// The amount of that token challengers need to hold
+        required_amount: u64,
+    },
+
+    /// Puts the challenge into jackpot mode or takes it out of it before it starts.
+    /// In jackpot mode all admit fees are escrowed in the pot which pays out to
+    /// challengers providing correct solutions.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "pot", mut, desc="PDA of the pot holding the admit fees of a challenge in jackpot mode")]
+    #[account(4, name = "system_program", desc="System Program")]
+    #[account(5, name = "program_config", desc="PDA of the program config")]
+    SetJackpot {
+        id: String,
+        /// The share of the pot in basis points paid out for each correct solution,
+        /// `None` pays the admit fees to the creator instead
+        jackpot: Option<u16>,
+    },
+
+    /// Enables or disables escrowing admit fees in the pot before the challenge starts.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "pot", mut, desc="PDA of the pot holding the escrowed admit fees")]
+    #[account(4, name = "system_program", desc="System Program")]
+    #[account(5, name = "program_config", desc="PDA of the program config")]
+    SetEscrow {
+        id: String,
+        /// If `true` admit fees are held in the pot until the challenge finishes
+        escrow: bool,
+    },
+
+    /// Aborts the challenge which finishes it and allows challengers to claim refunds
+    /// if it escrows admit fees.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "program_config", desc="PDA of the program config")]
+    CancelChallenge {
+        id: String,
+    },
+
+    /// Refunds the admit cost of the unused tries of a challenger from the pot of a
+    /// cancelled challenge.
+    #[rustfmt::skip]
+    #[account(0, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(1, name = "challenger", mut, sig, desc="challenger account which receives the refund")]
+    #[account(2, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(3, name = "pot", mut, desc="PDA of the pot holding the escrowed admit fees")]
+    #[account(4, name = "program_config", desc="PDA of the program config")]
+    ClaimRefund,
+
+    /// Transfers the escrowed admit fees from the pot to the creator once the
+    /// challenge finished. If it was cancelled only the fees exceeding the refunds
+    /// still owed to challengers are transferred.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", mut, sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", desc="PDA for the challenge")]
+    #[account(2, name = "pot", mut, desc="PDA of the pot holding the escrowed admit fees")]
+    #[account(3, name = "program_config", desc="PDA of the program config")]
+    WithdrawEscrow {
+        id: String,
+    },
+
+    /// Initializes the program config and is invoked only once after the program is deployed.
+    /// Only the upgrade authority of the program may initialize it.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "admin", sig, desc="upgrade authority of the program which becomes the config admin")]
+    #[account(2, name = "program_data", desc="ProgramData account of the program holding its upgrade authority")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "program_config", mut, desc="PDA of the program config")]
+    InitConfig {
+        /// The fee taken from the admit cost of every admission
+        protocol_fee_basis_points: u16,
+        /// The account receiving the protocol fees
+        treasury: Pubkey,
+    },
+
+    /// Replaces the program config, this is processed even while the program is paused.
+    #[rustfmt::skip]
+    #[account(0, name = "admin", sig, desc="authority that can update the program config")]
+    #[account(1, name = "program_config", mut, desc="PDA of the program config")]
+    UpdateConfig {
+        /// The new admin which could be the same as the current one
+        admin: Pubkey,
+        /// The fee taken from the admit cost of every admission
+        protocol_fee_basis_points: u16,
+        /// The account receiving the protocol fees
+        treasury: Pubkey,
+        /// If `true` no other instruction is processed
+        paused: bool,
+    },
+
+    /// Sets or removes the program invoked after each correct solution before the
+    /// challenge starts.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction")]
+    #[account(1, name = "creator", sig, desc="challenge authority")]
+    #[account(2, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(3, name = "system_program", desc="System Program")]
+    #[account(4, name = "program_config", desc="PDA of the program config")]
+    SetHookProgram {
+        id: String,
+        /// The program invoked after each correct solution, `None` removes the hook
+        hook_program: Option<Pubkey>,
+    },
+
+    /// Revokes a fraudulent solve by burning the redeemed token from the challenger's
+    /// ATA which requires a soulbound redeem mint that is revocable.
+    #[rustfmt::skip]
+    #[account(0, name = "creator", sig, desc="challenge authority")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "challenger", desc="challenger account which received the redeemed token")]
+    #[account(3, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(4, name = "redeem", mut, desc="PDA of token redeemed for the solve")]
+    #[account(5, name = "redeem_ata", mut, desc="ATA for redeem PDA and challenger")]
+    #[account(6, name = "token_program", desc="Token-2022 Program")]
+    #[account(7, name = "program_config", desc="PDA of the program config")]
+    RevokeSolve {
+        id: String,
+        /// The index of the solution the challenger solved
+        solution_index: u8,
+        /// If set the solution needs to be solved again, only possible for the most
+        /// recently solved solution
+        reopen: bool,
+    },
+
+    /// Migrates a challenger account stored in a legacy layout to the current layout,
+    /// reallocating it as needed. Anyone may migrate a challenger.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the additional rent")]
+    #[account(1, name = "challenger_pda", mut, desc="PDA for the challenger")]
+    #[account(2, name = "system_program", desc="System Program")]
+    #[account(3, name = "program_config", desc="PDA of the program config")]
+    MigrateChallenger,
+
+    /// Migrates a challenge account stored in the legacy layout to the current layout,
+    /// reallocating it as needed and keeping the space reserved for solutions.
+    /// Anyone may migrate a challenge.
+    #[rustfmt::skip]
+    #[account(0, name = "payer", mut, sig, desc="pays for the transaction and the additional rent")]
+    #[account(1, name = "challenge_pda", mut, desc="PDA for the challenge")]
+    #[account(2, name = "system_program", desc="System Program")]
+    #[account(3, name = "program_config", desc="PDA of the program config")]
+    MigrateChallenge,
     // TODO(thlorenz): may need some ixs for creators that want to mutate solutions, i.e.
     //  - add solutions at index (replacing existing ones)
     //  - replace solution at index
     //  - clear solutions
 }
 
+// Typed accounts and instruction builders generated by `build.rs` from the
+// `#[account]` attributes of [ChallengeInstruction].
+include!(concat!(env!("OUT_DIR"), "/ixs_accounts.rs"));
+
+/// The program config is passed at its declared index by every instruction.
+fn program_config() -> Pubkey {
+    ProgramConfig::pda().0
+}
+
 // -----------------
 // Create Challenge
 // -----------------
@@ -108,35 +348,274 @@ pub fn create_challenge(
     tries_per_admit: u8,
     solutions: Vec<&str>,
 ) -> Result<Instruction, ProgramError> {
+    create_challenge_with_fee_recipients(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        vec![],
+    )
+}
+
+/// Creates a new challenge whose admit cost is split among multiple accounts.
+///
+/// * [fee_recipients]: the accounts sharing the admit cost with basis points adding
+///   up to 10,000, any rounding dust goes to the creator
+pub fn create_challenge_with_fee_recipients(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    fee_recipients: Vec<FeeRecipient>,
+) -> Result<Instruction, ProgramError> {
+    create_challenge_with_options(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        CreateChallengeOptions {
+            fee_recipients,
+            ..Default::default()
+        },
+    )
+}
+
+/// Creates a new challenge reserving space for more solutions than are provided.
+/// Solutions added later via [add_solutions] use up that space instead of growing the
+/// challenge account.
+///
+/// * [capacity]: the amount of solutions to allocate space for
+pub fn create_challenge_with_capacity(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    capacity: u8,
+) -> Result<Instruction, ProgramError> {
+    create_challenge_with_options(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        CreateChallengeOptions {
+            capacity,
+            ..Default::default()
+        },
+    )
+}
+
+/// Creates a new challenge whose solutions are bound to it and stretched before being
+/// stored, see [crate::state::Challenge::hashing].
+///
+/// * [hashing]: the salt, which should be random and unique per challenge, and the
+///   amount of times solutions are rehashed
+pub fn create_challenge_with_hashing(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    hashing: SolutionHashing,
+) -> Result<Instruction, ProgramError> {
+    create_challenge_with_options(
+        payer,
+        creator,
+        id,
+        admit_cost,
+        tries_per_admit,
+        solutions,
+        CreateChallengeOptions {
+            hashing: Some(hashing),
+            ..Default::default()
+        },
+    )
+}
+
+/// The optional settings of a challenge that are provided when it is created.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreateChallengeOptions {
+    /// See [create_challenge_with_fee_recipients]
+    pub fee_recipients: Vec<FeeRecipient>,
+    /// See [create_challenge_with_capacity]
+    pub capacity: u8,
+    /// See [create_challenge_with_hashing]
+    pub hashing: Option<SolutionHashing>,
+    /// How solutions are normalized before they are hashed, challengers need to use
+    /// the same normalization, see [crate::state::Challenge::normalization]
+    pub normalization: SolutionNormalization,
+    /// If set the redeem mint is created under the Token-2022 program so that redeemed
+    /// tokens cannot be transferred, see [crate::state::Challenge::soulbound]
+    pub soulbound: Option<SoulboundRedeem>,
+}
+
+/// Creates a new challenge with any combination of the optional settings.
+/// Solutions are normalized and hashed according to the provided [options].
+pub fn create_challenge_with_options(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    options: CreateChallengeOptions,
+) -> Result<Instruction, ProgramError> {
+    let CreateChallengeOptions {
+        fee_recipients,
+        capacity,
+        hashing,
+        normalization,
+        soulbound,
+    } = options;
+
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, &id);
 
     let redeem = Redeem::new(challenge_pda);
     let (redeem_pda, _) = redeem.pda();
 
-    let solutions = hash_solutions(&solutions);
-
-    let ix = Instruction {
-        program_id: challenge_id(),
-        accounts: vec![
-            AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(creator, false),
-            AccountMeta::new(challenge_pda, false),
-            AccountMeta::new(redeem_pda, false),
-            AccountMeta::new_readonly(spl_token::id(), false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
-        data: ChallengeInstruction::CreateChallenge {
-            id,
+    let solutions = hash_stored_solutions(
+        &solutions,
+        &normalization,
+        &challenge_pda,
+        hashing.as_ref(),
+        0,
+    );
+
+    CreateChallengeAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        redeem_pda,
+        token_program: Redeem::token_program(soulbound.is_some()),
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(
+        id,
+        admit_cost,
+        tries_per_admit,
+        redeem_pda,
+        solutions,
+        fee_recipients,
+        capacity,
+        hashing,
+        normalization,
+        soulbound,
+    )
+}
+
+/// The max amount of solutions passed with each instruction returned by
+/// [create_challenge_chunked].
+/// Keeps each transaction adding solutions below the max transaction size with separate
+/// payer and creator, an id of the maximum length and solution hashing.
+pub const SOLUTIONS_PER_INSTRUCTION: usize = 20;
+
+/// The max size of a serialized transaction, the same as
+/// `solana_sdk::packet::PACKET_DATA_SIZE` which is not available to programs.
+pub const MAX_TRANSACTION_SIZE: usize = 1_232;
+
+/// The size of a signature of a transaction.
+const SIGNATURE_BYTES: usize = 64;
+
+/// Determines the size of the serialized transaction that sends the [ixs] and is paid
+/// by the [payer].
+pub fn transaction_size(ixs: &[Instruction], payer: &Pubkey) -> usize {
+    let message = Message::new(ixs, Some(payer));
+    let signatures = message.header.num_required_signatures as usize;
+    // short vec length of the signatures followed by signatures and message
+    1 + signatures * SIGNATURE_BYTES + message.serialize().len()
+}
+
+/// Creates a new challenge with any amount of solutions up to the maximum supported.
+/// Since only a limited amount of solutions fit into one transaction they are split
+/// into chunks of at most [SOLUTIONS_PER_INSTRUCTION].
+/// The first instruction creates the challenge reserving space for all solutions and
+/// each following one adds the next chunk via [add_solutions].
+/// The first instruction includes as many solutions as fit into its transaction next
+/// to the [options] of the challenge.
+///
+/// Each instruction needs to be sent in a separate transaction in the order returned
+/// and the creator needs to sign all but the first.
+///
+/// * [options]: see [create_challenge_with_options], the `capacity` is set to the
+///   amount of provided solutions
+pub fn create_challenge_chunked(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    solutions: Vec<&str>,
+    options: CreateChallengeOptions,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let capacity = u8::try_from(solutions.len())
+        .map_err(|_| ChallengeError::ExceedingMaxSupportedSolutions)?;
+    let CreateChallengeOptions {
+        hashing,
+        normalization,
+        ..
+    } = options;
+
+    let options = CreateChallengeOptions {
+        capacity,
+        ..options
+    };
+
+    // size the first chunk from the transaction creating the challenge without solutions
+    let first_len = {
+        let ix = create_challenge_with_options(
+            payer,
+            creator,
+            id.clone(),
             admit_cost,
             tries_per_admit,
-            redeem: redeem_pda,
-            solutions,
-        }
-        .try_to_vec()?,
+            vec![],
+            options.clone(),
+        )?;
+        // adding solutions may grow the short vec length of the data by one byte
+        let available = MAX_TRANSACTION_SIZE
+            .saturating_sub(transaction_size(&[ix], &payer) + 1);
+        (available / HASH_BYTES)
+            .min(SOLUTIONS_PER_INSTRUCTION)
+            .min(solutions.len())
     };
 
-    Ok(ix)
+    let (first, rest) = solutions.split_at(first_len);
+    let mut first_index = first.len() as u8;
+    let mut ixs = vec![create_challenge_with_options(
+        payer,
+        creator,
+        id.clone(),
+        admit_cost,
+        tries_per_admit,
+        first.to_vec(),
+        options,
+    )?];
+    for chunk in rest.chunks(SOLUTIONS_PER_INSTRUCTION) {
+        let solutions = hash_added_solutions(
+            chunk,
+            &normalization,
+            &creator,
+            &id,
+            hashing.as_ref(),
+            first_index,
+        );
+        ixs.push(add_solutions_ix(payer, creator, id.clone(), solutions)?);
+        first_index += chunk.len() as u8;
+    }
+
+    Ok(ixs)
 }
 
 // -----------------
@@ -149,32 +628,122 @@ pub fn create_challenge(
 /// * [creator]: the authority managing the challenge
 /// * [id]: unique id used when creating the challenge
 /// * [solutions]: solutions to be added in clear text, they are encoded via
-///   `sha256(sha256(solution))` before being stored
-/// * [index]: the index at which to insert the solutions
-///   if provided solutions starting at that index are replaced, otherwise they are appended
+///   `sha256(sha256(solution))` without normalizing them before being stored
+///
+/// Only works for legacy challenges without [SolutionHashing] and with the default
+/// [SolutionNormalization], the solutions added to any other challenge never match.
+#[deprecated(
+    note = "only works for legacy challenges, use add_challenge_solutions instead"
+)]
 pub fn add_solutions(
     payer: Pubkey,
     creator: Pubkey,
     id: String,
     solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    let solutions =
+        hash_solutions(&solutions, &SolutionNormalization::default());
+    add_solutions_ix(payer, creator, id, solutions)
+}
+
+/// Adds solutions to the provided challenge, normalizing and hashing them the same
+/// way as the challenge's existing solutions.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [challenge]: the challenge to add solutions to, its solutions need to be up to date
+///   since solutions with [SolutionHashing] are bound to their index
+/// * [solutions]: solutions to be added in clear text
+pub fn add_challenge_solutions(
+    payer: Pubkey,
+    challenge: &Challenge,
+    solutions: Vec<&str>,
+) -> Result<Instruction, ProgramError> {
+    let solutions = hash_added_solutions(
+        &solutions,
+        &challenge.normalization,
+        &challenge.authority,
+        &challenge.id,
+        challenge.hashing.as_ref(),
+        challenge.solutions.len() as u8,
+    );
+    add_solutions_ix(
+        payer,
+        challenge.authority,
+        challenge.id.clone(),
+        solutions,
+    )
+}
+
+/// Adds any amount of solutions to the provided challenge, split into chunks of
+/// [SOLUTIONS_PER_INSTRUCTION] like [create_challenge_chunked] does.
+///
+/// Each instruction needs to be sent in a separate transaction in the order returned.
+///
+/// * [challenge]: see [add_challenge_solutions]
+pub fn add_challenge_solutions_chunked(
+    payer: Pubkey,
+    challenge: &Challenge,
+    solutions: Vec<&str>,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let mut first_index = challenge.solutions.len();
+    let mut ixs = vec![];
+    for chunk in solutions.chunks(SOLUTIONS_PER_INSTRUCTION) {
+        let first = u8::try_from(first_index)
+            .map_err(|_| ChallengeError::ExceedingMaxSupportedSolutions)?;
+        let solutions = hash_added_solutions(
+            chunk,
+            &challenge.normalization,
+            &challenge.authority,
+            &challenge.id,
+            challenge.hashing.as_ref(),
+            first,
+        );
+        ixs.push(add_solutions_ix(
+            payer,
+            challenge.authority,
+            challenge.id.clone(),
+            solutions,
+        )?);
+        first_index += chunk.len();
+    }
+    Ok(ixs)
+}
+
+fn hash_added_solutions(
+    solutions: &[&str],
+    normalization: &SolutionNormalization,
+    creator: &Pubkey,
+    id: &str,
+    hashing: Option<&SolutionHashing>,
+    first_index: u8,
+) -> Vec<Solution> {
+    let (challenge_pda, _) = Challenge::shank_pda(&challenge_id(), creator, id);
+    hash_stored_solutions(
+        solutions,
+        normalization,
+        &challenge_pda,
+        hashing,
+        first_index,
+    )
+}
+
+fn add_solutions_ix(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    solutions: Vec<Solution>,
 ) -> Result<Instruction, ProgramError> {
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, &id);
-    let solutions = hash_solutions(&solutions);
-
-    let ix = Instruction {
-        program_id: challenge_id(),
-        accounts: vec![
-            AccountMeta::new(payer, true),
-            AccountMeta::new_readonly(creator, true),
-            AccountMeta::new(challenge_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
-        data: ChallengeInstruction::AddSolutions { id, solutions }
-            .try_to_vec()?,
-    };
 
-    Ok(ix)
+    AddSolutionsAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(id, solutions)
 }
 
 // -----------------
@@ -187,16 +756,12 @@ pub fn start_challenge(
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, &id);
 
-    let ix = Instruction {
-        program_id: challenge_id(),
-        accounts: vec![
-            AccountMeta::new_readonly(creator, true),
-            AccountMeta::new(challenge_pda, false),
-        ],
-        data: ChallengeInstruction::StartChallenge { id }.try_to_vec()?,
-    };
-
-    Ok(ix)
+    StartChallengeAccounts {
+        creator,
+        challenge_pda,
+        program_config: program_config(),
+    }
+    .instruction(id)
 }
 
 // -----------------
@@ -206,52 +771,121 @@ pub struct AdmitChallengerIx {
     pub challenge_pda: Pubkey,
     pub challenger_pda: Pubkey,
     pub ix: Instruction,
+    accounts: AdmitChallengerAccounts,
+    /// The pot or the fee recipients of the challenge which follow the declared accounts
+    fee_accounts: Vec<AccountMeta>,
 }
+
+impl AdmitChallengerIx {
+    /// Adds the treasury of the program [config] which needs to be provided if the
+    /// program charges a protocol fee.
+    pub fn with_protocol_fee(mut self, config: &ProgramConfig) -> Self {
+        if config.protocol_fee_basis_points > 0 {
+            self.accounts.treasury = Some(config.treasury);
+            self.update_account_metas();
+        }
+        self
+    }
+
+    fn update_account_metas(&mut self) {
+        self.ix.accounts = self.accounts.to_account_metas();
+        self.ix.accounts.extend(self.fee_accounts.iter().cloned());
+    }
+}
+
+/// Admits a challenger to a challenge that does not restrict admission via an allowlist.
+///
+/// * [payer]: pays for the transaction and the admit cost
+/// * [challenge]: the challenge to admit to, the challenger's token account is added
+///   automatically if it requires a token to be held, as are the fee recipients or the pot
+/// * [challenger]: the account that is admitted and will be able to redeem
+/// * [required_token_program]: the token program owning the challenge's `required_mint`,
+///   i.e. `spl_token_2022` for the soulbound redeem mint of another challenge, used to
+///   derive the challenger's token account. Required if the challenge requires a token
+///   to be held and ignored otherwise.
+///
+/// If the program charges a protocol fee use [AdmitChallengerIx::with_protocol_fee] to
+/// add the treasury.
 pub fn admit_challenger(
     payer: Pubkey,
-    creator: Pubkey,
-    id: &str,
+    challenge: &Challenge,
     challenger: Pubkey,
+    required_token_program: Option<Pubkey>,
 ) -> Result<AdmitChallengerIx, ProgramError> {
-    let (challenge_pda, _) =
-        Challenge::shank_pda(&challenge_id(), &creator, id);
+    admit_allowlisted_challenger(
+        payer,
+        challenge,
+        challenger,
+        vec![],
+        required_token_program,
+    )
+}
+
+/// Admits a challenger to a challenge that restricts admission via an allowlist.
+///
+/// * [admission_proof]: proof that the challenger is included in the allowlist, see
+///   [crate::utils::AdmissionTree::proof]
+///
+/// See [admit_challenger] for the remaining arguments.
+pub fn admit_allowlisted_challenger(
+    payer: Pubkey,
+    challenge: &Challenge,
+    challenger: Pubkey,
+    admission_proof: Vec<[u8; 32]>,
+    required_token_program: Option<Pubkey>,
+) -> Result<AdmitChallengerIx, ProgramError> {
+    let (challenge_pda, _) = challenge.pda();
     let (challenger_pda, _) =
         Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
 
-    let ix = Instruction {
-        program_id: challenge_id(),
-        accounts: vec![
-            AccountMeta::new(payer, true),
-            AccountMeta::new(creator, false),
-            AccountMeta::new_readonly(challenge_pda, false),
-            AccountMeta::new_readonly(challenger, false),
-            AccountMeta::new(challenger_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
-        data: ChallengeInstruction::AdmitChallenger { challenge_pda }
-            .try_to_vec()?,
+    let challenger_token_account = match challenge.required_mint {
+        Some(mint) => {
+            let token_program = required_token_program
+                .ok_or(ChallengeError::RequiredTokenAccountMissing)?;
+            Some(get_associated_token_address_with_program_id(
+                &challenger,
+                &mint,
+                &token_program,
+            ))
+        }
+        None => None,
+    };
+
+    let accounts = AdmitChallengerAccounts {
+        payer,
+        creator: challenge.authority,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        system_program: system_program::id(),
+        challenger_token_account,
+        // added via [AdmitChallengerIx::with_protocol_fee]
+        treasury: None,
+        program_config: program_config(),
+    };
+    let mut ix = accounts.instruction(challenge_pda, admission_proof)?;
+
+    let fee_accounts = if challenge.escrows_admit_fees() {
+        let (pot_pda, _) = challenge.pot_pda();
+        vec![AccountMeta::new(pot_pda, false)]
+    } else {
+        challenge
+            .fee_recipients
+            .iter()
+            .map(|x| AccountMeta::new(x.recipient, false))
+            .collect()
     };
+    ix.accounts.extend(fee_accounts.iter().cloned());
 
     Ok(AdmitChallengerIx {
         challenge_pda,
         challenger_pda,
         ix,
+        accounts,
+        fee_accounts,
     })
 }
 
-#[derive(Debug)]
-struct RedeemAccounts {
-    payer: AccountMeta,
-    challenge_pda: AccountMeta,
-    challenger: AccountMeta,
-    challenger_pda: AccountMeta,
-    redeem_pda: AccountMeta,
-    redeem_ata: AccountMeta,
-    spl_token_program: AccountMeta,
-    spl_associated_token_program: AccountMeta,
-    system_program: AccountMeta,
-}
-
 // -----------------
 // Redeem by providing solution
 // -----------------
@@ -262,7 +896,16 @@ struct RedeemAccounts {
 /// * [id]: unique id used when creating the challenge
 /// * [challenger]: the  account attempting to redeem by providing the solution
 /// * [solution]: solutions to be added in clear text, they are encoded via `sha256(solution)`
-///   before being passed to the challenge
+///   without normalizing them before being passed to the challenge
+///
+/// The pot of the challenge is always included and only used if the challenge is in
+/// jackpot mode.
+/// Only works for legacy challenges without [SolutionHashing], with the default
+/// [SolutionNormalization] and a redeem mint owned by the spl_token program, any other
+/// challenge rejects the solution.
+#[deprecated(
+    note = "only works for legacy challenges, use redeem_challenge instead"
+)]
 pub fn redeem(
     payer: Pubkey,
     creator: Pubkey,
@@ -270,51 +913,500 @@ pub fn redeem(
     challenger: Pubkey,
     solution: &str,
 ) -> Result<Instruction, ProgramError> {
-    let challenger_sends = hash_solution_challenger_sends(solution);
+    let challenger_sends = hash_solution_challenger_sends(
+        solution,
+        &SolutionNormalization::default(),
+    );
+    redeem_ix(
+        payer,
+        creator,
+        id,
+        challenger,
+        challenger_sends,
+        spl_token::id(),
+        None,
+    )
+}
+
+/// Attempts to redeem a challenge that has a hook program by providing a solution.
+/// Takes the same arguments as [redeem] and additionally:
+///
+/// * [hook_program]: the hook program of the challenge
+/// * [hook_accounts]: accounts passed through to the hook program after the challenge
+///   accounts, see [crate::hook::PostSolveHook]
+///
+/// Only works for legacy challenges the same as [redeem].
+#[deprecated(
+    note = "only works for legacy challenges, use redeem_challenge instead"
+)]
+pub fn redeem_with_hook(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: &str,
+    challenger: Pubkey,
+    solution: &str,
+    hook_program: Pubkey,
+    hook_accounts: Vec<AccountMeta>,
+) -> Result<Instruction, ProgramError> {
+    let challenger_sends = hash_solution_challenger_sends(
+        solution,
+        &SolutionNormalization::default(),
+    );
+    redeem_ix(
+        payer,
+        creator,
+        id,
+        challenger,
+        challenger_sends,
+        spl_token::id(),
+        Some((hook_program, hook_accounts)),
+    )
+}
+
+/// Attempts to redeem the provided challenge by providing a solution.
+/// The solution is normalized according to the [SolutionNormalization] of the challenge
+/// and encoded according to its [SolutionHashing] for the solution it is currently
+/// `solving`. Its hook program is included if it has one.
+///
+/// * [payer]: pays for the transaction and is usually the challenger
+/// * [challenge]: the challenge to redeem
+/// * [challenger]: the  account attempting to redeem by providing the solution
+/// * [solution]: the solution in clear text
+/// * [hook_accounts]: accounts passed through to the hook program of the challenge,
+///   ignored if it has none
+pub fn redeem_challenge(
+    payer: Pubkey,
+    challenge: &Challenge,
+    challenger: Pubkey,
+    solution: &str,
+    hook_accounts: Vec<AccountMeta>,
+) -> Result<Instruction, ProgramError> {
+    let challenger_sends = challenge.challenger_sends(solution);
+    redeem_ix(
+        payer,
+        challenge.authority,
+        &challenge.id,
+        challenger,
+        challenger_sends,
+        challenge.redeem_token_program(),
+        challenge
+            .hook_program
+            .map(|hook_program| (hook_program, hook_accounts)),
+    )
+}
 
+fn redeem_ix(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: &str,
+    challenger: Pubkey,
+    challenger_sends: Solution,
+    token_program: Pubkey,
+    hook: Option<(Pubkey, Vec<AccountMeta>)>,
+) -> Result<Instruction, ProgramError> {
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &creator, id);
     let (challenger_pda, _) =
         Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
     let redeem = Redeem::new(challenge_pda);
-    let redeem_ata = redeem.ata(&challenger);
-
-    let accounts = RedeemAccounts {
-        payer: AccountMeta::new(payer, true),
-        challenge_pda: AccountMeta::new(challenge_pda, false),
-        challenger: AccountMeta::new_readonly(challenger, true),
-        challenger_pda: AccountMeta::new(challenger_pda, false),
-        redeem_pda: AccountMeta::new(redeem.pda().0, false),
-        redeem_ata: AccountMeta::new(redeem_ata, false),
-        spl_token_program: AccountMeta::new_readonly(spl_token::id(), false),
-        spl_associated_token_program: AccountMeta::new_readonly(
-            spl_associated_token_account::id(),
-            false,
-        ),
-        system_program: AccountMeta::new_readonly(system_program::id(), false),
-    };
+    let redeem_ata = redeem.ata_with_program_id(&challenger, &token_program);
+    let pot = Pot::new(challenge_pda);
 
-    let ix = Instruction {
-        program_id: challenge_id(),
-        accounts: vec![
-            accounts.payer,
-            accounts.challenge_pda,
-            // challenger
-            accounts.challenger,
-            accounts.challenger_pda,
-            // redeem
-            accounts.redeem_pda,
-            accounts.redeem_ata,
-            // programs
-            accounts.spl_token_program,
-            accounts.spl_associated_token_program,
-            accounts.system_program,
-        ],
-        data: ChallengeInstruction::Redeem {
-            solution: challenger_sends,
-        }
-        .try_to_vec()?,
-    };
+    let mut ix = RedeemAccounts {
+        payer,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        redeem: redeem.pda().0,
+        redeem_ata,
+        token_program,
+        associated_token_program: spl_associated_token_account::id(),
+        system_program: system_program::id(),
+        // only used if the challenge is in jackpot mode
+        pot: Some(pot.pda),
+        hook_program: hook.as_ref().map(|(hook_program, _)| *hook_program),
+        program_config: program_config(),
+    }
+    .instruction(challenger_sends)?;
+    if let Some((_, hook_accounts)) = hook {
+        ix.accounts.extend(hook_accounts);
+    }
 
     Ok(ix)
 }
+
+// -----------------
+// Set Admission Root
+// -----------------
+
+/// Sets the root of the allowlist of challengers that can be admitted to the challenge.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [admission_root]: root of the allowlist, see [crate::utils::AdmissionTree::root],
+///   `None` removes the allowlist
+pub fn set_admission_root(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    admission_root: Option<[u8; 32]>,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    SetAdmissionRootAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(id, admission_root)
+}
+
+// -----------------
+// Set Required Token
+// -----------------
+
+/// Sets the token that challengers need to hold in order to be admitted to the challenge.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [required_mint]: mint of the token challengers need to hold, i.e. the redeem
+///   of another challenge, `None` removes the requirement
+/// * [required_amount]: the amount of the token challengers need to hold
+pub fn set_required_token(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    required_mint: Option<Pubkey>,
+    required_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    SetRequiredTokenAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(id, required_mint, required_amount)
+}
+
+// -----------------
+// Set Hook Program
+// -----------------
+
+/// Sets or removes the program invoked after each correct solution.
+/// This is only possible before the challenge starts.
+///
+/// * [payer]: pays for the transaction and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [hook_program]: the program invoked after each correct solution, `None` removes it
+pub fn set_hook_program(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    hook_program: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    SetHookProgramAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(id, hook_program)
+}
+
+// -----------------
+// Revoke Solve
+// -----------------
+
+/// Revokes a solve by burning the token the challenger redeemed for it.
+/// The challenge needs to have a soulbound redeem mint that is revocable, see
+/// [SoulboundRedeem::revocable].
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the account that solved the solution
+/// * [solution_index]: the index of the solution to revoke
+/// * [reopen]: if set the solution needs to be solved again, only possible if it is the
+///   most recently solved solution, any jackpot share paid out for it is not reclaimed
+pub fn revoke_solve(
+    creator: Pubkey,
+    id: String,
+    challenger: Pubkey,
+    solution_index: u8,
+    reopen: bool,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let redeem = Redeem::new(challenge_pda);
+    let token_program = Redeem::token_program(true);
+
+    RevokeSolveAccounts {
+        creator,
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        redeem: redeem.pda,
+        redeem_ata: redeem.ata_with_program_id(&challenger, &token_program),
+        token_program,
+        program_config: program_config(),
+    }
+    .instruction(id, solution_index, reopen)
+}
+
+// -----------------
+// Set Jackpot
+// -----------------
+
+/// Puts the challenge into jackpot mode, creating its pot if needed, or takes it out of it.
+/// This is only possible before the challenge starts.
+///
+/// * [payer]: pays for the transaction and the pot's rent and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [jackpot]: share of the pot in basis points paid out for each correct solution,
+///   `None` pays the admit fees to the creator instead
+pub fn set_jackpot(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    jackpot: Option<u16>,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let pot = Pot::new(challenge_pda);
+
+    SetJackpotAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        pot: pot.pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(id, jackpot)
+}
+
+// -----------------
+// Set Escrow
+// -----------------
+
+/// Enables or disables escrowing admit fees in the pot of the challenge, creating the pot
+/// if needed.
+/// This is only possible before the challenge starts.
+///
+/// * [payer]: pays for the transaction and the pot's rent and is usually the creator
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [escrow]: if `true` admit fees are held in the pot until the challenge finishes
+pub fn set_escrow(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+    escrow: bool,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let pot = Pot::new(challenge_pda);
+
+    SetEscrowAccounts {
+        payer,
+        creator,
+        challenge_pda,
+        pot: pot.pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(id, escrow)
+}
+
+// -----------------
+// Cancel Challenge
+// -----------------
+pub fn cancel_challenge(
+    creator: Pubkey,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    CancelChallengeAccounts {
+        creator,
+        challenge_pda,
+        program_config: program_config(),
+    }
+    .instruction(id)
+}
+
+// -----------------
+// Claim Refund
+// -----------------
+
+/// Claims the refund for the unused tries of a challenger of a cancelled challenge.
+///
+/// * [creator]: the authority managing the challenge
+/// * [id]: unique id used when creating the challenge
+/// * [challenger]: the admitted account which receives the refund
+pub fn claim_refund(
+    creator: Pubkey,
+    id: &str,
+    challenger: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, id);
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+    let pot = Pot::new(challenge_pda);
+
+    ClaimRefundAccounts {
+        challenge_pda,
+        challenger,
+        challenger_pda,
+        pot: pot.pda,
+        program_config: program_config(),
+    }
+    .instruction()
+}
+
+// -----------------
+// Withdraw Escrow
+// -----------------
+
+/// Withdraws the escrowed admit fees of a finished challenge to the creator.
+///
+/// * [creator]: the authority managing the challenge which receives the fees
+/// * [id]: unique id used when creating the challenge
+pub fn withdraw_escrow(
+    creator: Pubkey,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+    let pot = Pot::new(challenge_pda);
+
+    WithdrawEscrowAccounts {
+        creator,
+        challenge_pda,
+        pot: pot.pda,
+        program_config: program_config(),
+    }
+    .instruction(id)
+}
+
+// -----------------
+// Init Config
+// -----------------
+
+/// Initializes the program config and is invoked only once after the program is deployed.
+///
+/// * [payer]: pays for the transaction and is usually the admin
+/// * [admin]: the upgrade authority of the program which becomes the config admin
+/// * [protocol_fee_basis_points]: fee taken from the admit cost of every admission
+/// * [treasury]: the account receiving the protocol fees
+pub fn init_config(
+    payer: Pubkey,
+    admin: Pubkey,
+    protocol_fee_basis_points: u16,
+    treasury: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    InitConfigAccounts {
+        payer,
+        admin,
+        program_data: program_data_address(),
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction(protocol_fee_basis_points, treasury)
+}
+
+// -----------------
+// Update Config
+// -----------------
+
+/// Replaces the program config, i.e. to pause or unpause the program.
+///
+/// * [admin]: the current authority of the program config
+/// * [config]: the config to store, its `admin` may differ in order to transfer authority
+pub fn update_config(
+    admin: Pubkey,
+    config: &ProgramConfig,
+) -> Result<Instruction, ProgramError> {
+    UpdateConfigAccounts {
+        admin,
+        program_config: program_config(),
+    }
+    .instruction(
+        config.admin,
+        config.protocol_fee_basis_points,
+        config.treasury,
+        config.paused,
+    )
+}
+
+// -----------------
+// Migrate Challenger
+// -----------------
+
+/// Migrates the challenger account of the [challenger] stored in a legacy layout to the
+/// current layout, see [Challenger::from_account_data].
+///
+/// * [payer]: pays for the transaction and the additional rent
+/// * [challenge_pda]: the PDA of the challenge the challenger was admitted to
+/// * [challenger]: the address that was admitted to the challenge
+pub fn migrate_challenger(
+    payer: Pubkey,
+    challenge_pda: Pubkey,
+    challenger: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger);
+
+    MigrateChallengerAccounts {
+        payer,
+        challenger_pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction()
+}
+
+// -----------------
+// Migrate Challenge
+// -----------------
+
+/// Migrates the challenge with the [id] of the [creator] stored in the legacy layout to
+/// the current layout, see [Challenge::from_account_data].
+///
+/// * [payer]: pays for the transaction and the additional rent
+/// * [creator]: the authority of the challenge
+/// * [id]: the id of the challenge
+pub fn migrate_challenge(
+    payer: Pubkey,
+    creator: Pubkey,
+    id: String,
+) -> Result<Instruction, ProgramError> {
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, &id);
+
+    MigrateChallengeAccounts {
+        payer,
+        challenge_pda,
+        system_program: system_program::id(),
+        program_config: program_config(),
+    }
+    .instruction()
+}
@@ -1,12 +1,30 @@
-use solana_program::{declare_id, hash::HASH_BYTES, pubkey::Pubkey};
+use solana_program::{
+    bpf_loader_upgradeable, declare_id, hash::HASH_BYTES, pubkey::Pubkey,
+};
 
+#[cfg(not(target_os = "solana"))]
+pub mod audit;
+#[cfg(all(feature = "cli", not(target_os = "solana")))]
+pub mod cli;
+#[cfg(all(feature = "client", not(target_os = "solana")))]
+pub mod client;
+pub mod cpi;
+#[cfg(not(target_os = "solana"))]
+pub mod decode;
+#[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
-mod error;
+pub mod error;
+pub mod events;
+pub mod hook;
 pub mod ixs;
+#[cfg(all(feature = "cli", not(target_os = "solana")))]
+pub mod manifest;
+pub mod outcome;
 mod processor;
 pub mod shank_utils;
 pub mod state;
 pub mod utils;
+pub mod verify;
 
 declare_id!("FFFFaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
@@ -14,4 +32,13 @@ pub fn challenge_id() -> Pubkey {
     id()
 }
 
+/// The ProgramData account holding the upgrade authority of the deployed program.
+pub fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(
+        &[id().as_ref()],
+        &bpf_loader_upgradeable::id(),
+    )
+    .0
+}
+
 pub type Solution = [u8; HASH_BYTES];
@@ -0,0 +1,416 @@
+//! Declarative challenge manifests in TOML or YAML which are compared with the on-chain
+//! [Challenge] to plan the instructions needed to bring it in line with the manifest.
+//!
+//! Only available with the `cli` feature.
+//!
+//! ```toml
+//! id = "riddle"
+//! admit_cost = 1000
+//! tries_per_admit = 3
+//! # one solution per line, relative to the manifest
+//! solutions_file = "solutions.txt"
+//! normalize = true
+//!
+//! [schedule]
+//! start = true
+//! # unix timestamp before which the challenge is not started
+//! start_at = 1700000000
+//! ```
+
+use std::{
+    fmt, fs,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use solana_program::{
+    instruction::Instruction, program_error::ProgramError, pubkey::Pubkey,
+};
+use thiserror::Error;
+
+use crate::{
+    cli::read_solutions,
+    ixs::{self, CreateChallengeOptions},
+    state::{Challenge, HasPda, SolutionNormalization, SoulboundRedeem},
+    utils::hash_stored_solutions,
+};
+
+#[derive(Error, Debug)]
+pub enum ManifestError {
+    #[error("Failed to read {0}: {1}")]
+    Io(PathBuf, io::Error),
+
+    #[error("Invalid TOML manifest: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Invalid YAML manifest: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("Manifest {0} needs to have a .toml, .yaml or .yml extension")]
+    UnsupportedFormat(PathBuf),
+}
+
+/// Describes the desired state of a challenge of the creator applying it.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// The id of the challenge, unique for the creator.
+    pub id: String,
+
+    /// See [Challenge::admit_cost].
+    pub admit_cost: u64,
+
+    /// See [Challenge::tries_per_admit].
+    pub tries_per_admit: u8,
+
+    /// File with one solution in clear text per line, resolved relative to the manifest
+    /// when loaded via [Manifest::from_path].
+    pub solutions_file: PathBuf,
+
+    /// Applies all steps of [SolutionNormalization] to the solutions.
+    #[serde(default)]
+    pub normalize: bool,
+
+    /// Creates a soulbound redeem mint, see [SoulboundRedeem].
+    #[serde(default)]
+    pub soulbound: bool,
+
+    #[serde(default)]
+    pub schedule: Schedule,
+}
+
+/// Determines when the challenge is started.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Schedule {
+    /// Starts the challenge once it has all solutions.
+    #[serde(default)]
+    pub start: bool,
+
+    /// Unix timestamp before which the challenge is not started.
+    pub start_at: Option<i64>,
+}
+
+impl Manifest {
+    pub fn from_toml(s: &str) -> Result<Self, ManifestError> {
+        Ok(toml::from_str(s)?)
+    }
+
+    pub fn from_yaml(s: &str) -> Result<Self, ManifestError> {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Loads the manifest at [path] choosing the format by its extension.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ManifestError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|err| ManifestError::Io(path.to_path_buf(), err))?;
+        let mut manifest = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Manifest::from_toml(&content)?,
+            Some("yaml") | Some("yml") => Manifest::from_yaml(&content)?,
+            _ => {
+                return Err(ManifestError::UnsupportedFormat(
+                    path.to_path_buf(),
+                ))
+            }
+        };
+        if let Some(dir) = path.parent() {
+            manifest.solutions_file = dir.join(&manifest.solutions_file);
+        }
+        Ok(manifest)
+    }
+
+    /// Reads the solutions from the `solutions_file`, see [read_solutions].
+    pub fn read_solutions(&self) -> Result<Vec<String>, ManifestError> {
+        fs::File::open(&self.solutions_file)
+            .and_then(|file| read_solutions(BufReader::new(file)))
+            .map_err(|err| ManifestError::Io(self.solutions_file.clone(), err))
+    }
+
+    pub fn normalization(&self) -> SolutionNormalization {
+        if self.normalize {
+            SolutionNormalization {
+                nfkc: true,
+                case_fold: true,
+                trim_whitespace: true,
+                collapse_whitespace: true,
+            }
+        } else {
+            SolutionNormalization::default()
+        }
+    }
+
+    /// Whether the challenge should be started at the time [now] given as unix timestamp.
+    fn starts_at(&self, now: i64) -> bool {
+        self.schedule.start
+            && !matches!(self.schedule.start_at, Some(start_at) if start_at > now)
+    }
+}
+
+// -----------------
+// Plan
+// -----------------
+
+/// A change that applying the plan makes to the challenge.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// Creates the challenge with the given amount of solutions.
+    Create { solutions: usize },
+
+    /// Adds the solutions of the manifest starting at [from_index].
+    AddSolutions { from_index: usize, count: usize },
+
+    /// Starts the challenge.
+    Start,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Create { solutions } => {
+                write!(f, "create challenge with {} solutions", solutions)
+            }
+            Change::AddSolutions { from_index, count } => write!(
+                f,
+                "add {} solutions starting at index {}",
+                count, from_index
+            ),
+            Change::Start => write!(f, "start challenge"),
+        }
+    }
+}
+
+/// A difference between the manifest and the on-chain challenge that cannot be applied.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Conflict {
+    AdmitCost {
+        manifest: u64,
+        on_chain: u64,
+    },
+    TriesPerAdmit {
+        manifest: u8,
+        on_chain: u8,
+    },
+    Normalization,
+    Soulbound {
+        manifest: bool,
+        on_chain: bool,
+    },
+    /// The solution at [index] differs from the one stored on chain.
+    SolutionMismatch {
+        index: usize,
+    },
+    /// The challenge has more solutions than the manifest.
+    MissingSolutions {
+        manifest: usize,
+        on_chain: usize,
+    },
+    Cancelled,
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Conflict::AdmitCost { manifest, on_chain } => write!(
+                f,
+                "admit_cost is {} on chain but {} in the manifest",
+                on_chain, manifest
+            ),
+            Conflict::TriesPerAdmit { manifest, on_chain } => write!(
+                f,
+                "tries_per_admit is {} on chain but {} in the manifest",
+                on_chain, manifest
+            ),
+            Conflict::Normalization => {
+                write!(f, "normalization differs from the one on chain")
+            }
+            Conflict::Soulbound { manifest, on_chain } => write!(
+                f,
+                "soulbound is {} on chain but {} in the manifest",
+                on_chain, manifest
+            ),
+            Conflict::SolutionMismatch { index } => {
+                write!(f, "solution {} differs from the one on chain", index)
+            }
+            Conflict::MissingSolutions { manifest, on_chain } => write!(
+                f,
+                "challenge has {} solutions on chain but the manifest only {}",
+                on_chain, manifest
+            ),
+            Conflict::Cancelled => write!(f, "challenge was cancelled"),
+        }
+    }
+}
+
+/// The result of comparing a [Manifest] with the on-chain challenge.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Plan {
+    /// The changes applying the plan makes in order.
+    pub changes: Vec<Change>,
+
+    /// Differences that cannot be applied since the program does not support changing
+    /// them once the challenge was created.
+    pub conflicts: Vec<Conflict>,
+
+    /// Set if the challenge should start at this unix timestamp which is not reached yet.
+    pub deferred_start: Option<i64>,
+
+    /// The instructions performing the changes, each needs to be sent in a separate
+    /// transaction in order.
+    pub instructions: Vec<Instruction>,
+}
+
+impl Plan {
+    /// Whether applying the plan changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Compares the [manifest] with the [on_chain] challenge of the [creator] and plans the
+/// instructions that bring the challenge in line with it.
+/// Applying the returned plan and planning again results in an empty plan which makes
+/// it safe to apply the same manifest repeatedly.
+///
+/// * [solutions]: the solutions of the manifest, see [Manifest::read_solutions]
+/// * [on_chain]: the current challenge or `None` if it was not created yet
+/// * [now]: the current unix timestamp to check the [Schedule] against
+pub fn plan(
+    manifest: &Manifest,
+    solutions: &[String],
+    payer: Pubkey,
+    creator: Pubkey,
+    on_chain: Option<&Challenge>,
+    now: i64,
+) -> Result<Plan, ProgramError> {
+    let solutions = solutions.iter().map(String::as_str).collect::<Vec<_>>();
+    let mut changes = vec![];
+    let mut conflicts = vec![];
+    let mut instructions = vec![];
+
+    let started = match on_chain {
+        None => {
+            changes.push(Change::Create {
+                solutions: solutions.len(),
+            });
+            instructions.extend(ixs::create_challenge_chunked(
+                payer,
+                creator,
+                manifest.id.clone(),
+                manifest.admit_cost,
+                manifest.tries_per_admit,
+                solutions,
+                CreateChallengeOptions {
+                    normalization: manifest.normalization(),
+                    soulbound: manifest
+                        .soulbound
+                        .then(SoulboundRedeem::default),
+                    ..Default::default()
+                },
+            )?);
+            false
+        }
+        Some(challenge) => {
+            diff_params(manifest, challenge, &mut conflicts);
+            let (added, solution_conflicts) =
+                diff_solutions(&solutions, challenge);
+            conflicts.extend(solution_conflicts);
+            if !added.is_empty() {
+                changes.push(Change::AddSolutions {
+                    from_index: challenge.solutions.len(),
+                    count: added.len(),
+                });
+                instructions.extend(ixs::add_challenge_solutions_chunked(
+                    payer, challenge, added,
+                )?);
+            }
+            challenge.started
+        }
+    };
+
+    let mut deferred_start = None;
+    if !started && manifest.schedule.start {
+        if manifest.starts_at(now) {
+            changes.push(Change::Start);
+            instructions
+                .push(ixs::start_challenge(creator, manifest.id.clone())?);
+        } else {
+            deferred_start = manifest.schedule.start_at;
+        }
+    }
+
+    Ok(Plan {
+        changes,
+        conflicts,
+        deferred_start,
+        instructions,
+    })
+}
+
+fn diff_params(
+    manifest: &Manifest,
+    challenge: &Challenge,
+    conflicts: &mut Vec<Conflict>,
+) {
+    if challenge.cancelled {
+        conflicts.push(Conflict::Cancelled);
+    }
+    if manifest.admit_cost != challenge.admit_cost {
+        conflicts.push(Conflict::AdmitCost {
+            manifest: manifest.admit_cost,
+            on_chain: challenge.admit_cost,
+        });
+    }
+    if manifest.tries_per_admit != challenge.tries_per_admit {
+        conflicts.push(Conflict::TriesPerAdmit {
+            manifest: manifest.tries_per_admit,
+            on_chain: challenge.tries_per_admit,
+        });
+    }
+    if manifest.normalization() != challenge.normalization {
+        conflicts.push(Conflict::Normalization);
+    }
+    if manifest.soulbound != challenge.soulbound {
+        conflicts.push(Conflict::Soulbound {
+            manifest: manifest.soulbound,
+            on_chain: challenge.soulbound,
+        });
+    }
+}
+
+/// Returns the solutions of the manifest missing on chain together with conflicts of
+/// solutions that differ from the ones stored.
+/// Solutions are hashed the way the challenge stores them in order to compare them.
+fn diff_solutions<'a>(
+    solutions: &[&'a str],
+    challenge: &Challenge,
+) -> (Vec<&'a str>, Vec<Conflict>) {
+    let on_chain = &challenge.solutions;
+    if on_chain.len() > solutions.len() {
+        return (
+            vec![],
+            vec![Conflict::MissingSolutions {
+                manifest: solutions.len(),
+                on_chain: on_chain.len(),
+            }],
+        );
+    }
+
+    let hashed = hash_stored_solutions(
+        &solutions[..on_chain.len()],
+        &challenge.normalization,
+        &challenge.pda().0,
+        challenge.hashing.as_ref(),
+        0,
+    );
+    let conflicts = hashed
+        .iter()
+        .zip(on_chain)
+        .enumerate()
+        .filter(|(_, (hashed, stored))| hashed != stored)
+        .map(|(index, _)| Conflict::SolutionMismatch { index })
+        .collect::<Vec<_>>();
+
+    (solutions[on_chain.len()..].to_vec(), conflicts)
+}
@@ -0,0 +1,65 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{program::set_return_data, pubkey::Pubkey};
+
+use crate::challenge_id;
+
+/// Size of a serialized [RedeemOutcome].
+pub const REDEEM_OUTCOME_SIZE: usize = 1 /* result */
+    + 1 /* solution_index */
+    + 1 /* tries_remaining */
+    + 1 /* finished */;
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+pub enum RedeemResult {
+    Correct,
+    Incorrect,
+}
+
+/// Describes the outcome of a Redeem instruction.
+/// It is set as the return data of the transaction and can be decoded via
+/// [RedeemOutcome::from_return_data].
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+pub struct RedeemOutcome {
+    /// Whether the provided solution was correct
+    pub result: RedeemResult,
+
+    /// The index of the solution the challenger attempted to solve
+    pub solution_index: u8,
+
+    /// The tries the challenger has remaining after this attempt
+    pub tries_remaining: u8,
+
+    /// Whether this attempt finished the challenge
+    pub finished: bool,
+}
+
+impl RedeemOutcome {
+    pub fn is_correct(&self) -> bool {
+        self.result == RedeemResult::Correct
+    }
+
+    /// Sets the outcome as return data of the transaction, only use on-chain.
+    pub fn set_return_data(&self) {
+        // Serializing the outcome into a vec cannot fail
+        let data = self.try_to_vec().unwrap();
+        set_return_data(&data);
+    }
+
+    /// Decodes the outcome from the return data of a transaction.
+    /// Returns `None` if the return data was not set by this program or is not an outcome.
+    ///
+    /// The runtime strips trailing zeros from return data, thus they are restored
+    /// before the outcome is deserialized.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &challenge_id() || data.len() > REDEEM_OUTCOME_SIZE {
+            return None;
+        }
+        let mut buf = [0u8; REDEEM_OUTCOME_SIZE];
+        buf[..data.len()].copy_from_slice(data);
+        RedeemOutcome::try_from_slice(&buf).ok()
+    }
+}
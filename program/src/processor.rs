@@ -3,24 +3,46 @@ use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
+    program_error::ProgramError,
     pubkey::Pubkey,
+    rent::Rent,
+    sysvar::Sysvar,
 };
 
 use crate::{
     challenge_id, check_id,
+    error::ChallengeError,
+    events::ChallengeEvent,
+    hook::PostSolveHook,
     ixs::ChallengeInstruction,
+    outcome::{RedeemOutcome, RedeemResult},
     state::{
-        Challenge, Challenger, HasPda, HasSize, Redeem,
-        StateFromPdaAccountValue, TryStateFromAccount,
+        Challenge, ChallengeView, Challenger, FeeRecipient, HasPda, HasSize,
+        Pot, ProgramConfig, Redeem, SolutionHashing, SolutionNormalization,
+        SoulboundRedeem, StateFromPdaAccountValue, TryStateFromAccount,
+        TryStateFromAccountUnchecked, CHALLENGE_DISCRIMINATOR,
+        SOLUTION_HASHING_SIZE,
     },
     utils::{
         allocate_account_and_assign_owner, assert_account_does_not_exist,
-        assert_account_has_no_data, assert_adding_non_empty,
-        assert_can_add_solutions, assert_challenger_has_tries_remaining,
-        assert_has_solution, assert_has_solutions, assert_is_signer,
+        assert_account_has_no_data, assert_account_is_funded_and_has_data,
+        assert_adding_non_empty, assert_can_add_solutions,
+        assert_can_reopen_solution, assert_cancelled,
+        assert_challenge_migrated, assert_challenger_has_tries_remaining,
+        assert_challenger_holds_required_token,
+        assert_challenger_is_allowlisted, assert_challenger_migrated,
+        assert_challenger_solved, assert_escrows_admit_fees,
+        assert_fee_recipient, assert_finished, assert_has_solution,
+        assert_has_solutions, assert_hook_program, assert_is_signer,
         assert_keys_equal, assert_max_supported_solutions, assert_not_finished,
-        assert_not_started, assert_started, create_mint, mint_token_to_recvr,
-        reallocate_account, transfer_lamports, AllocateAndAssignAccountArgs,
+        assert_not_paused, assert_not_started, assert_owned_by_program,
+        assert_permanent_delegate, assert_pot, assert_pot_with_bump,
+        assert_started, assert_upgrade_authority, assert_valid_escrow,
+        assert_valid_fee_recipients, assert_valid_hook_program,
+        assert_valid_jackpot, assert_valid_protocol_fee,
+        assert_valid_solution_hashing, burn_token_of_owner, create_mint,
+        mint_token_to_recvr, next_optional_account_info, reallocate_account,
+        transfer_lamports, AllocateAndAssignAccountArgs, BurnTokenArgs,
         CreateMintArgs, MintTokenArgs, ReallocateAccountArgs,
     },
     Solution,
@@ -39,6 +61,17 @@ pub fn process<'a>(
     let instruction = ChallengeInstruction::try_from_slice(instruction_data)?;
 
     use ChallengeInstruction::*;
+
+    // Managing the config itself needs to be possible while the program is paused
+    let config = match instruction {
+        InitConfig { .. } | UpdateConfig { .. } => None,
+        _ => {
+            let config = program_config(&instruction, accounts)?;
+            assert_not_paused(config.as_ref())?;
+            config
+        }
+    };
+
     match instruction {
         CreateChallenge {
             id,
@@ -46,6 +79,11 @@ pub fn process<'a>(
             tries_per_admit,
             redeem,
             solutions,
+            fee_recipients,
+            capacity,
+            hashing,
+            normalization,
+            soulbound,
         } => process_create_challenge(
             program_id,
             accounts,
@@ -54,6 +92,11 @@ pub fn process<'a>(
             tries_per_admit,
             redeem,
             solutions,
+            fee_recipients,
+            capacity,
+            hashing,
+            normalization,
+            soulbound,
         ),
         AddSolutions { id, solutions } => {
             process_add_solutions(program_id, accounts, id, solutions)
@@ -61,16 +104,109 @@ pub fn process<'a>(
         StartChallenge { id } => {
             process_start_challenge(program_id, accounts, id)
         }
-        AdmitChallenger { challenge_pda } => {
-            process_admit_challenger(program_id, accounts, challenge_pda)
-        }
+        AdmitChallenger {
+            challenge_pda,
+            admission_proof,
+        } => process_admit_challenger(
+            program_id,
+            accounts,
+            config,
+            challenge_pda,
+            admission_proof,
+        ),
         Redeem { solution } => process_redeem(program_id, accounts, solution),
+        SetAdmissionRoot { id, admission_root } => {
+            process_set_admission_root(program_id, accounts, id, admission_root)
+        }
+        SetRequiredToken {
+            id,
+            required_mint,
+            required_amount,
+        } => process_set_required_token(
+            program_id,
+            accounts,
+            id,
+            required_mint,
+            required_amount,
+        ),
+        SetJackpot { id, jackpot } => {
+            process_set_jackpot(program_id, accounts, id, jackpot)
+        }
+        SetEscrow { id, escrow } => {
+            process_set_escrow(program_id, accounts, id, escrow)
+        }
+        CancelChallenge { id } => {
+            process_cancel_challenge(program_id, accounts, id)
+        }
+        ClaimRefund => process_claim_refund(program_id, accounts, config),
+        WithdrawEscrow { id } => {
+            process_withdraw_escrow(program_id, accounts, config, id)
+        }
+        InitConfig {
+            protocol_fee_basis_points,
+            treasury,
+        } => process_init_config(
+            program_id,
+            accounts,
+            protocol_fee_basis_points,
+            treasury,
+        ),
+        UpdateConfig {
+            admin,
+            protocol_fee_basis_points,
+            treasury,
+            paused,
+        } => process_update_config(
+            program_id,
+            accounts,
+            admin,
+            protocol_fee_basis_points,
+            treasury,
+            paused,
+        ),
+        SetHookProgram { id, hook_program } => {
+            process_set_hook_program(program_id, accounts, id, hook_program)
+        }
+        RevokeSolve {
+            id,
+            solution_index,
+            reopen,
+        } => process_revoke_solve(
+            program_id,
+            accounts,
+            id,
+            solution_index,
+            reopen,
+        ),
+        MigrateChallenger => process_migrate_challenger(program_id, accounts),
+        MigrateChallenge => process_migrate_challenge(program_id, accounts),
+    }
+}
+
+/// The program config is passed at its declared index, followed by the accounts that
+/// are not declared, i.e. fee recipients.
+fn program_config(
+    instruction: &ChallengeInstruction,
+    accounts: &[AccountInfo],
+) -> Result<Option<ProgramConfig>, ProgramError> {
+    let config_info = instruction
+        .account_attrs()
+        .iter()
+        .position(|attr| attr.name == "program_config")
+        .and_then(|idx| accounts.get(idx));
+    match config_info {
+        Some(config_info) => ProgramConfig::from_config_account(config_info),
+        None => {
+            msg!("Err: program config account was not provided");
+            Err(ChallengeError::ProgramConfigAccountMissing.into())
+        }
     }
 }
 
 // -----------------
 // Create Challenge
 // -----------------
+#[allow(clippy::too_many_arguments)]
 fn process_create_challenge<'a>(
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'a>],
@@ -79,18 +215,30 @@ fn process_create_challenge<'a>(
     tries_per_admit: u8,
     redeem: Pubkey,
     solutions: Vec<Solution>,
+    fee_recipients: Vec<FeeRecipient>,
+    capacity: u8,
+    hashing: Option<SolutionHashing>,
+    normalization: SolutionNormalization,
+    soulbound: Option<SoulboundRedeem>,
 ) -> ProgramResult {
     msg!("IX: create challenge");
 
-    assert_keys_equal(program_id, &challenge_id(), || {
-        format!(
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
             "Provided program id ({}) does not match this program's id ({})",
             program_id,
             challenge_id()
         )
-    })?;
+        },
+    )?;
 
     assert_max_supported_solutions(&solutions)?;
+    assert_valid_fee_recipients(&fee_recipients)?;
+    assert_valid_solution_hashing(hashing.as_ref())?;
 
     // TODO(thlorenz): think about if we need to ensure that we don't allow
     // pre-initialized accounts.
@@ -108,30 +256,45 @@ fn process_create_challenge<'a>(
     let redeem_pda_info = next_account_info(account_info_iter)?;
     let spl_token_program_info = next_account_info(account_info_iter)?;
 
-    assert_keys_equal(redeem_pda_info.key, &redeem, || {
-        format!(
-            "Provided redeem_account ({}) does not redeem key passed ({})",
-            redeem_pda_info.key, redeem
-        )
-    })?;
+    assert_keys_equal(
+        redeem_pda_info.key,
+        &redeem,
+        ChallengeError::IncorrectRedeemMint,
+        || {
+            format!(
+                "Provided redeem_account ({}) does not redeem key passed ({})",
+                redeem_pda_info.key, redeem
+            )
+        },
+    )?;
 
     // Create Challenge PDA account
-    {
+    let bump = {
         let (challenge_pda, bump) =
             Challenge::shank_pda(&challenge_id(), creator_info.key, &id);
         let bump_arr = [bump];
         let challenge_seeds =
             Challenge::shank_seeds_with_bump(creator_info.key, &id, &bump_arr);
 
-        assert_keys_equal(challenge_pda_info.key, &challenge_pda, || {
-            format!(
+        assert_keys_equal(
+            challenge_pda_info.key,
+            &challenge_pda,
+            ChallengeError::IncorrectPda,
+            || {
+                format!(
                 "PDA for the challenge for creator ({}) and id ({}) is incorrect",
                 creator_info.key, id
             )
-        })?;
+            },
+        )?;
         assert_account_has_no_data(challenge_pda_info)?;
 
-        let size = Challenge::needed_size(&solutions, &id);
+        let size =
+            Challenge::needed_size_with_capacity(&solutions, &id, capacity)
+                + Challenge::space_to_store_n_fee_recipients(
+                    fee_recipients.len(),
+                )
+                + hashing.map_or(0, |_| SOLUTION_HASHING_SIZE);
         allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
             payer_info,
             account_info: challenge_pda_info,
@@ -139,7 +302,8 @@ fn process_create_challenge<'a>(
             signer_seeds: &challenge_seeds,
             size,
         })?;
-    }
+        bump
+    };
 
     // Create redeem mint
     {
@@ -149,12 +313,17 @@ fn process_create_challenge<'a>(
         let redeem_seeds =
             Redeem::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
 
-        assert_keys_equal(redeem_pda_info.key, &redeem_pda, || {
-            format!(
+        assert_keys_equal(
+            redeem_pda_info.key,
+            &redeem_pda,
+            ChallengeError::IncorrectRedeemMint,
+            || {
+                format!(
                 "PDA for the challenge redeem ('{}') is incorrect, should be '{}'",
                 redeem_pda_info.key, redeem_pda
             )
-        })?;
+            },
+        )?;
         assert_account_has_no_data(redeem_pda_info)?;
         create_mint(CreateMintArgs {
             payer_info,
@@ -162,12 +331,16 @@ fn process_create_challenge<'a>(
             mint_authority_info: challenge_pda_info,
             spl_token_program_info,
             signer_seeds: &redeem_seeds,
+            soulbound: soulbound.as_ref(),
         })?;
     }
 
     // Serialize Challenge
     let challenge = Challenge {
+        discriminator: CHALLENGE_DISCRIMINATOR,
         authority: *creator_info.key,
+        bump,
+        pot_bump: 0,
         id,
         started: false,
         finished: false,
@@ -175,6 +348,18 @@ fn process_create_challenge<'a>(
         tries_per_admit,
         redeem,
         solving: 0,
+        admission_root: None,
+        required_mint: None,
+        required_amount: 0,
+        normalization,
+        soulbound: soulbound.is_some(),
+        escrowed_tries: 0,
+        fee_recipients,
+        jackpot: None,
+        escrow: false,
+        cancelled: false,
+        hook_program: None,
+        hashing,
         solutions,
     };
 
@@ -183,6 +368,15 @@ fn process_create_challenge<'a>(
     )?;
 
     msg!("Challenge account created and initialized");
+    ChallengeEvent::Created {
+        challenge_pda: *challenge_pda_info.key,
+        creator: challenge.authority,
+        id: challenge.id,
+        admit_cost,
+        tries_per_admit,
+        solutions: challenge.solutions.len() as u8,
+    }
+    .emit();
 
     Ok(())
 }
@@ -198,13 +392,18 @@ fn process_add_solutions<'a>(
 ) -> ProgramResult {
     msg!("IX: add solutions");
 
-    assert_keys_equal(program_id, &challenge_id(), || {
-        format!(
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
             "Provided program id ({}) does not match this program's id ({})",
             program_id,
             challenge_id()
         )
-    })?;
+        },
+    )?;
     assert_adding_non_empty(&extra_solutions)?;
 
     let account_info_iter = &mut accounts.iter();
@@ -221,23 +420,28 @@ fn process_add_solutions<'a>(
         &id,
     )?;
 
-    // 1. append solutions
+    // 1. append solutions, using up the space reserved for them first
     assert_can_add_solutions(&challenge.solutions, &extra_solutions)?;
+    let added = extra_solutions.len() as u8;
+    let reserved = challenge
+        .reserved_space(challenge_pda_info.data_len())
+        .saturating_sub(Challenge::space_to_store_n_solutions(added));
     challenge.solutions.extend(extra_solutions);
 
     // 2. reallocate account to fit extra solutions, including upping lamports to stay rent excempt
-    let size = challenge.size();
-    reallocate_account(ReallocateAccountArgs {
-        payer_info,
-        account_info: challenge_pda_info,
-        new_size: size,
-        zero_init: false,
-    })?;
+    reallocate_challenge(payer_info, challenge_pda_info, &challenge, reserved)?;
 
     challenge.serialize(
         &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
     )?;
 
+    ChallengeEvent::SolutionsAdded {
+        challenge_pda: *challenge_pda_info.key,
+        added,
+        solutions: challenge.solutions.len() as u8,
+    }
+    .emit();
+
     // TODO(thlorenz): unfinish, if we now can redeem b providing solutions again
 
     Ok(())
@@ -253,13 +457,18 @@ fn process_start_challenge(
 ) -> ProgramResult {
     msg!("IX: start challenge");
 
-    assert_keys_equal(program_id, &challenge_id(), || {
-        format!(
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
             "Provided program id ({}) does not match this program's id ({})",
             program_id,
             challenge_id()
         )
-    })?;
+        },
+    )?;
 
     let account_info_iter = &mut accounts.iter();
     let creator_info = next_account_info(account_info_iter)?;
@@ -282,6 +491,11 @@ fn process_start_challenge(
         &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
     )?;
 
+    ChallengeEvent::Started {
+        challenge_pda: *challenge_pda_info.key,
+    }
+    .emit();
+
     Ok(())
 }
 
@@ -291,17 +505,24 @@ fn process_start_challenge(
 fn process_admit_challenger<'a>(
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'a>],
+    config: Option<ProgramConfig>,
     challenge_pda: Pubkey,
+    admission_proof: Vec<[u8; 32]>,
 ) -> ProgramResult {
     msg!("IX: admit challenger");
 
-    assert_keys_equal(program_id, &challenge_id(), || {
-        format!(
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
             "Provided program id ({}) does not match this program's id ({})",
             program_id,
             challenge_id()
         )
-    })?;
+        },
+    )?;
 
     let account_info_iter = &mut accounts.iter();
     let payer_info = next_account_info(account_info_iter)?;
@@ -309,18 +530,42 @@ fn process_admit_challenger<'a>(
     let challenge_pda_info = next_account_info(account_info_iter)?;
     let challenger_info = next_account_info(account_info_iter)?;
     let challenger_pda_info = next_account_info(account_info_iter)?;
+    let _system_program_info = next_account_info(account_info_iter)?;
+    // only provided if the challenge requires challengers to hold a token
+    let challenger_token_info = next_optional_account_info(account_info_iter)?;
+    // only provided if the program charges a protocol fee
+    let treasury_info = next_optional_account_info(account_info_iter)?;
+    let _program_config_info = next_account_info(account_info_iter)?;
 
-    assert_keys_equal(challenge_pda_info.key, &challenge_pda, || {
-        format!(
+    assert_keys_equal(
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
             "Provided challenge pda ({}) does not match the PDA account ({}) provided in the instruction",
             challenge_pda, challenge_pda_info.key
         )
-    })?;
+        },
+    )?;
     assert_account_does_not_exist(challenger_pda_info, "challenger PDA")?;
 
-    let challenge: Challenge = challenge_pda_info.try_state_from_account()?;
+    assert_challenge_migrated(challenge_pda_info)?;
+    let challenge: Challenge =
+        challenge_pda_info.try_state_from_account_unchecked()?;
+
     assert_started(&challenge)?;
     assert_not_finished(&challenge)?;
+    assert_challenger_is_allowlisted(
+        &challenge,
+        challenger_info.key,
+        &admission_proof,
+    )?;
+    assert_challenger_holds_required_token(
+        &challenge,
+        challenger_info.key,
+        challenger_token_info,
+    )?;
 
     // 1. create challenger account
     let (pda, bump) = Challenger::shank_pda(
@@ -329,12 +574,17 @@ fn process_admit_challenger<'a>(
         challenger_info.key,
     );
 
-    assert_keys_equal(challenger_pda_info.key, &pda, || {
-        format!(
+    assert_keys_equal(
+        challenger_pda_info.key,
+        &pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
             "PDA account ({}) provided for the challenger is not a valid for this challenge",
             challenger_pda_info.key
         )
-    })?;
+        },
+    )?;
 
     let bump_arr = [bump];
     let seeds = Challenger::shank_seeds_with_bump(
@@ -353,19 +603,79 @@ fn process_admit_challenger<'a>(
     })?;
 
     // 2. initialize challenger account using data from the challenge
-    let challenger = Challenger {
-        authority: *challenger_info.key,
+    let challenger = Challenger::new(
+        *challenger_info.key,
         challenge_pda,
-        tries_remaining: challenge.tries_per_admit,
-        redeemed: false,
-    };
+        challenge.tries_per_admit,
+    );
 
     challenger.serialize(
         &mut &mut challenger_pda_info.try_borrow_mut_data()?.as_mut(),
     )?;
 
-    // 3. transfer admit cost to creator account
-    transfer_lamports(payer_info, creator_info, challenge.admit_cost)?;
+    // 3. take the protocol fee from the admit cost
+    if let Some(config) = config
+        .as_ref()
+        .filter(|config| config.protocol_fee_basis_points > 0)
+    {
+        let treasury_info = treasury_info.ok_or_else(|| {
+            msg!("Err: treasury is required when charging a protocol fee");
+            ChallengeError::IncorrectTreasury
+        })?;
+        assert_keys_equal(
+            treasury_info.key,
+            &config.treasury,
+            ChallengeError::IncorrectTreasury,
+            || {
+                format!(
+                "Provided treasury ({}) does not match the treasury ({}) of the program config",
+                treasury_info.key, config.treasury
+            )
+            },
+        )?;
+        let protocol_fee = config.protocol_fee(challenge.admit_cost);
+        if protocol_fee > 0 {
+            transfer_lamports(payer_info, treasury_info, protocol_fee)?;
+        }
+    }
+
+    // 4. escrow the remaining admit fees in the pot if configured or in jackpot mode,
+    //    otherwise transfer their shares to fee recipients and the rest to creator account
+    let admit_fees = challenge.admit_fees(config.as_ref());
+    if challenge.escrows_admit_fees() {
+        let pot_info = next_account_info(account_info_iter)?;
+        assert_pot(&challenge, pot_info)?;
+        if admit_fees > 0 {
+            transfer_lamports(payer_info, pot_info, admit_fees)?;
+        }
+        // track the tries paid into the pot to know what is owed if it is cancelled
+        let mut data = challenge_pda_info.try_borrow_mut_data()?;
+        let view = ChallengeView::from_data(&mut data)?;
+        view.header.escrowed_tries = { view.header.escrowed_tries }
+            .saturating_add(challenge.tries_per_admit as u32);
+    } else {
+        let (shares, creator_share) = challenge.admit_fee_shares(admit_fees);
+        for (fee_recipient, share) in
+            challenge.fee_recipients.iter().zip(shares)
+        {
+            // the remaining accounts are the fee recipients of the challenge
+            let fee_recipient_info = next_account_info(account_info_iter)?;
+            assert_fee_recipient(fee_recipient_info, fee_recipient)?;
+            if share > 0 {
+                transfer_lamports(payer_info, fee_recipient_info, share)?;
+            }
+        }
+        if creator_share > 0 {
+            transfer_lamports(payer_info, creator_info, creator_share)?;
+        }
+    }
+
+    ChallengeEvent::Admitted {
+        challenge_pda,
+        challenger: *challenger_info.key,
+        admit_cost: challenge.admit_cost,
+    }
+    .emit();
 
     Ok(())
 }
@@ -373,6 +683,18 @@ fn process_admit_challenger<'a>(
 // -----------------
 // Redeem by proposing solution
 // -----------------
+/// The parts of a challenge needed to complete a redeem after it was updated in place.
+struct RedeemedChallenge {
+    authority: Pubkey,
+    id: String,
+    bump: u8,
+    solution_index: u8,
+    solved: bool,
+    finished: bool,
+    payout: u64,
+    hook_program: Option<Pubkey>,
+}
+
 fn process_redeem<'a>(
     program_id: &'a Pubkey,
     accounts: &'a [AccountInfo<'a>],
@@ -380,13 +702,18 @@ fn process_redeem<'a>(
 ) -> ProgramResult {
     msg!("IX: redeem");
 
-    assert_keys_equal(program_id, &challenge_id(), || {
-        format!(
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
             "Provided program id ({}) does not match this program's id ({})",
             program_id,
             challenge_id()
         )
-    })?;
+        },
+    )?;
 
     let account_info_iter = &mut accounts.iter();
 
@@ -403,16 +730,28 @@ fn process_redeem<'a>(
 
     // programs
     let spl_token_program_info = next_account_info(account_info_iter)?;
+    let _spl_associated_token_program_info =
+        next_account_info(account_info_iter)?;
+    let _system_program_info = next_account_info(account_info_iter)?;
+
+    // only used if the challenge is in jackpot mode or has a hook program respectively
+    let pot_info = next_account_info(account_info_iter)?;
+    let hook_program_info = next_account_info(account_info_iter)?;
+    let _program_config_info = next_account_info(account_info_iter)?;
+    // all remaining accounts are passed through to the hook program
+    let hook_account_infos = account_info_iter.as_slice();
 
     assert_is_signer(payer_info, "payer")?;
     assert_is_signer(challenger_info, "challenger")?;
 
+    assert_challenger_migrated(challenger_pda_info)?;
     let mut challenger: Challenger =
         challenger_pda_info.try_state_from_account()?;
 
     assert_keys_equal(
         &challenger.challenge_pda,
         challenge_pda_info.key,
+        ChallengeError::ChallengerChallengeMismatch,
         || {
             format!(
             "Challenge pda ({}) of provided callenger does not match the PDA account ({}) for which you are trying to redeem",
@@ -420,42 +759,125 @@ fn process_redeem<'a>(
         )
         },
     )?;
+    assert_keys_equal(
+        &challenger.authority,
+        challenger_info.key,
+        ChallengeError::IncorrectAuthority,
+        || {
+            format!(
+            "Challenger's authority ({}) does not match provided challenger ({})",
+            challenger.authority, challenger_info.key
+        )
+        },
+    )?;
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge_id(),
+        challenge_pda_info.key,
+        challenger_info.key,
+    );
+    assert_keys_equal(
+        challenger_pda_info.key,
+        &challenger_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided challenger PDA ({}) is not the PDA of the challenger ({})",
+            challenger_pda_info.key, challenger_pda
+        )
+        },
+    )?;
 
-    let mut challenge: Challenge =
-        challenge_pda_info.try_state_from_account()?;
-
-    // TODO(thlorenz): Technically the challenger would not have been admitted if the challenge
-    // wasn't already started, so might not need this check
-    assert_started(&challenge)?;
-    assert_not_finished(&challenge)?;
+    // The challenge is accessed in place as deserializing and serializing it entirely
+    // would make redeeming more expensive the more solutions it has
+    assert_account_is_funded_and_has_data(challenge_pda_info)?;
+    let RedeemedChallenge {
+        authority,
+        id,
+        bump,
+        solution_index,
+        solved,
+        finished,
+        payout,
+        hook_program,
+    } = {
+        let mut data = challenge_pda_info.try_borrow_mut_data()?;
+        let challenge = ChallengeView::from_data(&mut data)?;
+
+        // TODO(thlorenz): Technically the challenger would not have been admitted if the challenge
+        // wasn't already started, so might not need this check
+        assert_started(&challenge)?;
+        assert_not_finished(&challenge)?;
+
+        assert_keys_equal(
+            redeem_info.key,
+            &challenge.header.redeem,
+            ChallengeError::IncorrectRedeemMint,
+            || {
+                format!(
+                "Provided redeem ({}) does not match the redeem ({}) for the challenge",
+                redeem_info.key, challenge.header.redeem
+            )
+            },
+        )?;
 
-    assert_keys_equal(redeem_info.key, &challenge.redeem, || {
-        format!(
-            "Provided redeem ({}) does not match the redeem ({}) for the challenge",
-            redeem_info.key, challenge.redeem
-        )
-    })?;
+        assert_challenger_has_tries_remaining(&challenger)?;
+        assert_has_solution(&challenge)?;
+
+        let solution_index = challenge.header.solving;
+        let solved =
+            challenge.is_solution_correct(challenge_pda_info.key, &solution);
+        if solved {
+            // update challenge
+            challenge.header.solving += 1;
+            let finished = challenge.current_solution().is_none();
+            challenge.header.set_finished(finished);
+            if finished {
+                msg!("Challenge finished, no more player will be admitted or solutions accepted");
+            }
+        }
 
-    assert_challenger_has_tries_remaining(&challenger)?;
-    assert_has_solution(&challenge)?;
+        // the pot stays rent exempt when paying out the jackpot
+        let payout = match challenge.jackpot {
+            Some(_) if solved => {
+                assert_pot_with_bump(
+                    challenge_pda_info.key,
+                    challenge.header.pot_bump,
+                    pot_info,
+                )?;
+                let rent_exempt_lamports = Rent::get()?.minimum_balance(0);
+                challenge
+                    .jackpot_payout(pot_info.lamports(), rent_exempt_lamports)
+            }
+            _ => 0,
+        };
+
+        // the used try is no longer refunded if the challenge is cancelled
+        if challenge.header.escrow() || challenge.jackpot.is_some() {
+            challenge.header.escrowed_tries =
+                { challenge.header.escrowed_tries }.saturating_sub(1);
+        }
 
-    if challenge.is_solution_correct(&solution) {
-        // update challenge
-        challenge.solving += 1;
-        challenge.finished = challenge.current_solution().is_none();
-        if challenge.finished {
-            msg!("Challenge finished, no more player will be admitted or solutions accepted");
+        RedeemedChallenge {
+            authority: challenge.header.authority,
+            id: challenge.id.to_string(),
+            bump: challenge.header.bump,
+            solution_index,
+            solved,
+            finished: challenge.header.finished(),
+            payout,
+            hook_program: challenge.hook_program,
         }
-        challenge.serialize(
-            &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
-        )?;
+    };
 
+    if solved {
         // update challenger
         challenger.redeemed = true;
+        challenger.mark_solved(solution_index);
 
-        let (_, bump) = challenge.pda();
+        // the stored bump avoids deriving the challenge PDA
         let bump_arr = [bump];
-        let challenge_seeds = challenge.seeds(&bump_arr);
+        let challenge_seeds =
+            Challenge::shank_seeds_with_bump(&authority, &id, &bump_arr);
 
         mint_token_to_recvr(MintTokenArgs {
             payer_info,
@@ -466,6 +888,25 @@ fn process_redeem<'a>(
             spl_token_program_info,
             signer_seeds: &challenge_seeds,
         })?;
+
+        if payout > 0 {
+            msg!("Paying out {} lamports from the pot", payout);
+            **pot_info.try_borrow_mut_lamports()? -= payout;
+            **challenger_info.try_borrow_mut_lamports()? += payout;
+        }
+
+        ChallengeEvent::Solved {
+            challenge_pda: *challenge_pda_info.key,
+            challenger: *challenger_info.key,
+            solution_index,
+        }
+        .emit();
+        if finished {
+            ChallengeEvent::Finished {
+                challenge_pda: *challenge_pda_info.key,
+            }
+            .emit();
+        }
     } else {
         msg!("Provided solution was incorrect");
     }
@@ -476,5 +917,1114 @@ fn process_redeem<'a>(
         &mut &mut challenger_pda_info.try_borrow_mut_data()?.as_mut(),
     )?;
 
+    // invoke the hook once the challenger reflects the solve so that it can be verified
+    if let (true, Some(hook_program)) = (solved, hook_program) {
+        assert_hook_program(&hook_program, hook_program_info)?;
+
+        msg!("Invoking hook program {}", hook_program);
+        PostSolveHook {
+            challenge_pda: *challenge_pda_info.key,
+            challenger: *challenger_info.key,
+            solution_index,
+        }
+        .invoke(
+            hook_program_info,
+            challenge_pda_info,
+            challenger_info,
+            challenger_pda_info,
+            hook_account_infos,
+        )?;
+    }
+
+    if !solved {
+        ChallengeEvent::AttemptFailed {
+            challenge_pda: *challenge_pda_info.key,
+            challenger: *challenger_info.key,
+            tries_remaining: challenger.tries_remaining,
+        }
+        .emit();
+    }
+
+    RedeemOutcome {
+        result: if solved {
+            RedeemResult::Correct
+        } else {
+            RedeemResult::Incorrect
+        },
+        solution_index,
+        tries_remaining: challenger.tries_remaining,
+        finished,
+    }
+    .set_return_data();
+
+    Ok(())
+}
+
+// -----------------
+// Set Admission Root
+// -----------------
+fn process_set_admission_root<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    admission_root: Option<[u8; 32]>,
+) -> ProgramResult {
+    msg!("IX: set admission root");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_not_finished(&challenge)?;
+
+    let reserved = challenge.reserved_space(challenge_pda_info.data_len());
+
+    // 1. replace the root
+    challenge.admission_root = admission_root;
+
+    // 2. resize account since the root is optional, including upping lamports to stay rent excempt
+    reallocate_challenge(payer_info, challenge_pda_info, &challenge, reserved)?;
+
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::AdmissionRootSet {
+        challenge_pda: *challenge_pda_info.key,
+        admission_root,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Set Required Token
+// -----------------
+fn process_set_required_token<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    required_mint: Option<Pubkey>,
+    required_amount: u64,
+) -> ProgramResult {
+    msg!("IX: set required token");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_not_finished(&challenge)?;
+
+    let reserved = challenge.reserved_space(challenge_pda_info.data_len());
+
+    // 1. replace the required token
+    challenge.required_mint = required_mint;
+    challenge.required_amount = required_amount;
+
+    // 2. resize account since the mint is optional, including upping lamports to stay rent excempt
+    reallocate_challenge(payer_info, challenge_pda_info, &challenge, reserved)?;
+
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::RequiredTokenSet {
+        challenge_pda: *challenge_pda_info.key,
+        required_mint,
+        required_amount,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Set Hook Program
+// -----------------
+fn process_set_hook_program<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    hook_program: Option<Pubkey>,
+) -> ProgramResult {
+    msg!("IX: set hook program");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // Challengers are admitted knowing which hook runs when they solve
+    assert_not_started(&challenge)?;
+    assert_valid_hook_program(hook_program.as_ref())?;
+
+    let reserved = challenge.reserved_space(challenge_pda_info.data_len());
+
+    // 1. replace the hook program
+    challenge.hook_program = hook_program;
+
+    // 2. resize account since the hook program is optional, including upping lamports to stay rent excempt
+    reallocate_challenge(payer_info, challenge_pda_info, &challenge, reserved)?;
+
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::HookProgramSet {
+        challenge_pda: *challenge_pda_info.key,
+        hook_program,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Revoke Solve
+// -----------------
+fn process_revoke_solve<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    solution_index: u8,
+    reopen: bool,
+) -> ProgramResult {
+    msg!("IX: revoke solve");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    // challenger
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+
+    // redeem
+    let redeem_info = next_account_info(account_info_iter)?;
+    let redeem_ata_challenger_info = next_account_info(account_info_iter)?;
+    let spl_token_program_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_challenger_migrated(challenger_pda_info)?;
+    let mut challenger: Challenger =
+        challenger_pda_info.try_state_from_account()?;
+    let (challenger_pda, _) = challenger.pda();
+    assert_keys_equal(
+        challenger_pda_info.key,
+        &challenger_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided challenger PDA ({}) is not the PDA of the challenger ({})",
+            challenger_pda_info.key, challenger_pda
+        )
+        },
+    )?;
+    assert_keys_equal(
+        &challenger.authority,
+        challenger_info.key,
+        ChallengeError::IncorrectAuthority,
+        || {
+            format!(
+            "Challenger's authority ({}) does not match provided challenger ({})",
+            challenger.authority, challenger_info.key
+        )
+        },
+    )?;
+    assert_keys_equal(
+        &challenger.challenge_pda,
+        challenge_pda_info.key,
+        ChallengeError::ChallengerChallengeMismatch,
+        || {
+            format!(
+            "Challenge pda ({}) of provided callenger does not match the PDA account ({}) for which you are revoking a solve",
+            &challenger.challenge_pda, challenge_pda_info.key
+        )
+        },
+    )?;
+    assert_keys_equal(
+        redeem_info.key,
+        &challenge.redeem,
+        ChallengeError::IncorrectRedeemMint,
+        || {
+            format!(
+                "Provided redeem mint ({}) does not match the challenge's redeem mint ({})",
+                redeem_info.key, challenge.redeem
+            )
+        },
+    )?;
+
+    assert_permanent_delegate(
+        redeem_info,
+        spl_token_program_info,
+        challenge_pda_info.key,
+    )?;
+    assert_challenger_solved(&challenger, solution_index)?;
+    if reopen {
+        assert_can_reopen_solution(&challenge, solution_index)?;
+    }
+
+    // 1. burn the token the challenger redeemed for the solve
+    let bump_arr = [challenge.bump];
+    let challenge_seeds = challenge.seeds(&bump_arr);
+    burn_token_of_owner(BurnTokenArgs {
+        owner_info: challenger_info,
+        owner_ata_info: redeem_ata_challenger_info,
+        mint_info: redeem_info,
+        delegate_info: challenge_pda_info,
+        spl_token_program_info,
+        signer_seeds: &challenge_seeds,
+    })?;
+
+    // 2. record the revoked solve on the challenger
+    challenger.mark_revoked(solution_index);
+    challenger.serialize(
+        &mut &mut challenger_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    // 3. optionally require the solution to be solved again
+    if reopen {
+        challenge.solving = solution_index;
+        challenge.finished = false;
+        challenge.serialize(
+            &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+        )?;
+    }
+
+    ChallengeEvent::SolveRevoked {
+        challenge_pda: *challenge_pda_info.key,
+        challenger: *challenger_info.key,
+        solution_index,
+        reopened: reopen,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Set Jackpot
+// -----------------
+fn process_set_jackpot<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    jackpot: Option<u16>,
+) -> ProgramResult {
+    msg!("IX: set jackpot");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let pot_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // Once challengers are admitted the admit fees need to stay in the pot
+    assert_not_started(&challenge)?;
+    assert_valid_jackpot(&challenge, jackpot)?;
+
+    // 1. create the pot unless it exists already
+    if jackpot.is_some() {
+        challenge.pot_bump = create_pot_unless_exists(
+            program_id,
+            payer_info,
+            challenge_pda_info,
+            pot_info,
+        )?;
+    }
+
+    let reserved = challenge.reserved_space(challenge_pda_info.data_len());
+
+    // 2. set the jackpot share
+    challenge.jackpot = jackpot;
+
+    // 3. resize account since the jackpot is optional, including upping lamports to stay rent excempt
+    reallocate_challenge(payer_info, challenge_pda_info, &challenge, reserved)?;
+
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::JackpotSet {
+        challenge_pda: *challenge_pda_info.key,
+        jackpot,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Set Escrow
+// -----------------
+fn process_set_escrow<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    id: String,
+    escrow: bool,
+) -> ProgramResult {
+    msg!("IX: set escrow");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let pot_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // Once challengers are admitted the admit fees need to stay in the pot
+    assert_not_started(&challenge)?;
+    assert_valid_escrow(&challenge, escrow)?;
+
+    if escrow {
+        challenge.pot_bump = create_pot_unless_exists(
+            program_id,
+            payer_info,
+            challenge_pda_info,
+            pot_info,
+        )?;
+    }
+
+    challenge.escrow = escrow;
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::EscrowSet {
+        challenge_pda: *challenge_pda_info.key,
+        escrow,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Cancel Challenge
+// -----------------
+fn process_cancel_challenge(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    id: String,
+) -> ProgramResult {
+    msg!("IX: cancel challenge");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: mut challenge,
+        ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    assert_not_finished(&challenge)?;
+
+    // no more challengers are admitted nor solutions accepted from here on
+    challenge.cancelled = true;
+    challenge.finished = true;
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::Cancelled {
+        challenge_pda: *challenge_pda_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Claim Refund
+// -----------------
+fn process_claim_refund(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    config: Option<ProgramConfig>,
+) -> ProgramResult {
+    msg!("IX: claim refund");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let challenger_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+    let pot_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer(challenger_info, "challenger")?;
+
+    assert_challenger_migrated(challenger_pda_info)?;
+    let mut challenger: Challenger =
+        challenger_pda_info.try_state_from_account()?;
+    let (challenger_pda, _) = challenger.pda();
+    assert_keys_equal(
+        challenger_pda_info.key,
+        &challenger_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided challenger PDA ({}) is not the PDA of the challenger ({})",
+            challenger_pda_info.key, challenger_pda
+        )
+        },
+    )?;
+    assert_keys_equal(
+        &challenger.authority,
+        challenger_info.key,
+        ChallengeError::IncorrectAuthority,
+        || {
+            format!(
+            "Challenger's authority ({}) does not match provided challenger ({})",
+            challenger.authority, challenger_info.key
+        )
+        },
+    )?;
+    assert_keys_equal(
+        &challenger.challenge_pda,
+        challenge_pda_info.key,
+        ChallengeError::ChallengerChallengeMismatch,
+        || {
+            format!(
+            "Challenge pda ({}) of provided callenger does not match the PDA account ({}) for which you are claiming a refund",
+            &challenger.challenge_pda, challenge_pda_info.key
+        )
+        },
+    )?;
+
+    assert_challenge_migrated(challenge_pda_info)?;
+    let mut challenge: Challenge =
+        challenge_pda_info.try_state_from_account_unchecked()?;
+
+    assert_cancelled(&challenge)?;
+    assert_escrows_admit_fees(&challenge)?;
+    assert_pot(&challenge, pot_info)?;
+
+    // 1. refund the unused tries, the pot stays rent exempt
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(0);
+    let available = pot_info.lamports().saturating_sub(rent_exempt_lamports);
+    let refund = challenge
+        .refund_for(&challenger, config.as_ref())
+        .min(available);
+    if refund == 0 {
+        msg!(
+            "Err: challenger ({}) has nothing left to refund",
+            challenger_info.key
+        );
+        return Err(ChallengeError::NothingToRefund.into());
+    }
+
+    msg!("Refunding {} lamports from the pot", refund);
+    **pot_info.try_borrow_mut_lamports()? -= refund;
+    **challenger_info.try_borrow_mut_lamports()? += refund;
+
+    // 2. use up the remaining tries so the refund cannot be claimed twice
+    challenge.escrowed_tries = challenge
+        .escrowed_tries
+        .saturating_sub(challenger.tries_remaining as u32);
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    challenger.tries_remaining = 0;
+    challenger.serialize(
+        &mut &mut challenger_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::RefundClaimed {
+        challenge_pda: *challenge_pda_info.key,
+        challenger: *challenger_info.key,
+        refund,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Withdraw Escrow
+// -----------------
+fn process_withdraw_escrow(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    config: Option<ProgramConfig>,
+    id: String,
+) -> ProgramResult {
+    msg!("IX: withdraw escrow");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let creator_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let pot_info = next_account_info(account_info_iter)?;
+
+    let StateFromPdaAccountValue::<Challenge> {
+        state: challenge, ..
+    } = Challenge::account_state_verifying_creator(
+        challenge_pda_info,
+        creator_info,
+        &id,
+    )?;
+
+    // The creator can only withdraw once the challenge finished, if it was cancelled
+    // only what is not owed to challengers as refunds
+    assert_finished(&challenge)?;
+    assert_escrows_admit_fees(&challenge)?;
+    assert_pot(&challenge, pot_info)?;
+
+    // the pot stays rent exempt
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(0);
+    let available = pot_info.lamports().saturating_sub(rent_exempt_lamports);
+    let withdrawal = if challenge.cancelled {
+        available.saturating_sub(challenge.outstanding_refunds(config.as_ref()))
+    } else {
+        available
+    };
+    if withdrawal > 0 {
+        msg!("Withdrawing {} lamports from the pot", withdrawal);
+        **pot_info.try_borrow_mut_lamports()? -= withdrawal;
+        **creator_info.try_borrow_mut_lamports()? += withdrawal;
+    }
+
+    ChallengeEvent::EscrowWithdrawn {
+        challenge_pda: *challenge_pda_info.key,
+        withdrawal,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Migrate Challenger
+// -----------------
+fn process_migrate_challenger<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    msg!("IX: migrate challenger");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenger_pda_info = next_account_info(account_info_iter)?;
+    let _system_program_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer(payer_info, "payer")?;
+    assert_owned_by_program(challenger_pda_info, "challenger")?;
+
+    let (challenger, is_legacy_layout) = {
+        let data = challenger_pda_info.try_borrow_data()?;
+        (
+            Challenger::from_account_data(&data)?,
+            Challenger::is_legacy_layout(&data),
+        )
+    };
+    let (challenger_pda, _) = challenger.pda();
+    assert_keys_equal(
+        challenger_pda_info.key,
+        &challenger_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided challenger PDA ({}) is not the PDA of the challenger ({})",
+            challenger_pda_info.key, challenger_pda
+        )
+        },
+    )?;
+
+    if !is_legacy_layout {
+        msg!("Challenger is stored in the current layout already");
+        return Ok(());
+    }
+
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info: challenger_pda_info,
+        new_size: Challenger::size(),
+        zero_init: false,
+    })?;
+    challenger.serialize(
+        &mut &mut challenger_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::ChallengerMigrated {
+        challenge_pda: challenger.challenge_pda,
+        challenger: challenger.authority,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Migrate Challenge
+// -----------------
+fn process_migrate_challenge<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    msg!("IX: migrate challenge");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let challenge_pda_info = next_account_info(account_info_iter)?;
+    let _system_program_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer(payer_info, "payer")?;
+    assert_owned_by_program(challenge_pda_info, "challenge")?;
+
+    let (challenge, is_legacy_layout) = {
+        let data = challenge_pda_info.try_borrow_data()?;
+        (
+            Challenge::from_account_data(&data)?,
+            Challenge::is_legacy_layout(&data),
+        )
+    };
+    let (challenge_pda, _) = challenge.pda();
+    assert_keys_equal(
+        challenge_pda_info.key,
+        &challenge_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided challenge PDA ({}) is not the PDA of the challenge ({})",
+            challenge_pda_info.key, challenge_pda
+        )
+        },
+    )?;
+
+    if !is_legacy_layout {
+        msg!("Challenge is stored in the current layout already");
+        return Ok(());
+    }
+
+    // keep the space that was reserved for solutions which were not added yet
+    let reserved = challenge_pda_info
+        .data_len()
+        .saturating_sub(challenge.legacy_size());
+    reallocate_challenge(payer_info, challenge_pda_info, &challenge, reserved)?;
+    challenge.serialize(
+        &mut &mut challenge_pda_info.try_borrow_mut_data()?.as_mut(),
+    )?;
+
+    ChallengeEvent::ChallengeMigrated {
+        challenge_pda: *challenge_pda_info.key,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Reallocate Challenge
+// -----------------
+/// Resizes the challenge account to fit the updated challenge while keeping the
+/// [reserved] space for solutions that were not added yet.
+fn reallocate_challenge<'a>(
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    challenge: &Challenge,
+    reserved: usize,
+) -> ProgramResult {
+    reallocate_account(ReallocateAccountArgs {
+        payer_info,
+        account_info: challenge_pda_info,
+        new_size: challenge.size() + reserved,
+        zero_init: false,
+    })
+}
+
+// -----------------
+// Pot
+// -----------------
+/// Creates the pot of the challenge unless it exists already and returns its bump.
+fn create_pot_unless_exists<'a>(
+    program_id: &'a Pubkey,
+    payer_info: &'a AccountInfo<'a>,
+    challenge_pda_info: &'a AccountInfo<'a>,
+    pot_info: &'a AccountInfo<'a>,
+) -> Result<u8, ProgramError> {
+    let (pot_pda, bump) =
+        Pot::shank_pda(&challenge_id(), challenge_pda_info.key);
+    assert_keys_equal(
+        pot_info.key,
+        &pot_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+                "PDA for the challenge pot ('{}') is incorrect, should be '{}'",
+                pot_info.key, pot_pda
+            )
+        },
+    )?;
+
+    if pot_info.owner.eq(program_id) {
+        return Ok(bump);
+    }
+
+    let bump_arr = [bump];
+    let pot_seeds =
+        Pot::shank_seeds_with_bump(challenge_pda_info.key, &bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: pot_info,
+        owner: program_id,
+        signer_seeds: &pot_seeds,
+        size: 0,
+    })?;
+
+    Ok(bump)
+}
+
+// -----------------
+// Init Config
+// -----------------
+fn process_init_config<'a>(
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    protocol_fee_basis_points: u16,
+    treasury: Pubkey,
+) -> ProgramResult {
+    msg!("IX: init config");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+    assert_valid_protocol_fee(protocol_fee_basis_points)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let payer_info = next_account_info(account_info_iter)?;
+    let admin_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
+    let _system_program_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer(admin_info, "admin")?;
+    assert_upgrade_authority(program_data_info, admin_info)?;
+
+    let (config_pda, bump) = ProgramConfig::pda();
+    assert_keys_equal(
+        config_info.key,
+        &config_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+                "PDA for the program config ({}) is incorrect, should be ({})",
+                config_info.key, config_pda
+            )
+        },
+    )?;
+    assert_account_has_no_data(config_info)?;
+
+    let bump_arr = [bump];
+    let config_seeds = ProgramConfig::shank_seeds_with_bump(&bump_arr);
+    allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
+        payer_info,
+        account_info: config_info,
+        owner: program_id,
+        signer_seeds: &config_seeds,
+        size: ProgramConfig::size(),
+    })?;
+
+    let config = ProgramConfig {
+        admin: *admin_info.key,
+        protocol_fee_basis_points,
+        treasury,
+        paused: false,
+        bump,
+    };
+    config.serialize(&mut &mut config_info.try_borrow_mut_data()?.as_mut())?;
+
+    msg!("Program config created and initialized");
+
+    ChallengeEvent::ConfigInitialized {
+        admin: config.admin,
+        protocol_fee_basis_points,
+        treasury,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// -----------------
+// Update Config
+// -----------------
+fn process_update_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    admin: Pubkey,
+    protocol_fee_basis_points: u16,
+    treasury: Pubkey,
+    paused: bool,
+) -> ProgramResult {
+    msg!("IX: update config");
+
+    assert_keys_equal(
+        program_id,
+        &challenge_id(),
+        ChallengeError::IncorrectProgramId,
+        || {
+            format!(
+            "Provided program id ({}) does not match this program's id ({})",
+            program_id,
+            challenge_id()
+        )
+        },
+    )?;
+    assert_valid_protocol_fee(protocol_fee_basis_points)?;
+
+    let account_info_iter = &mut accounts.iter();
+    let admin_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+
+    assert_is_signer(admin_info, "admin")?;
+
+    let config = match ProgramConfig::from_config_account(config_info)? {
+        Some(config) => config,
+        None => {
+            msg!("Err: program config was not initialized yet");
+            return Err(ChallengeError::AccountHasNoData.into());
+        }
+    };
+    assert_keys_equal(
+        &config.admin,
+        admin_info.key,
+        ChallengeError::IncorrectAuthority,
+        || {
+            format!(
+            "Program config's admin ({}) does not match provided admin ({})",
+            config.admin, admin_info.key
+        )
+        },
+    )?;
+
+    let updated_config = ProgramConfig {
+        admin,
+        protocol_fee_basis_points,
+        treasury,
+        paused,
+        bump: config.bump,
+    };
+    updated_config
+        .serialize(&mut &mut config_info.try_borrow_mut_data()?.as_mut())?;
+
+    ChallengeEvent::ConfigUpdated {
+        admin,
+        protocol_fee_basis_points,
+        treasury,
+        paused,
+    }
+    .emit();
+
     Ok(())
 }
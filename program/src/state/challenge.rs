@@ -2,24 +2,35 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
 use solana_program::{
     account_info::AccountInfo,
-    hash::{hash, HASH_BYTES},
+    hash::HASH_BYTES,
     program_error::ProgramError,
-    pubkey::Pubkey,
+    pubkey::{Pubkey, PUBKEY_BYTES},
     rent::Rent,
     sysvar::Sysvar,
 };
 
 use crate::{
     challenge_id,
-    utils::{assert_is_signer, assert_keys_equal},
+    error::ChallengeError,
+    utils::{
+        assert_challenge_migrated, assert_is_signer, assert_keys_equal,
+        hash_salted_solution_challenger_sends, hash_solution_challenger_sends,
+        stored_solution,
+    },
     Solution,
 };
 
 use super::{
-    HasPda, HasSize, Redeem, StateFromPdaAccountValue, TryStateFromPdaAccount,
+    Challenger, HasPda, HasSize, Pot, ProgramConfig, Redeem,
+    StateFromPdaAccountValue, TryStateFromPdaAccountUnchecked,
 };
 
 #[derive(ShankAccount, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
 #[seeds(
     "challenge",
     creator("The authority managing the challenge, usually the creator"),
@@ -36,11 +47,28 @@ use super::{
 ///     without requiring the signature of the `authority`.
 ///   - however adding solutions requires the authority to sign
 pub struct Challenge {
+    // NOTE: the fixed size fields come first in order to be accessible in place via
+    // [ChallengeHeader], see [CHALLENGE_HEADER_SIZE]
+    /// Identifies the account as a challenge stored in the current layout, see
+    /// [CHALLENGE_DISCRIMINATOR].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::hex")
+    )]
+    pub discriminator: [u8; 8],
+
     /// The authority that can update the challenge, normally the creator.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
     pub authority: Pubkey,
 
-    /// The id of the challenge, needs to be unique for the creator.
-    pub id: String,
+    /// The bump of this challenge's PDA, stored to avoid deriving it when signing.
+    pub bump: u8,
+
+    /// The bump of the [Pot] PDA once it was created, otherwise `0`.
+    pub pot_bump: u8,
 
     /// Indicates if the challenge has started and is ready to accept challengers.
     /// If not it won't admit nor redeem to anyone.
@@ -50,6 +78,15 @@ pub struct Challenge {
     /// At this point no challengers can be admitted nor can any one redeem the price.
     pub finished: bool,
 
+    /// Indicates that the creator aborted the challenge which also marks it as finished.
+    pub cancelled: bool,
+
+    /// If set every `admit_cost` is escrowed in the challenge's [Pot] instead of being paid
+    /// to the creator right away.
+    /// The creator withdraws the fees once the challenge finishes, unless it was cancelled
+    /// in which case challengers can claim a refund for their unused tries instead.
+    pub escrow: bool,
+
     /// The fee that will be transferred to the creator from the challenger account
     /// when the admit instruction is processed.
     pub admit_cost: u64,
@@ -63,11 +100,87 @@ pub struct Challenge {
     /// The address of the price token.
     /// Should this be an array/collection for case b) of the reason to have multiple solutions?
     /// See below ([Challenge::solutions])
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
     pub redeem: Pubkey,
 
     /// The index of the solution that needs to be found next
     pub solving: u8,
 
+    /// The amount of the `required_mint` token that a challenger needs to hold.
+    /// Only relevant if `required_mint` is set.
+    pub required_amount: u64,
+
+    /// How solutions in clear text are normalized before being hashed by creators and
+    /// challengers, so that i.e. `"Hello World "` matches `"hello world"`.
+    /// It cannot be changed after the challenge was created as the stored solutions
+    /// depend on it.
+    pub normalization: SolutionNormalization,
+
+    /// If set the `redeem` mint is owned by the Token-2022 program and has the
+    /// NonTransferable extension, so that redeemed tokens cannot be passed on and serve
+    /// as proof of having solved the challenge, see [SoulboundRedeem].
+    pub soulbound: bool,
+
+    /// The tries that challengers paid into the [Pot] and neither used nor were refunded
+    /// for yet. Once the challenge is cancelled the creator can only withdraw what
+    /// exceeds the refunds still owed for them.
+    pub escrowed_tries: u32,
+
+    /// The id of the challenge, needs to be unique for the creator.
+    pub id: String,
+
+    /// The root of the merkle tree of challengers that are allowed to be admitted.
+    /// If set, each challenger needs to provide a proof of being included in the allowlist
+    /// when being admitted, otherwise anyone paying the `admit_cost` is admitted.
+    /// See [crate::utils::AdmissionTree].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::option_hex")
+    )]
+    pub admission_root: Option<[u8; 32]>,
+
+    /// The mint of a token that challengers need to hold in order to be admitted.
+    /// When this is the `redeem` mint of another challenge, only challengers that solved
+    /// that challenge can be admitted which allows chaining challenges.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::option_pubkey")
+    )]
+    pub required_mint: Option<Pubkey>,
+
+    /// The accounts that share the `admit_cost` paid by each admitted challenger.
+    /// If empty the creator receives the entire `admit_cost`, otherwise the basis points
+    /// of all recipients add up to [TOTAL_FEE_BASIS_POINTS] and any rounding dust goes to
+    /// the creator.
+    pub fee_recipients: Vec<FeeRecipient>,
+
+    /// If set the challenge is in jackpot mode and every `admit_cost` is escrowed in the
+    /// challenge's [Pot] instead of being paid to the creator.
+    /// Each correct solution pays out this share of the pot in basis points to the solver,
+    /// solving the last solution pays out the entire pot.
+    pub jackpot: Option<u16>,
+
+    /// The program that is invoked after each correct solution in order to run custom
+    /// logic, i.e. unlocking a game item for the solver.
+    /// See [crate::hook::PostSolveHook] for the data and accounts it receives.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::option_pubkey")
+    )]
+    pub hook_program: Option<Pubkey>,
+
+    /// If set solutions are bound to this challenge and stretched before being stored
+    /// which prevents replaying a solution sent to another challenge and makes
+    /// guessing short solutions from their stored hash more expensive.
+    /// Otherwise solutions are stored as `sha256(sha256(solution))`.
+    /// It cannot be changed after the challenge was created as the stored solutions
+    /// depend on it.
+    /// See [crate::utils::hash_bound_solutions].
+    pub hashing: Option<SolutionHashing>,
+
     /// All solutions of the challenge, solving each will result in the redeem
     /// to be sent to the challenger.
     /// There are two reasons why multiple solutions exist:
@@ -77,41 +190,277 @@ pub struct Challenge {
     ///   challengers may be allowed to redeem multiple times and receive the `redeem` token more
     ///   than once
     // NOTE: shank doesn't handle type aliases yet nor an array with constant identifier
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::hex_vec")
+    )]
     pub solutions: Vec<[u8; 32]>,
 }
 
+/// The maximum amount of accounts that can share the admit fee of a challenge.
+pub const MAX_FEE_RECIPIENTS: usize = 8;
+
+/// The basis points that the shares of all fee recipients need to add up to.
+pub const TOTAL_FEE_BASIS_POINTS: u16 = 10_000;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// An account receiving a share of the admit fee.
+pub struct FeeRecipient {
+    /// The account that receives the share.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
+    pub recipient: Pubkey,
+
+    /// The share of the `admit_cost` this account receives in basis points,
+    /// i.e. `100` is 1%.
+    pub basis_points: u16,
+}
+
+#[rustfmt::skip]
+pub const FEE_RECIPIENT_SIZE: usize =
+    /* recipient */    32 +
+    /* basis_points */  2;
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Determines how a solution in clear text is normalized before it is hashed.
+/// The steps are applied in the order of the fields.
+pub struct SolutionNormalization {
+    /// Applies Unicode compatibility normalization (NFKC), i.e. to match ligatures and
+    /// full width characters with their plain equivalents.
+    pub nfkc: bool,
+
+    /// Lowercases the solution.
+    pub case_fold: bool,
+
+    /// Removes leading and trailing whitespace.
+    pub trim_whitespace: bool,
+
+    /// Replaces each run of whitespace with a single space, implies `trim_whitespace`.
+    pub collapse_whitespace: bool,
+}
+
+#[rustfmt::skip]
+pub const SOLUTION_NORMALIZATION_SIZE: usize =
+    /* nfkc */                1 +
+    /* case_fold */           1 +
+    /* trim_whitespace */     1 +
+    /* collapse_whitespace */ 1;
+
+/// The maximum amount of times a bound solution can be rehashed, limited by the compute
+/// units available when redeeming as the program hashes the solution that many times.
+pub const MAX_HASH_ITERATIONS: u16 = 1_000;
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Determines how the solutions of a challenge are hashed.
+pub struct SolutionHashing {
+    /// Random bytes the challenger hashes with the solution, unique per challenge.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::hex")
+    )]
+    pub salt: [u8; 32],
+
+    /// The amount of times the bound solution is rehashed before being stored,
+    /// at most [MAX_HASH_ITERATIONS].
+    pub iterations: u16,
+}
+
+#[rustfmt::skip]
+pub const SOLUTION_HASHING_SIZE: usize =
+    /* salt */       32 +
+    /* iterations */  2;
+
 impl std::fmt::Debug for Challenge {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Challenge")
+            .field("discriminator", &self.discriminator)
             .field("authority", &self.authority)
-            .field("id", &self.id)
+            .field("bump", &self.bump)
+            .field("pot_bump", &self.pot_bump)
             .field("started", &self.started)
             .field("finished", &self.finished)
+            .field("cancelled", &self.cancelled)
+            .field("escrow", &self.escrow)
             .field("admit_cost", &self.admit_cost)
             .field("tries_per_admit", &self.tries_per_admit)
             .field("redeem", &self.redeem)
             .field("solving", &self.solving)
+            .field("required_amount", &self.required_amount)
+            .field("normalization", &self.normalization)
+            .field("soulbound", &self.soulbound)
+            .field("escrowed_tries", &self.escrowed_tries)
+            .field("id", &self.id)
+            .field("admission_root", &self.admission_root)
+            .field("required_mint", &self.required_mint)
+            .field("fee_recipients", &self.fee_recipients)
+            .field("jackpot", &self.jackpot)
+            .field("hook_program", &self.hook_program)
+            .field("hashing", &self.hashing)
             .field("solutions", &self.solutions.len())
             .finish()
     }
 }
 
+/// Serializes the stored fields together with the derived `address` of the challenge,
+/// its `pot` and the number of `remaining_solutions`.
+/// The derived fields are ignored when deserializing.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Challenge {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use crate::utils::serde_fields::pubkey;
+
+        #[derive(serde::Serialize)]
+        struct WithDerived<'a> {
+            #[serde(with = "pubkey")]
+            address: Pubkey,
+            #[serde(with = "pubkey")]
+            pot: Pubkey,
+            remaining_solutions: usize,
+            #[serde(flatten, serialize_with = "stored")]
+            challenge: &'a Challenge,
+        }
+        fn stored<S: serde::Serializer>(
+            challenge: &&Challenge,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Challenge::serialize(challenge, serializer)
+        }
+
+        let (address, _) = self.pda();
+        WithDerived {
+            address,
+            pot: Pot::new(address).pda,
+            remaining_solutions: self
+                .solutions
+                .len()
+                .saturating_sub(self.solving as usize),
+            challenge: self,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Challenge {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        Challenge::deserialize(deserializer)
+    }
+}
+
+impl Default for Challenge {
+    fn default() -> Self {
+        Self {
+            discriminator: CHALLENGE_DISCRIMINATOR,
+            authority: Pubkey::default(),
+            bump: 0,
+            pot_bump: 0,
+            started: false,
+            finished: false,
+            cancelled: false,
+            escrow: false,
+            admit_cost: 0,
+            tries_per_admit: 0,
+            redeem: Pubkey::default(),
+            solving: 0,
+            required_amount: 0,
+            normalization: SolutionNormalization::default(),
+            soulbound: false,
+            escrowed_tries: 0,
+            id: String::new(),
+            admission_root: None,
+            required_mint: None,
+            fee_recipients: vec![],
+            jackpot: None,
+            hook_program: None,
+            hashing: None,
+            solutions: vec![],
+        }
+    }
+}
+
+/// Prefixes the data of each challenge account.
+pub const CHALLENGE_DISCRIMINATOR: [u8; 8] = *b"chlnge01";
+
 #[rustfmt::skip]
 pub const EMPTY_CHALLENGE_SIZE_WITH_EMPTY_ID: usize =
-    /* authority */      32 + 
-    /* id */              4 + /* does not include string len */
+    /* discriminator */   8 +
+    /* authority */      32 +
+    /* bump */            1 +
+    /* pot_bump */        1 +
     /* started */         1 +
     /* finished */        1 +
+    /* cancelled */       1 +
+    /* escrow */          1 +
     /* admit_cost */      8 +
     /* tries_per_admit */ 1 +
     /* redeem */         32 +
     /* solving */         1 +
+    /* required_amount */ 8 +
+    /* normalization */   SOLUTION_NORMALIZATION_SIZE +
+    /* soulbound */       1 +
+    /* escrowed_tries */  4 +
+    /* id */              4 + /* does not include string len */
+    /* admission_root */  1 + /* does not include root when set */
+    /* required_mint */   1 + /* does not include mint when set */
+    /* fee_recipients */  4 + // u32 for Vec::len
+    /* jackpot */         1 + /* does not include basis points when set */
+    /* hook_program */    1 + /* does not include program when set */
+    /* hashing */         1 + /* does not include salt and iterations when set */
     /* solutions */       4; // u32 for Vec::len
 
+/// The layout in which challenges were stored before the `discriminator` and the fields
+/// following `authority` in the current layout were added.
+#[derive(BorshDeserialize)]
+struct LegacyChallenge {
+    authority: Pubkey,
+    id: String,
+    started: bool,
+    finished: bool,
+    admit_cost: u64,
+    tries_per_admit: u8,
+    redeem: Pubkey,
+    solving: u8,
+    solutions: Vec<[u8; 32]>,
+}
+
+#[rustfmt::skip]
+const EMPTY_LEGACY_CHALLENGE_SIZE_WITH_EMPTY_ID: usize =
+    /* authority */      32 +
+    /* id */              4 +
+    /* started */         1 +
+    /* finished */        1 +
+    /* admit_cost */      8 +
+    /* tries_per_admit */ 1 +
+    /* redeem */         32 +
+    /* solving */         1 +
+    /* solutions */       4;
+
 impl HasSize for Challenge {
     /// Returns the size assuming no more solutions will be added.
     fn size(&self) -> usize {
         Challenge::needed_size(&self.solutions, &self.id)
+            + self.admission_root.map_or(0, |root| root.len())
+            + self.required_mint.map_or(0, |_| PUBKEY_BYTES)
+            + Challenge::space_to_store_n_fee_recipients(
+                self.fee_recipients.len(),
+            )
+            + self.jackpot.map_or(0, |_| 2)
+            + self.hook_program.map_or(0, |_| PUBKEY_BYTES)
+            + self.hashing.map_or(0, |_| SOLUTION_HASHING_SIZE)
     }
 }
 
@@ -129,16 +478,94 @@ impl Challenge {
     pub fn seeds<'a>(&'a self, bump: &'a [u8; 1]) -> [&'a [u8]; 4] {
         Challenge::shank_seeds_with_bump(&self.authority, &self.id, bump)
     }
+
+    /// Returns the size of a challenge with the given solutions and id that has
+    /// none of the optional properties set.
     pub fn needed_size(solutions: &[Solution], id: &str) -> usize {
         EMPTY_CHALLENGE_SIZE_WITH_EMPTY_ID
             + id.len()
             + Challenge::space_to_store_n_solutions(solutions.len() as u8)
     }
 
+    /// Returns the size of a challenge with the given solutions and id that has
+    /// none of the optional properties set and reserves space for [capacity] solutions
+    /// if that exceeds the provided solutions.
+    pub fn needed_size_with_capacity(
+        solutions: &[Solution],
+        id: &str,
+        capacity: u8,
+    ) -> usize {
+        Challenge::needed_size(solutions, id).max(
+            EMPTY_CHALLENGE_SIZE_WITH_EMPTY_ID
+                + id.len()
+                + Challenge::space_to_store_n_solutions(capacity),
+        )
+    }
+
+    /// Returns `true` if the challenge account [data] is stored in the legacy layout,
+    /// i.e. it needs to be migrated via [crate::ixs::migrate_challenge].
+    pub fn is_legacy_layout(data: &[u8]) -> bool {
+        !data.is_empty() && !data.starts_with(&CHALLENGE_DISCRIMINATOR)
+    }
+
+    /// Returns the size the challenge had when stored in the legacy layout.
+    pub fn legacy_size(&self) -> usize {
+        EMPTY_LEGACY_CHALLENGE_SIZE_WITH_EMPTY_ID
+            + self.id.len()
+            + Challenge::space_to_store_n_solutions(self.solutions.len() as u8)
+    }
+
+    /// Decodes challenge account data stored in the current or the legacy layout.
+    /// Legacy challenges are upgraded to the current layout in memory, their `redeem`
+    /// mint is owned by the spl-token program and their solutions are stored as
+    /// `sha256(sha256(solution))`.
+    /// NOTE: the data may include space reserved for solutions following the challenge.
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if !Challenge::is_legacy_layout(data) {
+            return Ok(BorshDeserialize::deserialize(&mut &data[..])?);
+        }
+        let LegacyChallenge {
+            authority,
+            id,
+            started,
+            finished,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solving,
+            solutions,
+        } = LegacyChallenge::deserialize(&mut &data[..])
+            .map_err(|_| ChallengeError::ChallengeDataInvalid)?;
+        let (_, bump) = Challenge::pda_for(&authority, &id);
+        Ok(Self {
+            authority,
+            bump,
+            started,
+            finished,
+            admit_cost,
+            tries_per_admit,
+            redeem,
+            solving,
+            id,
+            solutions,
+            ..Default::default()
+        })
+    }
+
+    /// Returns the space of the challenge account with the given length that is
+    /// reserved for solutions which were not added yet.
+    pub fn reserved_space(&self, account_len: usize) -> usize {
+        account_len.saturating_sub(self.size())
+    }
+
     pub fn space_to_store_n_solutions(solutions_len: u8) -> usize {
         solutions_len as usize * HASH_BYTES
     }
 
+    pub fn space_to_store_n_fee_recipients(fee_recipients_len: usize) -> usize {
+        fee_recipients_len * FEE_RECIPIENT_SIZE
+    }
+
     /// Only use on-chain as Rent::get is not available otherwise.
     #[allow(unused)]
     pub(crate) fn rent_exempt_lamports(&self) -> Result<u64, ProgramError> {
@@ -149,26 +576,35 @@ impl Challenge {
     /// Deserializes a challenge from the given account data and verifies the following:
     /// - the provided challenge pda account is for the provided creator and challenge id  
     /// - the challenge account is funded and initialized (has data)
+    /// - the challenge account is stored in the current layout
     /// - the creator (authority) is signer
     /// - the creator is the authority for the challenge
+    ///
+    /// NOTE: the account data may include space reserved for solutions following the challenge
     pub fn account_state_verifying_creator(
         challenge_pda_info: &AccountInfo,
         creator_info: &AccountInfo,
         id: &str,
     ) -> Result<StateFromPdaAccountValue<Challenge>, ProgramError> {
+        assert_challenge_migrated(challenge_pda_info)?;
         let StateFromPdaAccountValue::<Challenge> { state, pda, bump } =
-            challenge_pda_info.try_state_from_pda_account(|| {
+            challenge_pda_info.try_state_from_pda_account_unchecked(|| {
                 Challenge::shank_pda(&challenge_id(), creator_info.key, id)
             })?;
 
         assert_is_signer(creator_info, "creator")?;
 
-        assert_keys_equal(&state.authority, creator_info.key, || {
-            format!(
+        assert_keys_equal(
+            &state.authority,
+            creator_info.key,
+            ChallengeError::IncorrectAuthority,
+            || {
+                format!(
             "Challenge's authority ({}) does not match provided creator ({})",
             state.authority, creator_info.key
         )
-        })?;
+            },
+        )?;
         Ok(StateFromPdaAccountValue::<Challenge> { state, pda, bump })
     }
 
@@ -176,8 +612,19 @@ impl Challenge {
         self.solutions.get(self.solving as usize)
     }
 
-    pub fn is_solution_correct(&self, sent_solution: &Solution) -> bool {
-        let solution_stored_as = hash(sent_solution).to_bytes();
+    /// Verifies the solution the challenger sent for the current solution, see
+    /// [crate::utils::stored_solution].
+    pub fn is_solution_correct(
+        &self,
+        challenge_pda: &Pubkey,
+        sent_solution: &Solution,
+    ) -> bool {
+        let solution_stored_as = stored_solution(
+            sent_solution,
+            challenge_pda,
+            self.solving,
+            self.hashing.as_ref(),
+        );
         let correct_solution = self.current_solution();
 
         // We should always get a solution here since we assert first that we have one
@@ -188,7 +635,122 @@ impl Challenge {
         }
     }
 
+    /// Hashes the solution in clear text the way a challenger sends it when attempting
+    /// to solve the current solution, applying the `normalization` and `hashing` of the
+    /// challenge.
+    pub fn challenger_sends(&self, solution: &str) -> Solution {
+        match &self.hashing {
+            Some(SolutionHashing { salt, .. }) => {
+                hash_salted_solution_challenger_sends(
+                    solution,
+                    &self.normalization,
+                    salt,
+                    &self.pda().0,
+                    self.solving,
+                )
+            }
+            None => {
+                hash_solution_challenger_sends(solution, &self.normalization)
+            }
+        }
+    }
+
+    /// The part of the `admit_cost` that remains once the protocol fee of the [config]
+    /// is taken, which is split among the fee recipients or escrowed in the [Pot].
+    pub fn admit_fees(&self, config: Option<&ProgramConfig>) -> u64 {
+        let protocol_fee =
+            config.map_or(0, |config| config.protocol_fee(self.admit_cost));
+        self.admit_cost.saturating_sub(protocol_fee)
+    }
+
+    /// Splits the [admit_fees] among the `fee_recipients`.
+    /// Returns the lamports each recipient receives in the order they are stored
+    /// and the rounding dust which goes to the creator.
+    /// Without any fee recipients the creator receives the entire [admit_fees].
+    pub fn admit_fee_shares(&self, admit_fees: u64) -> (Vec<u64>, u64) {
+        let shares = self
+            .fee_recipients
+            .iter()
+            .map(|FeeRecipient { basis_points, .. }| {
+                (admit_fees as u128 * *basis_points as u128
+                    / TOTAL_FEE_BASIS_POINTS as u128) as u64
+            })
+            .collect::<Vec<u64>>();
+        let dust = admit_fees - shares.iter().sum::<u64>();
+        (shares, dust)
+    }
+
+    /// Determines the lamports paid out of the pot to a challenger that provided a
+    /// correct solution.
+    /// The pot always keeps the [rent_exempt_lamports] and is only emptied
+    /// once the challenge is finished.
+    pub fn jackpot_payout(
+        &self,
+        pot_lamports: u64,
+        rent_exempt_lamports: u64,
+    ) -> u64 {
+        let available = pot_lamports.saturating_sub(rent_exempt_lamports);
+        match self.jackpot {
+            Some(_) if self.finished => available,
+            Some(basis_points) => {
+                (available as u128 * basis_points as u128
+                    / TOTAL_FEE_BASIS_POINTS as u128) as u64
+            }
+            None => 0,
+        }
+    }
+
+    /// Admit fees end up in the [Pot] either when the challenge is in jackpot mode
+    /// or when they are explicitly escrowed.
+    pub fn escrows_admit_fees(&self) -> bool {
+        self.escrow || self.jackpot.is_some()
+    }
+
+    /// Determines the lamports refunded to a challenger of a cancelled challenge for
+    /// the tries they did not use.
+    /// The protocol fee of the [config] is not refunded since it was never escrowed.
+    pub fn refund_for(
+        &self,
+        challenger: &Challenger,
+        config: Option<&ProgramConfig>,
+    ) -> u64 {
+        self.refund_for_tries(challenger.tries_remaining as u32, config)
+    }
+
+    /// Determines the lamports that still need to stay in the [Pot] of a cancelled
+    /// challenge to refund all challengers for their [Challenge::escrowed_tries].
+    pub fn outstanding_refunds(&self, config: Option<&ProgramConfig>) -> u64 {
+        self.refund_for_tries(self.escrowed_tries, config)
+    }
+
+    fn refund_for_tries(
+        &self,
+        tries: u32,
+        config: Option<&ProgramConfig>,
+    ) -> u64 {
+        if self.tries_per_admit == 0 {
+            return 0;
+        }
+        (self.admit_fees(config) as u128 * tries as u128
+            / self.tries_per_admit as u128) as u64
+    }
+
+    pub fn pot_pda(&self) -> (Pubkey, u8) {
+        Pot::new(self.pda().0).pda()
+    }
+
     pub fn redeem_pda(&self) -> (Pubkey, u8) {
         Redeem::new(self.pda().0).pda()
     }
+
+    /// The token program that owns the `redeem` mint, see [Challenge::soulbound].
+    pub fn redeem_token_program(&self) -> Pubkey {
+        Redeem::token_program(self.soulbound)
+    }
+
+    /// The associated token account of the `redeem` mint for the provided receiver.
+    pub fn redeem_ata(&self, recvr: &Pubkey) -> Pubkey {
+        Redeem::new(self.pda().0)
+            .ata_with_program_id(recvr, &self.redeem_token_program())
+    }
 }
@@ -0,0 +1,303 @@
+use std::mem::size_of;
+
+use bytemuck::{Pod, Zeroable};
+use solana_program::{
+    hash::HASH_BYTES,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+use crate::{error::ChallengeError, utils::stored_solution, Solution};
+
+use super::{
+    Challenge, SolutionHashing, SolutionNormalization, FEE_RECIPIENT_SIZE,
+    SOLUTION_HASHING_SIZE, SOLUTION_NORMALIZATION_SIZE, TOTAL_FEE_BASIS_POINTS,
+};
+
+/// The size of the fixed size fields at the start of each challenge account.
+pub const CHALLENGE_HEADER_SIZE: usize = size_of::<ChallengeHeader>();
+
+/// The fixed size fields at the start of a [Challenge] account in the same layout in
+/// which they are borsh serialized.
+/// This allows reading and updating them in place without deserializing the entire
+/// challenge which includes up to 8KB of solutions.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct ChallengeHeader {
+    pub discriminator: [u8; 8],
+    pub authority: Pubkey,
+    pub bump: u8,
+    pub pot_bump: u8,
+    started: u8,
+    finished: u8,
+    cancelled: u8,
+    escrow: u8,
+    pub admit_cost: u64,
+    pub tries_per_admit: u8,
+    pub redeem: Pubkey,
+    pub solving: u8,
+    pub required_amount: u64,
+    normalization: [u8; SOLUTION_NORMALIZATION_SIZE],
+    soulbound: u8,
+    pub escrowed_tries: u32,
+}
+
+impl ChallengeHeader {
+    pub fn started(&self) -> bool {
+        self.started != 0
+    }
+
+    pub fn finished(&self) -> bool {
+        self.finished != 0
+    }
+
+    pub fn set_finished(&mut self, finished: bool) {
+        self.finished = finished as u8;
+    }
+
+    pub fn cancelled(&self) -> bool {
+        self.cancelled != 0
+    }
+
+    pub fn escrow(&self) -> bool {
+        self.escrow != 0
+    }
+
+    pub fn soulbound(&self) -> bool {
+        self.soulbound != 0
+    }
+
+    pub fn normalization(&self) -> SolutionNormalization {
+        let [nfkc, case_fold, trim_whitespace, collapse_whitespace] =
+            self.normalization;
+        SolutionNormalization {
+            nfkc: nfkc != 0,
+            case_fold: case_fold != 0,
+            trim_whitespace: trim_whitespace != 0,
+            collapse_whitespace: collapse_whitespace != 0,
+        }
+    }
+}
+
+/// Provides access to a challenge account's data in place.
+/// The [ChallengeHeader] is mapped directly onto the data while the variable size fields
+/// following it are located by skipping over them without deserializing any solutions.
+/// Thus accessing a challenge this way does not depend on the amount of its solutions.
+pub struct ChallengeView<'a> {
+    pub header: &'a mut ChallengeHeader,
+
+    /// The id of the challenge
+    pub id: &'a str,
+
+    /// See [Challenge::jackpot]
+    pub jackpot: Option<u16>,
+
+    /// See [Challenge::hook_program]
+    pub hook_program: Option<Pubkey>,
+
+    /// See [Challenge::hashing]
+    pub hashing: Option<SolutionHashing>,
+
+    /// The solutions of the challenge each of which is [HASH_BYTES] long
+    solutions: &'a [u8],
+}
+
+impl<'a> ChallengeView<'a> {
+    /// Maps the view onto the provided challenge account data.
+    /// Challenges stored in the legacy layout are rejected since they need to be
+    /// migrated first, see [Challenge::is_legacy_layout].
+    pub fn from_data(data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        if Challenge::is_legacy_layout(data) {
+            return Err(ChallengeError::ChallengeNeedsMigration.into());
+        }
+        if data.len() < CHALLENGE_HEADER_SIZE {
+            return Err(ChallengeError::ChallengeDataInvalid.into());
+        }
+        let (header, rest) = data.split_at_mut(CHALLENGE_HEADER_SIZE);
+        let header = bytemuck::from_bytes_mut::<ChallengeHeader>(header);
+        let rest: &'a [u8] = rest;
+
+        let mut reader = ChallengeReader {
+            data: rest,
+            offset: 0,
+        };
+        let id_len = reader.read_len()?;
+        let id = std::str::from_utf8(reader.take(id_len)?)
+            .map_err(|_| ChallengeError::ChallengeDataInvalid)?;
+        // admission_root
+        reader.skip_option(HASH_BYTES)?;
+        // required_mint
+        reader.skip_option(PUBKEY_BYTES)?;
+        // fee_recipients
+        let fee_recipients_len = reader.read_len()?;
+        reader.take(fee_recipients_len * FEE_RECIPIENT_SIZE)?;
+        let jackpot = reader
+            .read_option(2)?
+            .map(|x| u16::from_le_bytes([x[0], x[1]]));
+        let hook_program = reader.read_pubkey_option()?;
+        let hashing = reader.read_option(SOLUTION_HASHING_SIZE)?.map(|x| {
+            let mut salt = [0u8; 32];
+            salt.copy_from_slice(&x[..32]);
+            SolutionHashing {
+                salt,
+                iterations: u16::from_le_bytes([x[32], x[33]]),
+            }
+        });
+        let solutions_len = reader.read_len()?;
+        let solutions = reader.take(solutions_len * HASH_BYTES)?;
+
+        Ok(Self {
+            header,
+            id,
+            jackpot,
+            hook_program,
+            hashing,
+            solutions,
+        })
+    }
+
+    pub fn solutions_len(&self) -> usize {
+        self.solutions.len() / HASH_BYTES
+    }
+
+    pub fn solution(&self, index: u8) -> Option<&'a [u8]> {
+        let start = index as usize * HASH_BYTES;
+        self.solutions.get(start..start + HASH_BYTES)
+    }
+
+    /// See [Challenge::current_solution]
+    pub fn current_solution(&self) -> Option<&'a [u8]> {
+        self.solution(self.header.solving)
+    }
+
+    /// See [Challenge::is_solution_correct]
+    pub fn is_solution_correct(
+        &self,
+        challenge_pda: &Pubkey,
+        sent_solution: &Solution,
+    ) -> bool {
+        let solution_stored_as = stored_solution(
+            sent_solution,
+            challenge_pda,
+            self.header.solving,
+            self.hashing.as_ref(),
+        );
+        matches!(
+            self.current_solution(),
+            Some(correct_solution) if correct_solution == solution_stored_as
+        )
+    }
+
+    /// See [Challenge::jackpot_payout]
+    pub fn jackpot_payout(
+        &self,
+        pot_lamports: u64,
+        rent_exempt_lamports: u64,
+    ) -> u64 {
+        let available = pot_lamports.saturating_sub(rent_exempt_lamports);
+        match self.jackpot {
+            Some(_) if self.header.finished() => available,
+            Some(basis_points) => {
+                (available as u128 * basis_points as u128
+                    / TOTAL_FEE_BASIS_POINTS as u128) as u64
+            }
+            None => 0,
+        }
+    }
+
+    pub fn seeds<'b>(&'b self, bump: &'b [u8; 1]) -> [&'b [u8]; 4] {
+        Challenge::shank_seeds_with_bump(&self.header.authority, self.id, bump)
+    }
+}
+
+/// The properties of a challenge that are checked before processing an instruction.
+/// They are available on both the deserialized [Challenge] and a [ChallengeView].
+pub trait ChallengeStatus {
+    fn id(&self) -> &str;
+    fn is_started(&self) -> bool;
+    fn is_finished(&self) -> bool;
+    fn has_current_solution(&self) -> bool;
+}
+
+impl ChallengeStatus for Challenge {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn is_started(&self) -> bool {
+        self.started
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn has_current_solution(&self) -> bool {
+        self.current_solution().is_some()
+    }
+}
+
+impl<'a> ChallengeStatus for ChallengeView<'a> {
+    fn id(&self) -> &str {
+        self.id
+    }
+
+    fn is_started(&self) -> bool {
+        self.header.started()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.header.finished()
+    }
+
+    fn has_current_solution(&self) -> bool {
+        self.current_solution().is_some()
+    }
+}
+
+/// Reads borsh encoded lengths and options from the variable size part of a challenge.
+struct ChallengeReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ChallengeReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        let end = self.offset + len;
+        let bytes = self
+            .data
+            .get(self.offset..end)
+            .ok_or(ChallengeError::ChallengeDataInvalid)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn read_len(&mut self) -> Result<usize, ProgramError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            as usize)
+    }
+
+    fn read_option(
+        &mut self,
+        len: usize,
+    ) -> Result<Option<&'a [u8]>, ProgramError> {
+        match self.take(1)?[0] {
+            0 => Ok(None),
+            _ => self.take(len).map(Some),
+        }
+    }
+
+    fn read_pubkey_option(&mut self) -> Result<Option<Pubkey>, ProgramError> {
+        self.read_option(PUBKEY_BYTES)?
+            .map(|x| {
+                Pubkey::try_from(x)
+                    .map_err(|_| ChallengeError::ChallengeDataInvalid.into())
+            })
+            .transpose()
+    }
+
+    fn skip_option(&mut self, len: usize) -> Result<(), ProgramError> {
+        self.read_option(len).map(|_| ())
+    }
+}
@@ -1,23 +1,43 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
-use solana_program::pubkey::Pubkey;
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::challenge_id;
+use crate::{challenge_id, error::ChallengeError};
 
 use super::{HasPda, HasSize};
 
 #[derive(Debug, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(remote = "Self")
+)]
 #[seeds(
     "challenge",
     challenge_pda("The challenge PDA that the challenger wants to solve."),
     challenger("The address attempting to solve the challenge")
 )]
 pub struct Challenger {
+    /// Identifies the account as a challenger, see [CHALLENGER_DISCRIMINATOR].
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::hex")
+    )]
+    pub discriminator: [u8; 8],
+
     /// The authority that can redeem the challenge, which is the account from
     /// which the challenger PDA (the owner of this account) was derived.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
     pub authority: Pubkey,
 
     /// The PDA of the challenge that the challenger is solving.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
     pub challenge_pda: Pubkey,
 
     /// How many more attempts the callenger has to provide a solution to redeem.
@@ -26,14 +46,93 @@ pub struct Challenger {
     /// This means that the challenger redeemed at least once.
     /// Shoud this be a count even though we could just mint multiple `redeem` tokens?
     pub redeemed: bool,
+
+    /// Bitmap of the indexes of the solutions the challenger solved.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::hex")
+    )]
+    pub solved: [u8; 32],
+
+    /// Bitmap of the indexes of the solutions the creator revoked from the challenger,
+    /// see [crate::ixs::revoke_solve]. They are no longer part of `solved`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::hex")
+    )]
+    pub revoked: [u8; 32],
+}
+
+/// Serializes the stored fields together with the derived `address` of the challenger,
+/// the `redeem_mint` of the challenge and the indexes of the `solved` and `revoked`
+/// solutions.
+/// The derived fields are ignored when deserializing.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Challenger {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use super::Redeem;
+        use crate::utils::serde_fields::pubkey;
+
+        #[derive(serde::Serialize)]
+        struct WithDerived<'a> {
+            #[serde(with = "pubkey")]
+            address: Pubkey,
+            #[serde(with = "pubkey")]
+            redeem_mint: Pubkey,
+            solved_indexes: Vec<u8>,
+            revoked_indexes: Vec<u8>,
+            #[serde(flatten, serialize_with = "stored")]
+            challenger: &'a Challenger,
+        }
+        fn stored<S: serde::Serializer>(
+            challenger: &&Challenger,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            Challenger::serialize(challenger, serializer)
+        }
+
+        WithDerived {
+            address: self.pda().0,
+            redeem_mint: Redeem::new(self.challenge_pda).pda,
+            solved_indexes: self.solved_indexes(),
+            revoked_indexes: self.revoked_indexes(),
+            challenger: self,
+        }
+        .serialize(serializer)
+    }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Challenger {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        Challenger::deserialize(deserializer)
+    }
+}
+
+/// Prefixes the data of each challenger account.
+pub const CHALLENGER_DISCRIMINATOR: [u8; 8] = *b"chlngr01";
+
 #[rustfmt::skip]
 pub const CHALLENGER_SIZE: usize =
+    /* discriminator */   8 +
     /* authority */      32 + 
     /* challenge_pda */  32 + 
     /* tries_remaining */ 1 +
-    /* redeemed */        1;
+    /* redeemed */        1 +
+    /* solved */         32 +
+    /* revoked */        32;
+
+/// Size of challenger accounts created before the `discriminator` and the `solved`
+/// bitmap were added.
+pub const LEGACY_CHALLENGER_SIZE_V0: usize = 66;
+
+/// Size of challenger accounts created before the `revoked` bitmap was added.
+pub const LEGACY_CHALLENGER_SIZE_V1: usize = 106;
 
 impl HasSize for Challenger {
     fn size(&self) -> usize {
@@ -52,7 +151,103 @@ impl HasPda for Challenger {
 }
 
 impl Challenger {
+    pub fn new(
+        authority: Pubkey,
+        challenge_pda: Pubkey,
+        tries_remaining: u8,
+    ) -> Self {
+        Self {
+            discriminator: CHALLENGER_DISCRIMINATOR,
+            authority,
+            challenge_pda,
+            tries_remaining,
+            redeemed: false,
+            solved: [0; 32],
+            revoked: [0; 32],
+        }
+    }
+
     pub fn size() -> usize {
         CHALLENGER_SIZE
     }
+
+    /// Returns `true` if the challenger account data was stored in a legacy layout
+    /// and needs to be migrated via [crate::ixs::migrate_challenger] before the
+    /// challenger can redeem, claim a refund or have a solve revoked.
+    pub fn is_legacy_layout(data: &[u8]) -> bool {
+        data.len() == LEGACY_CHALLENGER_SIZE_V0
+            || (data.len() == LEGACY_CHALLENGER_SIZE_V1
+                && data.starts_with(&CHALLENGER_DISCRIMINATOR))
+    }
+
+    /// Decodes challenger account data stored in the current or a legacy layout.
+    /// Legacy challengers are upgraded to the current layout in memory.
+    /// NOTE: challengers created before the `solved` bitmap was added have no solved
+    /// indexes even if they `redeemed`.
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        match data.len() {
+            LEGACY_CHALLENGER_SIZE_V0 => {
+                let (authority, challenge_pda, tries_remaining, redeemed) =
+                    <(Pubkey, Pubkey, u8, bool)>::try_from_slice(data)?;
+                Ok(Self {
+                    redeemed,
+                    ..Self::new(authority, challenge_pda, tries_remaining)
+                })
+            }
+            LEGACY_CHALLENGER_SIZE_V1
+                if data.starts_with(&CHALLENGER_DISCRIMINATOR) =>
+            {
+                let (
+                    _discriminator,
+                    authority,
+                    challenge_pda,
+                    tries_remaining,
+                    redeemed,
+                    solved,
+                ) = <([u8; 8], Pubkey, Pubkey, u8, bool, [u8; 32])>::try_from_slice(
+                    data,
+                )?;
+                Ok(Self {
+                    redeemed,
+                    solved,
+                    ..Self::new(authority, challenge_pda, tries_remaining)
+                })
+            }
+            _ if data.starts_with(&CHALLENGER_DISCRIMINATOR) => {
+                Ok(Self::try_from_slice(data)?)
+            }
+            _ => Err(ChallengeError::AccountDiscriminatorMismatch.into()),
+        }
+    }
+
+    pub fn has_solved(&self, index: u8) -> bool {
+        self.solved[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn mark_solved(&mut self, index: u8) {
+        self.solved[index as usize / 8] |= 1 << (index % 8);
+    }
+
+    /// The indexes of the solutions the challenger solved in ascending order.
+    pub fn solved_indexes(&self) -> Vec<u8> {
+        (0..=u8::MAX).filter(|x| self.has_solved(*x)).collect()
+    }
+
+    pub fn has_revoked(&self, index: u8) -> bool {
+        self.revoked[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Moves the solution from the solved to the revoked solutions.
+    /// The challenger is no longer considered to have redeemed if this was the only
+    /// solution they solved.
+    pub fn mark_revoked(&mut self, index: u8) {
+        self.solved[index as usize / 8] &= !(1 << (index % 8));
+        self.revoked[index as usize / 8] |= 1 << (index % 8);
+        self.redeemed = self.solved.iter().any(|x| *x != 0);
+    }
+
+    /// The indexes of the solutions revoked from the challenger in ascending order.
+    pub fn revoked_indexes(&self) -> Vec<u8> {
+        (0..=u8::MAX).filter(|x| self.has_revoked(*x)).collect()
+    }
 }
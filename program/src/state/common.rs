@@ -4,7 +4,10 @@ use solana_program::{
     program_error::ProgramError, pubkey::Pubkey,
 };
 
-use crate::utils::{assert_account_is_funded_and_has_data, assert_keys_equal};
+use crate::{
+    error::ChallengeError,
+    utils::{assert_account_is_funded_and_has_data, assert_keys_equal},
+};
 
 // -----------------
 // StateFromAccount
@@ -83,12 +86,17 @@ impl<'a, T: BorshDeserialize, F: FnOnce() -> (Pubkey, u8)>
         let (pda, bump) = get_pda_and_bump();
         let state: T = self.try_state_from_account_unchecked()?;
 
-        assert_keys_equal(self.key, &pda, || {
-            format!(
+        assert_keys_equal(
+            self.key,
+            &pda,
+            ChallengeError::IncorrectPda,
+            || {
+                format!(
                 "The derrived PDA ({}) does not match the address of the provided PDA account ({})",
                 pda, self.key
             )
-        })?;
+            },
+        )?;
 
         Ok(StateFromPdaAccountValue { state, pda, bump })
     }
@@ -120,12 +128,17 @@ impl<'a, T: BorshDeserialize, F: FnOnce() -> (Pubkey, u8)>
         let (pda, bump) = get_pda_and_bump();
         let state: T = self.try_state_from_account()?;
 
-        assert_keys_equal(self.key, &pda, || {
-            format!(
+        assert_keys_equal(
+            self.key,
+            &pda,
+            ChallengeError::IncorrectPda,
+            || {
+                format!(
                 "The derrived PDA ({}) does not match the address of the provided PDA account ({})",
                 pda, self.key
             )
-        })?;
+            },
+        )?;
 
         Ok(StateFromPdaAccountValue { state, pda, bump })
     }
@@ -0,0 +1,134 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    challenge_id,
+    error::ChallengeError,
+    utils::{assert_keys_equal, assert_owned_by_program},
+};
+
+use super::{HasPda, HasSize, TryStateFromAccount};
+
+#[derive(Debug, Default, ShankAccount, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds("config")]
+/// This is the PDA account that holds the program-wide configuration.
+/// It exists at most once per deployment and is passed by every instruction as the
+/// last of its declared accounts.
+/// As long as it is not initialized the program is not paused and charges no protocol fee.
+pub struct ProgramConfig {
+    /// The authority that can update the config.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
+    pub admin: Pubkey,
+
+    /// The fee in basis points of the `admit_cost` that is taken from every admission and
+    /// transferred to the `treasury`. The rest of the `admit_cost` is split among the fee
+    /// recipients and creator or escrowed in the pot of the challenge.
+    /// Changing it also changes the refunds of cancelled challenges for the unused tries
+    /// since they are refunded without the protocol fee.
+    pub protocol_fee_basis_points: u16,
+
+    /// The account receiving the protocol fees.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
+    pub treasury: Pubkey,
+
+    /// If set no instruction besides updating the config is processed.
+    pub paused: bool,
+
+    /// The bump of the config PDA which is stored so that the config can be verified
+    /// without deriving its address for every instruction.
+    pub bump: u8,
+}
+
+/// The maximum protocol fee, i.e. 100% of the `admit_cost`.
+pub const MAX_PROTOCOL_FEE_BASIS_POINTS: u16 = 10_000;
+
+#[rustfmt::skip]
+pub const PROGRAM_CONFIG_SIZE: usize =
+    /* admin */                     32 +
+    /* protocol_fee_basis_points */  2 +
+    /* treasury */                  32 +
+    /* paused */                     1 +
+    /* bump */                       1;
+
+impl HasSize for ProgramConfig {
+    fn size(&self) -> usize {
+        PROGRAM_CONFIG_SIZE
+    }
+}
+
+impl HasPda for ProgramConfig {
+    fn pda(&self) -> (Pubkey, u8) {
+        ProgramConfig::pda()
+    }
+}
+
+impl ProgramConfig {
+    pub fn pda() -> (Pubkey, u8) {
+        ProgramConfig::shank_pda(&challenge_id())
+    }
+
+    pub fn size() -> usize {
+        PROGRAM_CONFIG_SIZE
+    }
+
+    /// Deserializes the config from the provided account after verifying that it is the
+    /// config PDA via the bump stored in it.
+    /// Returns `None` if the config was not initialized yet in which case the config PDA
+    /// has to be derived to verify the account.
+    pub fn from_config_account(
+        config_info: &AccountInfo,
+    ) -> Result<Option<ProgramConfig>, ProgramError> {
+        if config_info.data_is_empty() {
+            let (config_pda, _) = ProgramConfig::pda();
+            assert_is_config_pda(config_info, &config_pda)?;
+            return Ok(None);
+        }
+
+        assert_owned_by_program(config_info, "program_config")?;
+        let config: ProgramConfig = config_info.try_state_from_account()?;
+        let config_pda = Pubkey::create_program_address(
+            &ProgramConfig::shank_seeds_with_bump(&[config.bump]),
+            &challenge_id(),
+        )
+        .map_err(|_| {
+            msg!("Err: program config bump {} is invalid", config.bump);
+            ChallengeError::IncorrectPda
+        })?;
+        assert_is_config_pda(config_info, &config_pda)?;
+
+        Ok(Some(config))
+    }
+
+    /// The protocol fee taken from the [admit_cost].
+    pub fn protocol_fee(&self, admit_cost: u64) -> u64 {
+        (admit_cost as u128 * self.protocol_fee_basis_points as u128
+            / MAX_PROTOCOL_FEE_BASIS_POINTS as u128) as u64
+    }
+}
+
+fn assert_is_config_pda(
+    config_info: &AccountInfo,
+    config_pda: &Pubkey,
+) -> Result<(), ProgramError> {
+    assert_keys_equal(
+        config_info.key,
+        config_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+                "Provided account ({}) is not the program config ({})",
+                config_info.key, config_pda
+            )
+        },
+    )
+}
@@ -1,9 +1,15 @@
 mod challenge;
+mod challenge_header;
 mod challenger;
 mod common;
+mod config;
+mod pot;
 mod redeem;
 
 pub use challenge::*;
+pub use challenge_header::*;
 pub use challenger::*;
 pub use common::*;
+pub use config::*;
+pub use pot::*;
 pub use redeem::*;
@@ -0,0 +1,42 @@
+use shank::ShankAccount;
+use solana_program::pubkey::Pubkey;
+
+use crate::challenge_id;
+
+use super::HasPda;
+
+#[derive(ShankAccount)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[seeds("pot", challenge_pda("The PDA of the challenge"))]
+/// This account is only constructed to access convenience methods.
+/// The pot itself is a data-less account owned by the program which holds the
+/// lamports of all admit fees when the challenge is in jackpot mode.
+pub struct Pot {
+    /// The PDA of the challenge whose admit fees accumulate in this pot.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
+    pub challenge_pda: Pubkey,
+
+    /// Derived PDA of this pot
+    /// (the challenge_pda is the address from which it is derived).
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
+    pub pda: Pubkey,
+}
+
+impl Pot {
+    pub fn new(challenge_pda: Pubkey) -> Self {
+        let (pda, _) = Pot::shank_pda(&challenge_id(), &challenge_pda);
+        Self { challenge_pda, pda }
+    }
+}
+
+impl HasPda for Pot {
+    fn pda(&self) -> (Pubkey, u8) {
+        Pot::shank_pda(&challenge_id(), &self.challenge_pda)
+    }
+}
@@ -1,3 +1,4 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
 use solana_program::pubkey::Pubkey;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
@@ -7,17 +8,26 @@ use crate::challenge_id;
 use super::{Challenge, HasPda};
 
 #[derive(ShankAccount)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[seeds("challenge", challenge_pda("The PDA of the challenge"))]
 /// This account is only constructed to access convenience methods.
 /// It's data is never stored on chain.
 pub struct Redeem {
     /// The PDA of the challenge that uses this redeem.
     /// This address is also set to be the authority of the mint.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
     pub challenge_pda: Pubkey,
 
     /// Derived PDA of this redeem token
     /// (the challenge_pda is the address from which it is derived).
     /// This is the actual mint address we use.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::pubkey")
+    )]
     pub pda: Pubkey,
 }
 
@@ -32,15 +42,59 @@ impl Redeem {
         Redeem::new(challenge_pda)
     }
 
+    /// The token program that owns the redeem mint which is Token-2022 for
+    /// [SoulboundRedeem] mints and spl_token otherwise.
+    pub fn token_program(soulbound: bool) -> Pubkey {
+        if soulbound {
+            spl_token_2022::id()
+        } else {
+            spl_token::id()
+        }
+    }
+
+    /// The associated token account for a redeem mint owned by the spl_token program.
     pub fn ata(&self, recvr: &Pubkey) -> Pubkey {
+        self.ata_with_program_id(recvr, &spl_token::id())
+    }
+
+    /// The associated token account for a redeem mint owned by the provided
+    /// [token_program], see [Redeem::token_program].
+    pub fn ata_with_program_id(
+        &self,
+        recvr: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Pubkey {
         get_associated_token_address_with_program_id(
             recvr,
             &self.pda,
-            &spl_token::id(),
+            token_program,
         )
     }
 }
 
+#[derive(
+    BorshSerialize, BorshDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Configures the redeem mint of a challenge to be created under the Token-2022 program
+/// with the NonTransferable extension.
+/// This way the token a challenger receives for a correct solution stays with them and
+/// can be used as a credential, i.e. as the `required_mint` of another challenge.
+pub struct SoulboundRedeem {
+    /// If set the MetadataPointer extension is initialized to point to this address
+    /// with the challenge PDA as its authority.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::utils::serde_fields::option_pubkey")
+    )]
+    pub metadata_address: Option<Pubkey>,
+
+    /// If set the PermanentDelegate extension is initialized with the challenge PDA as
+    /// the delegate which allows the creator to burn the token of a fraudulent solve,
+    /// see [crate::ixs::revoke_solve].
+    pub revocable: bool,
+}
+
 impl HasPda for Redeem {
     fn pda(&self) -> (Pubkey, u8) {
         Redeem::shank_pda(&challenge_id(), &self.challenge_pda)
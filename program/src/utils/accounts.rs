@@ -1,5 +1,5 @@
 use solana_program::{
-    account_info::AccountInfo,
+    account_info::{next_account_info, AccountInfo},
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
@@ -9,7 +9,24 @@ use solana_program::{
     sysvar::Sysvar,
 };
 
-use crate::error::ChallengeError;
+use crate::{challenge_id, error::ChallengeError};
+
+/// Returns the next account unless the program id was passed in its place which is how
+/// optional accounts that are not provided are passed.
+pub fn next_optional_account_info<
+    'a,
+    'b,
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+>(
+    iter: &mut I,
+) -> Result<Option<I::Item>, ProgramError> {
+    let info = next_account_info(iter)?;
+    Ok(if info.key == &challenge_id() {
+        None
+    } else {
+        Some(info)
+    })
+}
 
 // The below two methods create an account owned by the program, namely they initialize
 // the Challenge PDA.
@@ -1,22 +1,40 @@
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    account_info::AccountInfo,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    entrypoint::ProgramResult,
+    msg,
+    program_utils::limited_deserialize,
+    pubkey::Pubkey,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions, state::Account as TokenAccount,
 };
 
 use crate::{
+    challenge_id,
     error::ChallengeError,
-    state::{Challenge, Challenger},
+    program_data_address,
+    state::{
+        Challenge, ChallengeStatus, Challenger, FeeRecipient, Pot,
+        ProgramConfig, SolutionHashing, MAX_FEE_RECIPIENTS,
+        MAX_HASH_ITERATIONS, MAX_PROTOCOL_FEE_BASIS_POINTS,
+        TOTAL_FEE_BASIS_POINTS,
+    },
     Solution,
 };
 
+use super::{verify_admission_proof, AdmissionNode};
+
 pub fn assert_keys_equal<F: FnOnce() -> String>(
     provided_key: &Pubkey,
     expected_key: &Pubkey,
+    err: ChallengeError,
     get_msg: F,
 ) -> ProgramResult {
     if provided_key.ne(expected_key) {
         msg!("Err: {}", get_msg());
         msg!("Err: provided {} expected {}", provided_key, expected_key);
-        Err(ChallengeError::ProvidedAtaIsIncorrect.into())
+        Err(err.into())
     } else {
         Ok(())
     }
@@ -134,24 +152,42 @@ pub fn assert_not_started(challenge: &Challenge) -> ProgramResult {
     }
 }
 
-pub fn assert_started(challenge: &Challenge) -> ProgramResult {
-    if !challenge.started {
-        msg!("Err: challenge '{}' has not yet started and is not admitting challengers", challenge.id);
+pub fn assert_started(challenge: &impl ChallengeStatus) -> ProgramResult {
+    if !challenge.is_started() {
+        msg!("Err: challenge '{}' has not yet started and is not admitting challengers", challenge.id());
         Err(ChallengeError::ChallengeNotYetStarted.into())
     } else {
         Ok(())
     }
 }
 
-pub fn assert_not_finished(challenge: &Challenge) -> ProgramResult {
-    if challenge.finished {
-        msg!("Err: challenge '{}' has already finished and is not admitting challengers nor accepting solutions", challenge.id);
+pub fn assert_not_finished(challenge: &impl ChallengeStatus) -> ProgramResult {
+    if challenge.is_finished() {
+        msg!("Err: challenge '{}' has already finished and is not admitting challengers nor accepting solutions", challenge.id());
         Err(ChallengeError::ChallengeAlreadyFinished.into())
     } else {
         Ok(())
     }
 }
 
+pub fn assert_finished(challenge: &Challenge) -> ProgramResult {
+    if !challenge.finished {
+        msg!("Err: challenge '{}' has not finished yet", challenge.id);
+        Err(ChallengeError::ChallengeNotYetFinished.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_cancelled(challenge: &Challenge) -> ProgramResult {
+    if !challenge.cancelled {
+        msg!("Err: challenge '{}' was not cancelled", challenge.id);
+        Err(ChallengeError::ChallengeNotCancelled.into())
+    } else {
+        Ok(())
+    }
+}
+
 pub fn assert_account_does_not_exist(
     account: &AccountInfo,
     acc_name: &str,
@@ -171,12 +207,12 @@ pub fn assert_account_does_not_exist(
     }
 }
 
-pub fn assert_has_solution(challenge: &Challenge) -> ProgramResult {
-    if challenge.current_solution().is_none() {
+pub fn assert_has_solution(challenge: &impl ChallengeStatus) -> ProgramResult {
+    if !challenge.has_current_solution() {
         msg!("Err: challenge '{}' is out of solutions, not sure how that happened",
-            challenge.id);
+            challenge.id());
 
-        Err(ChallengeError::SolutionIsIncorrect.into())
+        Err(ChallengeError::OutOfSolutions.into())
     } else {
         Ok(())
     }
@@ -192,3 +228,427 @@ pub fn assert_challenger_has_tries_remaining(
         Ok(())
     }
 }
+
+pub fn assert_challenger_is_allowlisted(
+    challenge: &Challenge,
+    challenger: &Pubkey,
+    admission_proof: &[AdmissionNode],
+) -> ProgramResult {
+    match &challenge.admission_root {
+        Some(root)
+            if !verify_admission_proof(root, challenger, admission_proof) =>
+        {
+            msg!(
+                "Err: challenger ({}) is not included in the allowlist of challenge '{}'",
+                challenger,
+                challenge.id
+            );
+            Err(ChallengeError::ChallengerNotAllowlisted.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn assert_challenger_holds_required_token(
+    challenge: &Challenge,
+    challenger: &Pubkey,
+    challenger_token_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    let required_mint = match &challenge.required_mint {
+        Some(required_mint) => required_mint,
+        None => return Ok(()),
+    };
+    let challenger_token_info = match challenger_token_info {
+        Some(info) => info,
+        None => {
+            msg!(
+                "Err: challenge '{}' requires a token account for mint ({})",
+                challenge.id,
+                required_mint
+            );
+            return Err(ChallengeError::RequiredTokenAccountMissing.into());
+        }
+    };
+
+    if challenger_token_info.owner.ne(&spl_token::id())
+        && challenger_token_info.owner.ne(&spl_token_2022::id())
+    {
+        msg!(
+            "Err: provided token account ({}) is owned by ({}) which is neither the spl_token nor the spl_token_2022 program",
+            challenger_token_info.key,
+            challenger_token_info.owner
+        );
+        return Err(ChallengeError::IncorrectAccountOwner.into());
+    }
+    // Token-2022 accounts share the base layout of spl_token accounts, followed by
+    // their extensions, i.e. the token accounts of soulbound redeem mints
+    let data = challenger_token_info.try_borrow_data()?;
+    let token_account =
+        StateWithExtensions::<TokenAccount>::unpack(&data)?.base;
+
+    if token_account.mint.ne(required_mint)
+        || token_account.owner.ne(challenger)
+        || token_account.amount < challenge.required_amount
+    {
+        msg!(
+            "Err: challenger ({}) needs to hold {} of mint ({}), but provided token account ({}) holds {} of mint ({}) owned by ({})",
+            challenger,
+            challenge.required_amount,
+            required_mint,
+            challenger_token_info.key,
+            token_account.amount,
+            token_account.mint,
+            token_account.owner
+        );
+        Err(ChallengeError::ChallengerLacksRequiredToken.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_valid_fee_recipients(
+    fee_recipients: &[FeeRecipient],
+) -> ProgramResult {
+    if fee_recipients.len() > MAX_FEE_RECIPIENTS {
+        msg!(
+            "Err: fee recipients len ({}) is greater than maximum supported fee recipients ({})",
+            fee_recipients.len(),
+            MAX_FEE_RECIPIENTS
+        );
+        return Err(ChallengeError::ExceedingMaxFeeRecipients.into());
+    }
+    if fee_recipients.is_empty() {
+        return Ok(());
+    }
+
+    let total_basis_points = fee_recipients
+        .iter()
+        .map(|x| x.basis_points as u32)
+        .sum::<u32>();
+    if total_basis_points.ne(&(TOTAL_FEE_BASIS_POINTS as u32)) {
+        msg!(
+            "Err: basis points of fee recipients add up to {} instead of {}",
+            total_basis_points,
+            TOTAL_FEE_BASIS_POINTS
+        );
+        Err(ChallengeError::FeeRecipientsBasisPointsInvalid.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_fee_recipient(
+    fee_recipient_info: &AccountInfo,
+    fee_recipient: &FeeRecipient,
+) -> ProgramResult {
+    if fee_recipient_info.key.ne(&fee_recipient.recipient) {
+        msg!(
+            "Err: provided fee recipient ({}) does not match fee recipient ({}) of the challenge",
+            fee_recipient_info.key,
+            fee_recipient.recipient
+        );
+        Err(ChallengeError::FeeRecipientMismatch.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_valid_jackpot(
+    challenge: &Challenge,
+    jackpot: Option<u16>,
+) -> ProgramResult {
+    let basis_points = match jackpot {
+        Some(basis_points) => basis_points,
+        None => return Ok(()),
+    };
+    if basis_points == 0 || basis_points > TOTAL_FEE_BASIS_POINTS {
+        msg!(
+            "Err: jackpot share of {} basis points is not between 1 and {}",
+            basis_points,
+            TOTAL_FEE_BASIS_POINTS
+        );
+        return Err(ChallengeError::JackpotBasisPointsInvalid.into());
+    }
+    if !challenge.fee_recipients.is_empty() {
+        msg!(
+            "Err: challenge '{}' splits the admit fee among fee recipients and cannot be in jackpot mode",
+            challenge.id
+        );
+        Err(ChallengeError::JackpotWithFeeRecipients.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_pot(
+    challenge: &Challenge,
+    pot_info: &AccountInfo,
+) -> ProgramResult {
+    let (pot_pda, _) = challenge.pot_pda();
+    assert_keys_equal(
+        pot_info.key,
+        &pot_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided pot ({}) does not match the pot ({}) of the challenge",
+            pot_info.key, pot_pda
+        )
+        },
+    )
+}
+
+/// Same as [assert_pot] except that it uses the pot bump stored on the challenge
+/// instead of deriving the pot PDA.
+pub fn assert_pot_with_bump(
+    challenge_pda: &Pubkey,
+    pot_bump: u8,
+    pot_info: &AccountInfo,
+) -> ProgramResult {
+    let pot_pda = Pubkey::create_program_address(
+        &Pot::shank_seeds_with_bump(challenge_pda, &[pot_bump]),
+        &challenge_id(),
+    )
+    .map_err(|_| ChallengeError::IncorrectPda)?;
+    assert_keys_equal(
+        pot_info.key,
+        &pot_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+            "Provided pot ({}) does not match the pot ({}) of the challenge",
+            pot_info.key, pot_pda
+        )
+        },
+    )
+}
+
+pub fn assert_valid_escrow(
+    challenge: &Challenge,
+    escrow: bool,
+) -> ProgramResult {
+    if escrow && !challenge.fee_recipients.is_empty() {
+        msg!(
+            "Err: challenge '{}' splits the admit fee among fee recipients and cannot escrow it",
+            challenge.id
+        );
+        Err(ChallengeError::EscrowWithFeeRecipients.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_escrows_admit_fees(challenge: &Challenge) -> ProgramResult {
+    if !challenge.escrows_admit_fees() {
+        msg!(
+            "Err: challenge '{}' pays admit fees directly and holds no escrow",
+            challenge.id
+        );
+        Err(ChallengeError::ChallengeDoesNotEscrow.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_not_paused(config: Option<&ProgramConfig>) -> ProgramResult {
+    if matches!(config, Some(ProgramConfig { paused: true, .. })) {
+        msg!("Err: program is paused");
+        Err(ChallengeError::ProgramPaused.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Ensures that [program_data_info] is the ProgramData account of this program and
+/// that [authority_info] is the upgrade authority stored in it.
+pub fn assert_upgrade_authority(
+    program_data_info: &AccountInfo,
+    authority_info: &AccountInfo,
+) -> ProgramResult {
+    let program_data = program_data_address();
+    assert_keys_equal(
+        program_data_info.key,
+        &program_data,
+        ChallengeError::IncorrectProgramData,
+        || {
+            format!(
+                "Account ({}) is not the ProgramData account of the program",
+                program_data_info.key
+            )
+        },
+    )?;
+    assert_keys_equal(
+        program_data_info.owner,
+        &bpf_loader_upgradeable::id(),
+        ChallengeError::IncorrectProgramData,
+        || "ProgramData account is not owned by the upgradeable loader".into(),
+    )?;
+
+    let metadata_size = UpgradeableLoaderState::size_of_programdata_metadata();
+    let data = program_data_info.try_borrow_data()?;
+    let upgrade_authority =
+        match data.get(..metadata_size).and_then(|metadata| {
+            limited_deserialize(metadata, metadata_size as u64).ok()
+        }) {
+            Some(UpgradeableLoaderState::ProgramData {
+                upgrade_authority_address,
+                ..
+            }) => upgrade_authority_address,
+            _ => {
+                msg!("Err: ProgramData account data is invalid");
+                return Err(ChallengeError::IncorrectProgramData.into());
+            }
+        };
+
+    if upgrade_authority != Some(*authority_info.key) {
+        msg!(
+            "Err: {} is not the upgrade authority of the program",
+            authority_info.key
+        );
+        Err(ChallengeError::NotUpgradeAuthority.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_valid_protocol_fee(
+    protocol_fee_basis_points: u16,
+) -> ProgramResult {
+    if protocol_fee_basis_points > MAX_PROTOCOL_FEE_BASIS_POINTS {
+        msg!(
+            "Err: protocol fee of {} basis points exceeds {}",
+            protocol_fee_basis_points,
+            MAX_PROTOCOL_FEE_BASIS_POINTS
+        );
+        Err(ChallengeError::ProtocolFeeBasisPointsInvalid.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_owned_by_program(
+    account: &AccountInfo,
+    account_label: &str,
+) -> ProgramResult {
+    assert_keys_equal(
+        account.owner,
+        &challenge_id(),
+        ChallengeError::IncorrectAccountOwner,
+        || {
+            format!(
+                "Account '{}' ({}) is not owned by the challenge program",
+                account_label, account.key
+            )
+        },
+    )
+}
+
+pub fn assert_challenger_migrated(
+    challenger_info: &AccountInfo,
+) -> ProgramResult {
+    if Challenger::is_legacy_layout(&challenger_info.try_borrow_data()?) {
+        msg!(
+            "Err: challenger ({}) needs to be migrated to the current layout",
+            challenger_info.key
+        );
+        Err(ChallengeError::ChallengerNeedsMigration.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_challenge_migrated(
+    challenge_info: &AccountInfo,
+) -> ProgramResult {
+    if Challenge::is_legacy_layout(&challenge_info.try_borrow_data()?) {
+        msg!(
+            "Err: challenge ({}) needs to be migrated to the current layout",
+            challenge_info.key
+        );
+        Err(ChallengeError::ChallengeNeedsMigration.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_valid_hook_program(
+    hook_program: Option<&Pubkey>,
+) -> ProgramResult {
+    if matches!(hook_program, Some(hook_program) if hook_program == &challenge_id())
+    {
+        msg!("Err: the challenge program cannot be its own hook program");
+        Err(ChallengeError::InvalidHookProgram.into())
+    } else {
+        Ok(())
+    }
+}
+
+pub fn assert_hook_program(
+    hook_program: &Pubkey,
+    hook_program_info: &AccountInfo,
+) -> ProgramResult {
+    assert_keys_equal(
+        hook_program_info.key,
+        hook_program,
+        ChallengeError::IncorrectHookProgram,
+        || {
+            format!(
+                "Provided hook program ({}) does not match the hook program ({}) of the challenge",
+                hook_program_info.key, hook_program
+            )
+        },
+    )
+}
+
+pub fn assert_valid_solution_hashing(
+    hashing: Option<&SolutionHashing>,
+) -> ProgramResult {
+    match hashing {
+        Some(SolutionHashing { iterations, .. })
+            if *iterations > MAX_HASH_ITERATIONS =>
+        {
+            msg!(
+                "Err: solution hash iterations ({}) exceed maximum supported iterations ({})",
+                iterations,
+                MAX_HASH_ITERATIONS
+            );
+            Err(ChallengeError::ExceedingMaxHashIterations.into())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn assert_challenger_solved(
+    challenger: &Challenger,
+    index: u8,
+) -> ProgramResult {
+    if !challenger.has_solved(index) {
+        msg!(
+            "Err: challenger ({}) did not solve solution at index {}",
+            challenger.authority,
+            index
+        );
+        Err(ChallengeError::SolveNotFound.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Only the most recently solved solution can be reopened since solutions are solved in
+/// order and reopening an earlier one would reopen all solved after it as well.
+pub fn assert_can_reopen_solution(
+    challenge: &Challenge,
+    index: u8,
+) -> ProgramResult {
+    if challenge.cancelled || index.checked_add(1) != Some(challenge.solving) {
+        msg!(
+            "Err: cannot reopen solution at index {} of challenge '{}' which is solving index {}",
+            index,
+            challenge.id,
+            challenge.solving
+        );
+        Err(ChallengeError::CannotReopenSolution.into())
+    } else {
+        Ok(())
+    }
+}
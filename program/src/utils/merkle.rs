@@ -0,0 +1,102 @@
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+/// A node of the admission allowlist merkle tree, the root of it is stored
+/// on the challenge as `admission_root`.
+pub type AdmissionNode = [u8; 32];
+
+// Domain separation prevents an intermediate node from being passed off as a leaf
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Hashes the challenger pubkey into the leaf that is included in the allowlist.
+pub fn admission_leaf(challenger: &Pubkey) -> AdmissionNode {
+    hashv(&[LEAF_PREFIX, challenger.as_ref()]).to_bytes()
+}
+
+/// Hashes two sibling nodes into their parent.
+/// The pair is sorted first so that a proof does not need to include the
+/// position of each sibling.
+fn hash_pair(a: &AdmissionNode, b: &AdmissionNode) -> AdmissionNode {
+    let (lhs, rhs) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lhs, rhs]).to_bytes()
+}
+
+/// Verifies that the provided [proof] leads from the [challenger]'s leaf to the [root].
+pub fn verify_admission_proof(
+    root: &AdmissionNode,
+    challenger: &Pubkey,
+    proof: &[AdmissionNode],
+) -> bool {
+    let computed = proof
+        .iter()
+        .fold(admission_leaf(challenger), |node, sibling| {
+            hash_pair(&node, sibling)
+        });
+    computed.eq(root)
+}
+
+/// Merkle tree built from the pubkeys of all challengers that are allowed to be
+/// admitted to a challenge.
+/// It is meant to be used off-chain by the creator to derive the `admission_root` and
+/// the proof each challenger needs to provide when being admitted.
+pub struct AdmissionTree {
+    /// Each layer of the tree starting with the leaves and ending with the root.
+    layers: Vec<Vec<AdmissionNode>>,
+}
+
+impl AdmissionTree {
+    /// Builds the tree from the provided challengers.
+    /// The order of challengers does not matter since the leaves are sorted and
+    /// duplicates removed.
+    pub fn new(challengers: &[Pubkey]) -> Self {
+        let mut leaves = challengers
+            .iter()
+            .map(admission_leaf)
+            .collect::<Vec<AdmissionNode>>();
+        leaves.sort();
+        leaves.dedup();
+
+        let mut layers = vec![leaves];
+        loop {
+            let layer = layers.last().unwrap();
+            if layer.len() <= 1 {
+                break;
+            }
+            let parents = layer
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    // an odd node is carried up to the next layer as is
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<AdmissionNode>>();
+            layers.push(parents);
+        }
+
+        Self { layers }
+    }
+
+    /// The root to store as the challenge's `admission_root`.
+    /// Returns `None` if the tree was built without any challengers.
+    pub fn root(&self) -> Option<AdmissionNode> {
+        self.layers.last().and_then(|layer| layer.first()).copied()
+    }
+
+    /// Returns the proof that the [challenger] needs to provide when being admitted or
+    /// `None` if the challenger is not part of the allowlist.
+    pub fn proof(&self, challenger: &Pubkey) -> Option<Vec<AdmissionNode>> {
+        let leaf = admission_leaf(challenger);
+        let mut idx = self.layers.first()?.binary_search(&leaf).ok()?;
+
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            let sibling_idx = idx ^ 1;
+            if let Some(sibling) = layer.get(sibling_idx) {
+                proof.push(*sibling);
+            }
+            idx /= 2;
+        }
+        Some(proof)
+    }
+}
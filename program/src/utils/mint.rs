@@ -4,12 +4,27 @@ use solana_program::{
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_pack::Pack,
+    pubkey::Pubkey,
 };
 use spl_associated_token_account::{
     get_associated_token_address_with_program_id,
     instruction::create_associated_token_account_idempotent,
 };
-use spl_token::instruction::{initialize_mint2, mint_to};
+use spl_token_2022::{
+    extension::{
+        metadata_pointer, permanent_delegate::PermanentDelegate,
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+    },
+    instruction::{
+        burn, initialize_mint2, initialize_non_transferable_mint,
+        initialize_permanent_delegate, mint_to,
+    },
+};
+
+use crate::{
+    error::ChallengeError,
+    state::{Redeem, SoulboundRedeem},
+};
 
 use super::{
     allocate_account_and_assign_owner, assert_keys_equal,
@@ -48,12 +63,87 @@ fn initialize_mint(args: InitMintArgs) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Ensures that the provided token program is either the spl_token or the Token-2022
+/// program.
+fn assert_supported_token_program(
+    spl_token_program_info: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let key = spl_token_program_info.key;
+    if key != &spl_token::id() && key != &spl_token_2022::id() {
+        msg!(
+            "Err: '{}' is neither the spl_token nor the Token-2022 program id",
+            key
+        );
+        return Err(ChallengeError::IncorrectTokenProgram.into());
+    }
+    Ok(())
+}
+
+/// Initializes the NonTransferable and optionally the MetadataPointer and
+/// PermanentDelegate extensions of a Token-2022 mint which needs to happen before the
+/// mint itself is initialized.
+fn initialize_soulbound_extensions(
+    mint_info: &AccountInfo,
+    mint_authority: &Pubkey,
+    spl_token_program_info: &AccountInfo,
+    soulbound: &SoulboundRedeem,
+) -> Result<(), ProgramError> {
+    invoke(
+        &initialize_non_transferable_mint(
+            spl_token_program_info.key,
+            mint_info.key,
+        )?,
+        // 0. `[writable]` The mint account to initialize.
+        &[mint_info.clone()],
+    )?;
+    if let Some(metadata_address) = soulbound.metadata_address {
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                spl_token_program_info.key,
+                mint_info.key,
+                Some(*mint_authority),
+                Some(metadata_address),
+            )?,
+            // 0. `[writable]` The mint to initialize.
+            &[mint_info.clone()],
+        )?;
+    }
+    if soulbound.revocable {
+        invoke(
+            &initialize_permanent_delegate(
+                spl_token_program_info.key,
+                mint_info.key,
+                mint_authority,
+            )?,
+            // 0. `[writable]` The mint to initialize.
+            &[mint_info.clone()],
+        )?;
+    }
+    Ok(())
+}
+
+fn soulbound_extension_types(
+    soulbound: &SoulboundRedeem,
+) -> Vec<ExtensionType> {
+    let mut extension_types = vec![ExtensionType::NonTransferable];
+    if soulbound.metadata_address.is_some() {
+        extension_types.push(ExtensionType::MetadataPointer);
+    }
+    if soulbound.revocable {
+        extension_types.push(ExtensionType::PermanentDelegate);
+    }
+    extension_types
+}
+
 pub struct CreateMintArgs<'a, 'b> {
     pub payer_info: &'a AccountInfo<'a>,
     pub mint_info: &'a AccountInfo<'a>,
     pub mint_authority_info: &'a AccountInfo<'a>,
     pub spl_token_program_info: &'a AccountInfo<'a>,
     pub signer_seeds: &'b [&'b [u8]],
+    /// If provided the mint is created under the Token-2022 program with the
+    /// extensions configured by [SoulboundRedeem], otherwise under spl_token
+    pub soulbound: Option<&'b SoulboundRedeem>,
 }
 
 pub fn create_mint(args: CreateMintArgs) -> Result<(), ProgramError> {
@@ -65,23 +155,45 @@ pub fn create_mint(args: CreateMintArgs) -> Result<(), ProgramError> {
         mint_authority_info,
         spl_token_program_info,
         signer_seeds,
+        soulbound,
     } = args;
 
-    assert_keys_equal(spl_token_program_info.key, &spl_token::id(), || {
-        format!(
-            "'{}' does not match the spl_token program id",
-            spl_token_program_info.key
-        )
-    })?;
+    let token_program = Redeem::token_program(soulbound.is_some());
+    assert_keys_equal(
+        spl_token_program_info.key,
+        &token_program,
+        ChallengeError::IncorrectTokenProgram,
+        || {
+            format!(
+                "'{}' does not match the token program id ({})",
+                spl_token_program_info.key, token_program
+            )
+        },
+    )?;
 
+    let size = match soulbound {
+        Some(soulbound) => ExtensionType::try_calculate_account_len::<
+            spl_token_2022::state::Mint,
+        >(&soulbound_extension_types(soulbound))?,
+        None => spl_token::state::Mint::LEN,
+    };
     allocate_account_and_assign_owner(AllocateAndAssignAccountArgs {
         payer_info,
         account_info: mint_info,
         owner: spl_token_program_info.key,
-        size: spl_token::state::Mint::LEN,
+        size,
         signer_seeds,
     })?;
 
+    if let Some(soulbound) = soulbound {
+        initialize_soulbound_extensions(
+            mint_info,
+            mint_authority_info.key,
+            spl_token_program_info,
+            soulbound,
+        )?;
+    }
+
     initialize_mint(InitMintArgs {
         mint_info,
         mint_authority_info,
@@ -110,17 +222,36 @@ pub fn mint_token_to_recvr(args: MintTokenArgs) -> Result<(), ProgramError> {
         signer_seeds,
     } = args;
 
+    // the mint is owned by either token program depending on how it was created
+    assert_supported_token_program(spl_token_program_info)?;
+    assert_keys_equal(
+        mint_info.owner,
+        spl_token_program_info.key,
+        ChallengeError::IncorrectTokenProgram,
+        || {
+            format!(
+                "Provided token program ({}) does not own the mint ({}) which is owned by ({})",
+                spl_token_program_info.key, mint_info.key, mint_info.owner
+            )
+        },
+    )?;
+
     let ata = get_associated_token_address_with_program_id(
         recvr_info.key,
         mint_info.key,
         spl_token_program_info.key,
     );
-    assert_keys_equal(recvr_ata_info.key, &ata, || {
-        format!(
-            "The provided recvr ATA ('{}') does not match ('{}')",
-            recvr_ata_info.key, ata
-        )
-    })?;
+    assert_keys_equal(
+        recvr_ata_info.key,
+        &ata,
+        ChallengeError::ProvidedAtaIsIncorrect,
+        || {
+            format!(
+                "The provided recvr ATA ('{}') does not match ('{}')",
+                recvr_ata_info.key, ata
+            )
+        },
+    )?;
 
     msg!("mint_token_to_recvr() Creating ATA",);
     invoke(
@@ -164,3 +295,109 @@ pub fn mint_token_to_recvr(args: MintTokenArgs) -> Result<(), ProgramError> {
 
     Ok(())
 }
+
+/// Ensures that the mint is owned by the Token-2022 program and has the [delegate] as
+/// its permanent delegate which is able to burn tokens of any token account.
+pub fn assert_permanent_delegate(
+    mint_info: &AccountInfo,
+    spl_token_program_info: &AccountInfo,
+    delegate: &Pubkey,
+) -> Result<(), ProgramError> {
+    assert_keys_equal(
+        spl_token_program_info.key,
+        &spl_token_2022::id(),
+        ChallengeError::IncorrectTokenProgram,
+        || {
+            format!(
+                "'{}' does not match the Token-2022 program id",
+                spl_token_program_info.key
+            )
+        },
+    )?;
+    if mint_info.owner != spl_token_program_info.key {
+        msg!(
+            "Err: mint ({}) is not owned by the Token-2022 program",
+            mint_info.key
+        );
+        return Err(ChallengeError::SolveNotRevocable.into());
+    }
+
+    let data = mint_info.try_borrow_data()?;
+    let mint =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let permanent_delegate = mint
+        .get_extension::<PermanentDelegate>()
+        .ok()
+        .and_then(|x| Option::<Pubkey>::from(x.delegate));
+    if permanent_delegate.as_ref() != Some(delegate) {
+        msg!(
+            "Err: permanent delegate of mint ({}) is {:?}, but should be ({})",
+            mint_info.key,
+            permanent_delegate,
+            delegate
+        );
+        return Err(ChallengeError::SolveNotRevocable.into());
+    }
+    Ok(())
+}
+
+pub struct BurnTokenArgs<'a, 'b> {
+    pub owner_info: &'a AccountInfo<'a>,
+    pub owner_ata_info: &'a AccountInfo<'a>,
+    pub mint_info: &'a AccountInfo<'a>,
+    pub delegate_info: &'a AccountInfo<'a>,
+    pub spl_token_program_info: &'a AccountInfo<'a>,
+    pub signer_seeds: &'b [&'b [u8]],
+}
+
+/// Burns one token from the associated token account of the owner signed by the
+/// permanent delegate of the mint.
+pub fn burn_token_of_owner(args: BurnTokenArgs) -> Result<(), ProgramError> {
+    let BurnTokenArgs {
+        owner_info,
+        owner_ata_info,
+        mint_info,
+        delegate_info,
+        spl_token_program_info,
+        signer_seeds,
+    } = args;
+
+    let ata = get_associated_token_address_with_program_id(
+        owner_info.key,
+        mint_info.key,
+        spl_token_program_info.key,
+    );
+    assert_keys_equal(
+        owner_ata_info.key,
+        &ata,
+        ChallengeError::ProvidedAtaIsIncorrect,
+        || {
+            format!(
+                "The provided owner ATA ('{}') does not match ('{}')",
+                owner_ata_info.key, ata
+            )
+        },
+    )?;
+
+    invoke_signed(
+        &burn(
+            spl_token_program_info.key,
+            owner_ata_info.key, // account
+            mint_info.key,      // mint
+            delegate_info.key,  // authority (permanent delegate)
+            &[delegate_info.key],
+            1,
+        )?,
+        // 0. `[writable]` The account to burn from.
+        // 1. `[writable]` The token mint.
+        // 2. `[signer]` The account's owner/delegate.
+        &[
+            owner_ata_info.clone(),
+            mint_info.clone(),
+            delegate_info.clone(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
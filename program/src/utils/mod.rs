@@ -1,10 +1,16 @@
 mod accounts;
 mod asserts;
+mod hex;
+mod merkle;
 mod mint;
+#[cfg(feature = "serde")]
+pub mod serde_fields;
 mod solutions;
 
 pub(crate) use accounts::*;
 pub(crate) use asserts::*;
 pub(crate) use mint::*;
 
+pub use hex::*;
+pub use merkle::*;
 pub use solutions::*;
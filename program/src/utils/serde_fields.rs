@@ -0,0 +1,131 @@
+//! Field (de)serializers used via `#[serde(with = "..")]` so that state serializes
+//! pubkeys as base58 and byte arrays like solutions as hex.
+
+use std::str::FromStr;
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+use solana_program::pubkey::Pubkey;
+
+use super::{hex_string, parse_hex};
+
+/// A [Pubkey] as base58 string.
+pub mod pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        pubkey: &Pubkey,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Pubkey, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Pubkey::from_str(&s).map_err(|err| {
+            D::Error::custom(format!("invalid pubkey '{}': {}", s, err))
+        })
+    }
+}
+
+/// An optional [Pubkey] as base58 string or `null`.
+pub mod option_pubkey {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        pubkey: &Option<Pubkey>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match pubkey {
+            Some(pubkey) => serializer.collect_str(pubkey),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Pubkey>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| {
+                Pubkey::from_str(&s).map_err(|err| {
+                    D::Error::custom(format!("invalid pubkey '{}': {}", s, err))
+                })
+            })
+            .transpose()
+    }
+}
+
+fn bytes_from_hex<E: Error, const N: usize>(s: &str) -> Result<[u8; N], E> {
+    parse_hex(s).ok_or_else(|| {
+        E::custom(format!("expected {} bytes as hex, got '{}'", N, s))
+    })
+}
+
+/// A byte array as hex string.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &[u8; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex_string(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        bytes_from_hex(&String::deserialize(deserializer)?)
+    }
+}
+
+/// An optional byte array as hex string or `null`.
+pub mod option_hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        bytes: &Option<[u8; N]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => serializer.serialize_str(&hex_string(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Option<[u8; N]>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| bytes_from_hex(&s))
+            .transpose()
+    }
+}
+
+/// A list of byte arrays, i.e. solutions, as hex strings.
+pub mod hex_vec {
+    use serde::ser::SerializeSeq;
+
+    use super::*;
+
+    pub fn serialize<S: Serializer, const N: usize>(
+        items: &[[u8; N]],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(items.len()))?;
+        for bytes in items {
+            seq.serialize_element(&hex_string(bytes))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<Vec<[u8; N]>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|s| bytes_from_hex(s))
+            .collect()
+    }
+}
@@ -1,18 +1,167 @@
-use solana_program::hash::hash;
+use solana_program::{
+    hash::{hash, hashv},
+    pubkey::Pubkey,
+};
+use unicode_normalization::UnicodeNormalization;
 
-use crate::Solution;
+use crate::{
+    state::{SolutionHashing, SolutionNormalization},
+    Solution,
+};
 
-pub fn hash_solution_challenger_sends(s: &str) -> [u8; 32] {
-    hash(s.as_bytes()).to_bytes()
+/// Normalizes the solution in clear text according to the [SolutionNormalization] of
+/// the challenge before it is hashed.
+pub fn normalize_solution(
+    s: &str,
+    normalization: &SolutionNormalization,
+) -> String {
+    let SolutionNormalization {
+        nfkc,
+        case_fold,
+        trim_whitespace,
+        collapse_whitespace,
+    } = normalization;
+
+    let mut normalized = if *nfkc {
+        s.nfkc().collect()
+    } else {
+        s.to_string()
+    };
+    if *case_fold {
+        normalized = normalized.to_lowercase();
+    }
+    if *collapse_whitespace {
+        normalized =
+            normalized.split_whitespace().collect::<Vec<_>>().join(" ");
+    } else if *trim_whitespace {
+        normalized = normalized.trim().to_string();
+    }
+    normalized
+}
+
+pub fn hash_solution_challenger_sends(
+    s: &str,
+    normalization: &SolutionNormalization,
+) -> [u8; 32] {
+    hash(normalize_solution(s, normalization).as_bytes()).to_bytes()
 }
 
-pub fn hash_solutions(solutions: &[&str]) -> Vec<Solution> {
+pub fn hash_solutions(
+    solutions: &[&str],
+    normalization: &SolutionNormalization,
+) -> Vec<Solution> {
     solutions
         .iter()
         .map(|s| {
-            let challenger_sends = hash_solution_challenger_sends(s);
+            let challenger_sends =
+                hash_solution_challenger_sends(s, normalization);
             // program stores
             hash(&challenger_sends).to_bytes()
         })
         .collect::<Vec<Solution>>()
 }
+
+/// Hashes the solution as the challenger sends it to a challenge with [SolutionHashing],
+/// namely `sha256(salt | challenge_pda | index | solution)`.
+/// Binding it to the challenge and index prevents replaying it to another challenge or
+/// for another solution, even if the same salt was used.
+pub fn hash_salted_solution_challenger_sends(
+    s: &str,
+    normalization: &SolutionNormalization,
+    salt: &[u8; 32],
+    challenge_pda: &Pubkey,
+    index: u8,
+) -> Solution {
+    let normalized = normalize_solution(s, normalization);
+    hashv(&[
+        salt,
+        challenge_pda.as_ref(),
+        &[index],
+        normalized.as_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Derives the solution that the program stores from the one the challenger sends.
+///
+/// Without [SolutionHashing] this is `sha256(challenger_sends)`.
+/// Otherwise the solution is bound to the challenge PDA and its index via
+/// `sha256(challenge_pda | index | challenger_sends)` and the result is rehashed
+/// `iterations` times.
+pub fn stored_solution(
+    challenger_sends: &Solution,
+    challenge_pda: &Pubkey,
+    index: u8,
+    hashing: Option<&SolutionHashing>,
+) -> Solution {
+    match hashing {
+        Some(SolutionHashing { iterations, .. }) => {
+            let mut stored =
+                hashv(&[challenge_pda.as_ref(), &[index], challenger_sends])
+                    .to_bytes();
+            for _ in 0..*iterations {
+                stored = hash(&stored).to_bytes();
+            }
+            stored
+        }
+        None => hash(challenger_sends).to_bytes(),
+    }
+}
+
+/// Hashes solutions in clear text as they are stored by a challenge with [SolutionHashing].
+///
+/// * [challenge_pda]: the PDA of the challenge the solutions are bound to
+/// * [first_index]: the index of the first solution, i.e. the amount of solutions the
+///   challenge has when they are added
+pub fn hash_bound_solutions(
+    solutions: &[&str],
+    normalization: &SolutionNormalization,
+    challenge_pda: &Pubkey,
+    hashing: &SolutionHashing,
+    first_index: u8,
+) -> Vec<Solution> {
+    solutions
+        .iter()
+        .enumerate()
+        .map(|(idx, s)| {
+            let index = first_index.wrapping_add(idx as u8);
+            let challenger_sends = hash_salted_solution_challenger_sends(
+                s,
+                normalization,
+                &hashing.salt,
+                challenge_pda,
+                index,
+            );
+            stored_solution(
+                &challenger_sends,
+                challenge_pda,
+                index,
+                Some(hashing),
+            )
+        })
+        .collect::<Vec<Solution>>()
+}
+
+/// Hashes solutions in clear text as they are stored by the challenge at [challenge_pda],
+/// using [hash_bound_solutions] if the challenge has [SolutionHashing] and
+/// [hash_solutions] otherwise.
+///
+/// * [first_index]: the index of the first solution, only relevant with [SolutionHashing]
+pub fn hash_stored_solutions(
+    solutions: &[&str],
+    normalization: &SolutionNormalization,
+    challenge_pda: &Pubkey,
+    hashing: Option<&SolutionHashing>,
+    first_index: u8,
+) -> Vec<Solution> {
+    match hashing {
+        Some(hashing) => hash_bound_solutions(
+            solutions,
+            normalization,
+            challenge_pda,
+            hashing,
+            first_index,
+        ),
+        None => hash_solutions(solutions, normalization),
+    }
+}
@@ -0,0 +1,80 @@
+//! Helps other programs check if a wallet solved a challenge without depending on the
+//! layout of the accounts of this program.
+
+use solana_program::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    challenge_id,
+    error::ChallengeError,
+    state::Challenger,
+    utils::{
+        assert_account_is_funded_and_has_data, assert_keys_equal,
+        assert_owned_by_program,
+    },
+};
+
+/// Describes how a wallet did on a challenge.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolverStatus {
+    /// Whether the wallet solved at least one solution of the challenge
+    pub solved: bool,
+
+    /// How many solutions of the challenge the wallet solved
+    pub times_solved: u16,
+
+    /// The indexes of the solutions the wallet solved in ascending order
+    pub solved_indexes: Vec<u8>,
+}
+
+/// Reports if the [wallet] solved the challenge by reading its challenger account.
+/// Verifies the following:
+/// - the challenge account is owned by this program
+/// - the challenger account is the PDA derived for the challenge and the wallet
+/// - the challenger account is owned by this program and holds challenger data in the
+///   current or a legacy layout
+///
+/// A wallet that was never admitted to the challenge has no challenger account and is
+/// reported as not having solved it.
+///
+/// * [challenge_info]: the challenge PDA account
+/// * [challenger_info]: the challenger PDA account of the wallet
+/// * [wallet]: the address that was admitted to the challenge
+pub fn verify_solver(
+    challenge_info: &AccountInfo,
+    challenger_info: &AccountInfo,
+    wallet: &Pubkey,
+) -> Result<SolverStatus, ProgramError> {
+    assert_owned_by_program(challenge_info, "challenge")?;
+    assert_account_is_funded_and_has_data(challenge_info)?;
+
+    let (challenger_pda, _) =
+        Challenger::shank_pda(&challenge_id(), challenge_info.key, wallet);
+    assert_keys_equal(
+        challenger_info.key,
+        &challenger_pda,
+        ChallengeError::IncorrectPda,
+        || {
+            format!(
+                "Provided challenger ({}) is not the PDA of wallet ({}) for challenge ({})",
+                challenger_info.key, wallet, challenge_info.key
+            )
+        },
+    )?;
+
+    if challenger_info.data_is_empty() {
+        return Ok(SolverStatus::default());
+    }
+    assert_owned_by_program(challenger_info, "challenger")?;
+
+    let challenger =
+        Challenger::from_account_data(&challenger_info.try_borrow_data()?)?;
+
+    let solved_indexes = challenger.solved_indexes();
+    Ok(SolverStatus {
+        solved: challenger.redeemed,
+        times_solved: solved_indexes.len() as u16,
+        solved_indexes,
+    })
+}
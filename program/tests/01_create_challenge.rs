@@ -4,7 +4,7 @@ use crate::utils::{get_deserialized, get_unpacked, hash_solution};
 use assert_matches::assert_matches;
 use challenge::{
     challenge_id, ixs,
-    state::{Challenge, HasPda, Redeem},
+    state::{Challenge, HasPda, Redeem, CHALLENGE_DISCRIMINATOR},
 };
 use solana_program::{program_option::COption, pubkey::Pubkey};
 use solana_program_test::*;
@@ -78,7 +78,10 @@ async fn create_challenge_without_solutions() {
         assert_matches!(
             value,
             Challenge {
+                discriminator: CHALLENGE_DISCRIMINATOR,
                 authority,
+                bump: _,
+                pot_bump: 0,
                 id,
                 started: false,
                 finished: false,
@@ -86,6 +89,18 @@ async fn create_challenge_without_solutions() {
                 tries_per_admit: 1,
                 redeem: r,
                 solving: 0,
+                admission_root: None,
+                required_mint: None,
+                required_amount: 0,
+                normalization: _,
+                soulbound: false,
+                escrowed_tries: 0,
+                fee_recipients: _,
+                jackpot: None,
+                escrow: false,
+                cancelled: false,
+                hook_program: None,
+                hashing: None,
                 solutions,
             } => {
                 assert_eq!(&authority, &creator);
@@ -128,7 +143,7 @@ async fn create_challenge_with_two_solutions() {
         .expect("Failed create challenge");
 
     // Checks
-    let (challenge_pda, _) =
+    let (challenge_pda, bump) =
         Challenge::shank_pda(&challenge_id(), &creator, ID);
     let (acc, value) =
         get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
@@ -136,7 +151,10 @@ async fn create_challenge_with_two_solutions() {
     assert_matches!(
         value,
         Challenge {
+            discriminator: CHALLENGE_DISCRIMINATOR,
             authority,
+            bump: b,
+            pot_bump: 0,
             id,
             started: false,
             finished: false,
@@ -144,9 +162,22 @@ async fn create_challenge_with_two_solutions() {
             tries_per_admit: 1,
             redeem: r,
             solving: 0,
+            admission_root: None,
+            required_mint: None,
+            required_amount: 0,
+            normalization: _,
+            soulbound: false,
+            escrowed_tries: 0,
+            fee_recipients: _,
+            jackpot: None,
+            escrow: false,
+            cancelled: false,
+            hook_program: None,
+            hashing: None,
             solutions,
         } => {
             assert_eq!(&authority, &creator);
+            assert_eq!(b, bump);
             assert_eq!(id, ID);
             assert_eq!(r, Redeem::new(challenge_pda).pda().0);
             assert_eq!(solutions.len(), 2);
@@ -228,7 +259,10 @@ async fn create_two_challenges_same_creator_different_id() {
         assert_matches!(
             value,
             Challenge {
+                discriminator: CHALLENGE_DISCRIMINATOR,
                 authority,
+                bump: _,
+                pot_bump: 0,
                 id,
                 started: false,
                 finished: false,
@@ -236,6 +270,18 @@ async fn create_two_challenges_same_creator_different_id() {
                 tries_per_admit: 1,
                 redeem: r,
                 solving: 0,
+                admission_root: None,
+                required_mint: None,
+                required_amount: 0,
+                normalization: _,
+                soulbound: false,
+                escrowed_tries: 0,
+                fee_recipients: _,
+                jackpot: None,
+                escrow: false,
+                cancelled: false,
+                hook_program: None,
+                hashing: None,
                 solutions,
             } => {
                 assert_eq!(&authority, &creator);
@@ -260,7 +306,10 @@ async fn create_two_challenges_same_creator_different_id() {
         assert_matches!(
             value,
             Challenge {
+                discriminator: CHALLENGE_DISCRIMINATOR,
                 authority,
+                bump: _,
+                pot_bump: 0,
                 id,
                 started: false,
                 finished: false,
@@ -268,6 +317,18 @@ async fn create_two_challenges_same_creator_different_id() {
                 tries_per_admit: 2,
                 redeem: r,
                 solving: 0,
+                admission_root: None,
+                required_mint: None,
+                required_amount: 0,
+                normalization: _,
+                soulbound: false,
+                escrowed_tries: 0,
+                fee_recipients: _,
+                jackpot: None,
+                escrow: false,
+                cancelled: false,
+                hook_program: None,
+                hashing: None,
                 solutions,
             } => {
                 assert_eq!(&authority, &creator);
@@ -1,11 +1,14 @@
 #![cfg(feature = "test-sbf")]
+// exercises the legacy instruction builders which still need to work for challenges
+// without solution hashing
+#![allow(deprecated)]
 
 use assert_matches::assert_matches;
 use borsh::BorshSerialize;
 use challenge::{
     challenge_id,
     ixs::{self, ChallengeInstruction},
-    state::Challenge,
+    state::{Challenge, CHALLENGE_DISCRIMINATOR},
     utils::hash_solutions,
 };
 use solana_program::{
@@ -30,7 +33,7 @@ async fn add_solutions_creator_pays_to_empty_solutions() {
     let mut context = program_test().start_with_context().await;
     let creator = context.payer.pubkey();
     let added_acc =
-        add_challenge_with_solutions(&mut context, &ID, vec![], None);
+        add_challenge_with_solutions(&mut context, ID, vec![], None);
 
     let (challenge_pda, _) =
         Challenge::shank_pda(&challenge_id(), &context.payer.pubkey(), ID);
@@ -63,7 +66,10 @@ async fn add_solutions_creator_pays_to_empty_solutions() {
     assert_matches!(
         value,
         Challenge {
+            discriminator: CHALLENGE_DISCRIMINATOR,
             authority,
+            bump: _,
+            pot_bump: 0,
             id,
             started: false,
             finished: false,
@@ -71,6 +77,18 @@ async fn add_solutions_creator_pays_to_empty_solutions() {
             tries_per_admit: 1,
             redeem: _,
             solving: 0,
+            admission_root: None,
+            required_mint: None,
+            required_amount: 0,
+            normalization: _,
+            soulbound: false,
+            escrowed_tries: 0,
+            fee_recipients: _,
+            jackpot: None,
+            escrow: false,
+            cancelled: false,
+            hook_program: None,
+            hashing: None,
             solutions,
         } => {
             assert_eq!(&authority, &creator);
@@ -128,7 +146,10 @@ async fn add_solutions_creator_not_payer_to_empty_solutions() {
     assert_matches!(
         value,
         Challenge {
+            discriminator: CHALLENGE_DISCRIMINATOR,
             authority,
+            bump: _,
+            pot_bump: 0,
             id,
             started: false,
             finished: false,
@@ -136,6 +157,18 @@ async fn add_solutions_creator_not_payer_to_empty_solutions() {
             tries_per_admit: 1,
             redeem: _,
             solving: 0,
+            admission_root: None,
+            required_mint: None,
+            required_amount: 0,
+            normalization: _,
+            soulbound: false,
+            escrowed_tries: 0,
+            fee_recipients: _,
+            jackpot: None,
+            escrow: false,
+            cancelled: false,
+            hook_program: None,
+            hashing: None,
             solutions,
         } => {
             assert_eq!(&authority, &creator.pubkey());
@@ -191,7 +224,10 @@ async fn add_solutions_creator_pays_to_two_solutions() {
     assert_matches!(
         value,
         Challenge {
+            discriminator: CHALLENGE_DISCRIMINATOR,
             authority,
+            bump: _,
+            pot_bump: 0,
             id,
             started: false,
             finished: false,
@@ -199,6 +235,18 @@ async fn add_solutions_creator_pays_to_two_solutions() {
             tries_per_admit: 1,
             redeem: _,
             solving: 0,
+            admission_root: None,
+            required_mint: None,
+            required_amount: 0,
+            normalization: _,
+            soulbound: false,
+            escrowed_tries: 0,
+            fee_recipients: _,
+            jackpot: None,
+            escrow: false,
+            cancelled: false,
+            hook_program: None,
+            hashing: None,
             solutions,
         } => {
             assert_eq!(&authority, &creator);
@@ -289,7 +337,7 @@ async fn add_solutions_with_invalid_creator() {
     let ix = {
         let (challenge_pda, _) =
             Challenge::shank_pda(&challenge_id(), &creator, ID);
-        let solutions = hash_solutions(&solutions);
+        let solutions = hash_solutions(&solutions, &Default::default());
         Instruction {
             program_id: challenge_id(),
             accounts: vec![
@@ -334,7 +382,7 @@ async fn add_solutions_with_creator_not_signer() {
     let ix = {
         let (challenge_pda, _) =
             Challenge::shank_pda(&challenge_id(), &creator, ID);
-        let solutions = hash_solutions(&solutions);
+        let solutions = hash_solutions(&solutions, &Default::default());
         Instruction {
             program_id: challenge_id(),
             accounts: vec![
@@ -378,7 +426,7 @@ async fn add_solutions_creator_matches_but_id_is_different() {
     let ix = {
         let (challenge_pda, _) =
             Challenge::shank_pda(&challenge_id(), &creator, ID);
-        let solutions = hash_solutions(&solutions);
+        let solutions = hash_solutions(&solutions, &Default::default());
         Instruction {
             program_id: challenge_id(),
             accounts: vec![
@@ -6,7 +6,7 @@ use borsh::BorshSerialize;
 use challenge::{
     challenge_id,
     ixs::{self, ChallengeInstruction},
-    state::Challenge,
+    state::{Challenge, CHALLENGE_DISCRIMINATOR},
 };
 
 use solana_program::instruction::{AccountMeta, Instruction};
@@ -60,7 +60,10 @@ async fn start_challenge_that_is_not_started_and_has_solutions() {
     assert_matches!(
         value,
         Challenge {
+            discriminator: CHALLENGE_DISCRIMINATOR,
             authority,
+            bump: _,
+            pot_bump: 0,
             id,
             started: true,
             finished: false,
@@ -68,6 +71,18 @@ async fn start_challenge_that_is_not_started_and_has_solutions() {
             tries_per_admit: 1,
             redeem: _,
             solving: 0,
+            admission_root: None,
+            required_mint: None,
+            required_amount: 0,
+            normalization: _,
+            soulbound: false,
+            escrowed_tries: 0,
+            fee_recipients: _,
+            jackpot: None,
+            escrow: false,
+            cancelled: false,
+            hook_program: None,
+            hashing: None,
             solutions,
         } => {
             assert_eq!(&authority, &creator);
@@ -34,27 +34,26 @@ async fn admit_challenger_to_started_challenge() {
     let payer = context.payer.pubkey();
     let challenger = Pubkey::new_unique();
 
-    let solutions = hash_solutions(&["hello", "world"]);
-    add_pda_account(
-        &mut context,
-        &Challenge {
-            authority: creator,
-            id: ID.to_string(),
-            started: true,
-            finished: false,
-            admit_cost: ADMIT_COST,
-            tries_per_admit: TRIES_PER_ADMIT,
-            redeem: Pubkey::new_unique(),
-            solving: 0,
-            solutions,
-        },
-    );
+    let solutions = hash_solutions(&["hello", "world"], &Default::default());
+    let challenge = Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        solutions,
+        ..Default::default()
+    };
+    add_pda_account(&mut context, &challenge);
 
     let AdmitChallengerIx {
         ix,
         challenge_pda,
         challenger_pda,
-    } = ixs::admit_challenger(payer, creator, ID, challenger)
+        ..
+    } = ixs::admit_challenger(payer, &challenge, challenger, None)
         .expect("failed to create instruction");
 
     let tx = Transaction::new_signed_with_payer(
@@ -81,6 +80,7 @@ async fn admit_challenger_to_started_challenge() {
             challenge_pda: cpda,
             tries_remaining: TRIES_PER_ADMIT,
             redeemed: false,
+            ..
         } => {
             assert_eq!(&authority, &challenger);
             assert_eq!(&cpda, &challenge_pda);
@@ -113,18 +113,18 @@ async fn admit_challenger_trying_twice_for_same_challenge() {
     let payer = context.payer.pubkey();
     let challenger = Pubkey::new_unique();
 
-    let solutions = hash_solutions(&["hello", "world"]);
+    let solutions = hash_solutions(&["hello", "world"], &Default::default());
 
     let challenge = Challenge {
         authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
         id: ID.to_string(),
         started: true,
-        finished: false,
         admit_cost: ADMIT_COST,
         tries_per_admit: TRIES_PER_ADMIT,
         redeem: Pubkey::new_unique(),
-        solving: 0,
         solutions,
+        ..Default::default()
     };
 
     let (challenge_pda, _) = challenge.pda();
@@ -132,16 +132,11 @@ async fn admit_challenger_trying_twice_for_same_challenge() {
 
     add_pda_account(
         &mut context,
-        &Challenger {
-            authority: challenger,
-            challenge_pda,
-            tries_remaining: TRIES_PER_ADMIT,
-            redeemed: false,
-        },
+        &Challenger::new(challenger, challenge_pda, TRIES_PER_ADMIT),
     );
 
     let AdmitChallengerIx { ix, .. } =
-        ixs::admit_challenger(payer, creator, ID, challenger)
+        ixs::admit_challenger(payer, &challenge, challenger, None)
             .expect("failed to create instruction");
 
     let tx = Transaction::new_signed_with_payer(
@@ -169,24 +164,23 @@ async fn admit_challenger_to_not_yet_started_challenge() {
     let payer = context.payer.pubkey();
     let challenger = Pubkey::new_unique();
 
-    let solutions = hash_solutions(&["hello", "world"]);
+    let solutions = hash_solutions(&["hello", "world"], &Default::default());
 
     let challenge = &Challenge {
         authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
         id: ID.to_string(),
-        started: false,
-        finished: false,
         admit_cost: ADMIT_COST,
         tries_per_admit: TRIES_PER_ADMIT,
         redeem: Pubkey::new_unique(),
-        solving: 0,
         solutions,
+        ..Default::default()
     };
 
     add_pda_account(&mut context, challenge);
 
     let AdmitChallengerIx { ix, .. } =
-        ixs::admit_challenger(payer, creator, ID, challenger)
+        ixs::admit_challenger(payer, challenge, challenger, None)
             .expect("failed to create instruction");
 
     let tx = Transaction::new_signed_with_payer(
@@ -214,24 +208,25 @@ async fn admit_challenger_to_started_and_finished_challenge() {
     let payer = context.payer.pubkey();
     let challenger = Pubkey::new_unique();
 
-    let solutions = hash_solutions(&["hello", "world"]);
+    let solutions = hash_solutions(&["hello", "world"], &Default::default());
 
     let challenge = &Challenge {
         authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
         id: ID.to_string(),
         started: true,
         finished: true,
         admit_cost: ADMIT_COST,
         tries_per_admit: TRIES_PER_ADMIT,
         redeem: Pubkey::new_unique(),
-        solving: 0,
         solutions,
+        ..Default::default()
     };
 
     add_pda_account(&mut context, challenge);
 
     let AdmitChallengerIx { ix, .. } =
-        ixs::admit_challenger(payer, creator, ID, challenger)
+        ixs::admit_challenger(payer, challenge, challenger, None)
             .expect("failed to create instruction");
 
     let tx = Transaction::new_signed_with_payer(
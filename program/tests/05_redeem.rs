@@ -1,23 +1,33 @@
 #![cfg(feature = "test-sbf")]
+// exercises the legacy instruction builders which still need to work for challenges
+// without solution hashing
+#![allow(deprecated)]
 
 use assert_matches::assert_matches;
 
 use challenge::{
+    challenge_id,
+    error::ChallengeError,
     ixs,
-    state::{Challenge, Challenger, HasPda, Redeem},
+    state::{Challenge, Challenger, HasPda, Redeem, CHALLENGE_DISCRIMINATOR},
     utils::hash_solutions,
 };
 
+use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
 use solana_program_test::*;
 
 #[allow(unused)]
 use crate::utils::dump_account;
 use crate::utils::{
-    add_mint_to_redeem, add_pda_account, verify_minted_when_redeeming,
+    add_mint_to_redeem, add_pda_account, rent_exempt_lamports, try_process_ix,
+    verify_minted_when_redeeming,
 };
 use solana_sdk::{
-    signature::Keypair, signer::Signer, transaction::Transaction,
+    account::{Account, AccountSharedData},
+    signature::Keypair,
+    signer::Signer,
+    transaction::Transaction,
 };
 
 use crate::utils::{get_deserialized, program_test};
@@ -37,12 +47,8 @@ async fn admitted_challenger_redeems_with(
     let challenger_pair = Keypair::new();
     let challenger_key = challenger_pair.pubkey();
 
-    let challenger = Challenger {
-        authority: challenger_key,
-        challenge_pda: challenge.pda().0,
-        tries_remaining: TRIES_PER_ADMIT,
-        redeemed: false,
-    };
+    let challenger =
+        Challenger::new(challenger_key, challenge.pda().0, TRIES_PER_ADMIT);
     add_pda_account(context, &challenger);
 
     let ix = ixs::redeem(
@@ -78,17 +84,17 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
 
     let redeem = Redeem::for_challenge_with(&creator, ID);
 
-    let solutions = hash_solutions(&["hello", "world"]);
+    let solutions = hash_solutions(&["hello", "world"], &Default::default());
     let challenge = &Challenge {
         authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
         id: ID.to_string(),
         started: true,
-        finished: false,
         admit_cost: ADMIT_COST,
         tries_per_admit: TRIES_PER_ADMIT,
         redeem: redeem.pda().0,
-        solving: 0,
         solutions,
+        ..Default::default()
     };
     add_pda_account(&mut context, challenge);
     add_mint_to_redeem(&mut context, &redeem);
@@ -117,6 +123,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 challenge_pda: _,
                 tries_remaining,
                 redeemed: true,
+                ..
             } => {
                 assert_eq!(tries_remaining, TRIES_PER_ADMIT - 1);
             }
@@ -127,12 +134,27 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 .await
                 .1,
             Challenge {
+                discriminator: CHALLENGE_DISCRIMINATOR,
                 authority: _,
+                bump: _,
+                pot_bump: 0,
                 id: _,
                 started: true,
                 finished: false,
                 admit_cost: ADMIT_COST,
                 solving: 1,
+                admission_root: None,
+                required_mint: None,
+                required_amount: 0,
+                normalization: _,
+                soulbound: false,
+                escrowed_tries: 0,
+                fee_recipients: _,
+                jackpot: None,
+                escrow: false,
+                cancelled: false,
+                hook_program: None,
+                hashing: None,
                 solutions: _,
                 tries_per_admit: TRIES_PER_ADMIT,
                 redeem: _,
@@ -169,6 +191,7 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 challenge_pda: _,
                 tries_remaining,
                 redeemed: true,
+                ..
             } => {
                 assert_eq!(tries_remaining, TRIES_PER_ADMIT - 1);
             }
@@ -179,12 +202,27 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
                 .await
                 .1,
             Challenge {
+                discriminator: CHALLENGE_DISCRIMINATOR,
                 authority: _,
+                bump: _,
+                pot_bump: 0,
                 id: _,
                 started: true,
                 finished: true,
                 admit_cost: ADMIT_COST,
                 solving: 2,
+                admission_root: None,
+                required_mint: None,
+                required_amount: 0,
+                normalization: _,
+                soulbound: false,
+                escrowed_tries: 0,
+                fee_recipients: _,
+                jackpot: None,
+                escrow: false,
+                cancelled: false,
+                hook_program: None,
+                hashing: None,
                 solutions: _,
                 tries_per_admit: TRIES_PER_ADMIT,
                 redeem: _,
@@ -213,3 +251,100 @@ async fn redeem_for_valid_challenge_and_two_challengers_with_correct_solution()
 // - redeeming with challenger that was admitted but already redeemed (possibly need a config on
 //   the challenge if multiple redeems are allowed or not)
 //
+
+/// Adds a started challenge with the mint of its redeem.
+fn add_started_challenge(
+    context: &mut ProgramTestContext,
+    creator: Pubkey,
+) -> Challenge {
+    let redeem = Redeem::for_challenge_with(&creator, ID);
+    let challenge = Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: redeem.pda().0,
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..Default::default()
+    };
+    add_pda_account(context, &challenge);
+    add_mint_to_redeem(context, &redeem);
+    challenge
+}
+
+#[tokio::test]
+async fn redeem_with_foreign_challenger_pda() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_started_challenge(&mut context, creator);
+
+    let admitted = Challenger::new(
+        Pubkey::new_unique(),
+        challenge.pda().0,
+        TRIES_PER_ADMIT,
+    );
+    add_pda_account(&mut context, &admitted);
+
+    // an account that was never admitted signs, but passes the admitted challenger PDA
+    let intruder = Keypair::new();
+    let mut ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        intruder.pubkey(),
+        "hello",
+    )
+    .expect("failed to create instruction");
+    ix.accounts[3].pubkey = admitted.pda().0;
+
+    let err = try_process_ix(&mut context, ix, &[&intruder])
+        .await
+        .expect_err("should not redeem with a foreign challenger PDA");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn redeem_with_challenger_account_that_is_not_its_pda() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Pubkey::new_unique();
+    let challenge = add_started_challenge(&mut context, creator);
+
+    // a challenger account owned by the program, but not stored at its PDA
+    let challenger_pair = Keypair::new();
+    let challenger = Challenger::new(
+        challenger_pair.pubkey(),
+        challenge.pda().0,
+        TRIES_PER_ADMIT,
+    );
+    let address = Pubkey::new_unique();
+    let account = AccountSharedData::from(Account {
+        lamports: rent_exempt_lamports(&challenger),
+        data: challenger.try_to_vec().unwrap(),
+        owner: challenge_id(),
+        ..Default::default()
+    });
+    context.set_account(&address, &account);
+
+    let mut ix = ixs::redeem(
+        context.payer.pubkey(),
+        creator,
+        ID,
+        challenger_pair.pubkey(),
+        "hello",
+    )
+    .expect("failed to create instruction");
+    ix.accounts[3].pubkey = address;
+
+    let err = try_process_ix(&mut context, ix, &[&challenger_pair])
+        .await
+        .expect_err("should not redeem with a challenger that is not a PDA");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectPda)
+    );
+}
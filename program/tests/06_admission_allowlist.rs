@@ -0,0 +1,298 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, HasSize},
+    utils::{hash_solutions, verify_admission_proof, AdmissionTree},
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{add_pda_account, airdrop_rent};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 11;
+
+fn allowlisted_challenge(
+    creator: Pubkey,
+    admission_root: Option<[u8; 32]>,
+) -> Challenge {
+    Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        admission_root,
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..Default::default()
+    }
+}
+
+// -----------------
+// Admission Tree
+// -----------------
+#[test]
+fn admission_tree_proofs_verify_for_included_challengers_only() {
+    let challengers = (0..7).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+    let tree = AdmissionTree::new(&challengers);
+    let root = tree.root().expect("tree should have a root");
+
+    for challenger in &challengers {
+        let proof = tree.proof(challenger).expect("challenger has proof");
+        assert!(verify_admission_proof(&root, challenger, &proof));
+    }
+
+    let outsider = Pubkey::new_unique();
+    assert!(tree.proof(&outsider).is_none());
+
+    let proof = tree.proof(&challengers[0]).unwrap();
+    assert!(!verify_admission_proof(&root, &outsider, &proof));
+}
+
+#[test]
+fn admission_tree_single_challenger_has_empty_proof() {
+    let challenger = Pubkey::new_unique();
+    let tree = AdmissionTree::new(&[challenger]);
+
+    let proof = tree.proof(&challenger).unwrap();
+    assert!(proof.is_empty());
+    assert!(verify_admission_proof(
+        &tree.root().unwrap(),
+        &challenger,
+        &proof
+    ));
+}
+
+#[test]
+fn admission_tree_without_challengers_has_no_root() {
+    assert!(AdmissionTree::new(&[]).root().is_none());
+}
+
+// -----------------
+// Set Admission Root
+// -----------------
+#[tokio::test]
+async fn set_admission_root_and_remove_it_again() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = allowlisted_challenge(creator, None);
+    let (challenge_pda, _) = challenge.pda();
+    add_pda_account(&mut context, &challenge);
+
+    let tree =
+        AdmissionTree::new(&[Pubkey::new_unique(), Pubkey::new_unique()]);
+    let root = tree.root();
+
+    // Set root
+    {
+        let ix = ixs::set_admission_root(
+            context.payer.pubkey(),
+            creator,
+            ID.to_string(),
+            root,
+        )
+        .expect("failed to create instruction");
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to set admission root");
+
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_matches!(
+            value,
+            Challenge { admission_root, .. } => {
+                assert_eq!(admission_root, root);
+            }
+        );
+        assert_eq!(acc.data.len(), challenge.size() + 32);
+    }
+
+    // Remove root
+    {
+        let ix = ixs::set_admission_root(
+            context.payer.pubkey(),
+            creator,
+            ID.to_string(),
+            None,
+        )
+        .expect("failed to create instruction");
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to remove admission root");
+
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_matches!(
+            value,
+            Challenge {
+                admission_root: None,
+                required_mint: None,
+                required_amount: 0,
+                ..
+            }
+        );
+        assert_eq!(acc.data.len(), challenge.size());
+    }
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+#[tokio::test]
+async fn admit_allowlisted_challenger_with_valid_proof() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let payer = context.payer.pubkey();
+    let challenger = Pubkey::new_unique();
+
+    let tree = AdmissionTree::new(&[
+        Pubkey::new_unique(),
+        challenger,
+        Pubkey::new_unique(),
+    ]);
+    let challenge = allowlisted_challenge(creator, tree.root());
+    add_pda_account(&mut context, &challenge);
+
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_allowlisted_challenger(
+        payer,
+        &challenge,
+        challenger,
+        tree.proof(&challenger).unwrap(),
+        None,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger");
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(value.authority, challenger);
+    assert_eq!(value.tries_remaining, TRIES_PER_ADMIT);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn admit_challenger_not_in_allowlist() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let payer = context.payer.pubkey();
+    let allowed = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+
+    let tree = AdmissionTree::new(&[allowed, Pubkey::new_unique()]);
+    let challenge = allowlisted_challenge(creator, tree.root());
+    add_pda_account(&mut context, &challenge);
+
+    // Trying to get in with the proof of another challenger
+    let AdmitChallengerIx { ix, .. } = ixs::admit_allowlisted_challenger(
+        payer,
+        &challenge,
+        challenger,
+        tree.proof(&allowed).unwrap(),
+        None,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger");
+}
+
+#[tokio::test]
+#[should_panic]
+async fn admit_challenger_without_proof_to_allowlisted_challenge() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let payer = context.payer.pubkey();
+    let challenger = Pubkey::new_unique();
+
+    let tree = AdmissionTree::new(&[challenger, Pubkey::new_unique()]);
+    let challenge = allowlisted_challenge(creator, tree.root());
+    add_pda_account(&mut context, &challenge);
+
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(payer, &challenge, challenger, None)
+            .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger");
+}
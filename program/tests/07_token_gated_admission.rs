@@ -0,0 +1,240 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, HasSize, Redeem},
+    utils::hash_solutions,
+};
+
+use solana_program::{program_option::COption, pubkey::Pubkey};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_mint_account, add_mint_to_redeem, add_pda_account, add_token_account,
+    airdrop_rent,
+};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_token::state::Mint;
+
+use crate::utils::{get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 11;
+
+fn token_gated_challenge(
+    creator: Pubkey,
+    id: &str,
+    required_mint: Option<Pubkey>,
+    required_amount: u64,
+) -> Challenge {
+    Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, id).1,
+        id: id.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        required_mint,
+        required_amount,
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..Default::default()
+    }
+}
+
+fn add_mint(context: &mut ProgramTestContext) -> Pubkey {
+    let mint = Pubkey::new_unique();
+    add_mint_account(
+        context,
+        &mint,
+        &Mint {
+            mint_authority: COption::Some(Pubkey::new_unique()),
+            supply: 10,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        },
+    );
+    mint
+}
+
+async fn admit(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    challenger: Pubkey,
+) -> Pubkey {
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        challenge,
+        challenger,
+        Some(spl_token::id()),
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger");
+
+    challenger_pda
+}
+
+// -----------------
+// Set Required Token
+// -----------------
+#[tokio::test]
+async fn set_required_token_and_remove_it_again() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let challenge = token_gated_challenge(creator, ID, None, 0);
+    let (challenge_pda, _) = challenge.pda();
+    add_pda_account(&mut context, &challenge);
+
+    let required_mint = Pubkey::new_unique();
+    for (mint, amount) in [(Some(required_mint), 2), (None, 0)] {
+        let ix = ixs::set_required_token(
+            context.payer.pubkey(),
+            creator,
+            ID.to_string(),
+            mint,
+            amount,
+        )
+        .expect("failed to create instruction");
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to set required token");
+
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_matches!(
+            value,
+            Challenge { required_mint, required_amount, .. } => {
+                assert_eq!(required_mint, mint);
+                assert_eq!(required_amount, amount);
+            }
+        );
+        let extra = if mint.is_some() { 32 } else { 0 };
+        assert_eq!(acc.data.len(), challenge.size() + extra);
+    }
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+#[tokio::test]
+async fn admit_challenger_holding_required_token() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let challenger = Pubkey::new_unique();
+    let mint = add_mint(&mut context);
+    add_token_account(&mut context, &challenger, &mint, 3);
+
+    let challenge = token_gated_challenge(creator, ID, Some(mint), 2);
+    add_pda_account(&mut context, &challenge);
+
+    let challenger_pda = admit(&mut context, &challenge, challenger).await;
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(value.authority, challenger);
+}
+
+#[tokio::test]
+async fn admit_challenger_that_solved_prerequisite_challenge() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let challenger = Pubkey::new_unique();
+
+    // Level 1 was solved by the challenger and thus holds its redeem token
+    let level_1 = token_gated_challenge(creator, "level-1", None, 0);
+    let redeem = Redeem::new(level_1.pda().0);
+    add_pda_account(&mut context, &level_1);
+    add_mint_to_redeem(&mut context, &redeem);
+    add_token_account(&mut context, &challenger, &redeem.pda, 1);
+
+    // Level 2 requires the redeem token of level 1
+    let level_2 =
+        token_gated_challenge(creator, "level-2", Some(redeem.pda), 1);
+    add_pda_account(&mut context, &level_2);
+
+    let challenger_pda = admit(&mut context, &level_2, challenger).await;
+
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(value.challenge_pda, level_2.pda().0);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn admit_challenger_holding_too_little_of_required_token() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let challenger = Pubkey::new_unique();
+    let mint = add_mint(&mut context);
+    add_token_account(&mut context, &challenger, &mint, 1);
+
+    let challenge = token_gated_challenge(creator, ID, Some(mint), 2);
+    add_pda_account(&mut context, &challenge);
+
+    admit(&mut context, &challenge, challenger).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn admit_challenger_holding_other_token() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+
+    let challenger = Pubkey::new_unique();
+    let mint = add_mint(&mut context);
+    let other_mint = add_mint(&mut context);
+    add_token_account(&mut context, &challenger, &other_mint, 5);
+
+    // Builder derives the token account for `mint` which was never created
+    let challenge = token_gated_challenge(creator, ID, Some(mint), 1);
+    add_pda_account(&mut context, &challenge);
+
+    admit(&mut context, &challenge, challenger).await;
+}
@@ -0,0 +1,229 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    challenge_id,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, FeeRecipient},
+    utils::hash_solutions,
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{add_pda_account, airdrop_rent};
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::utils::{get_account, get_deserialized, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_001;
+const TRIES_PER_ADMIT: u8 = 11;
+
+fn challenge_with_fee_recipients(
+    creator: Pubkey,
+    fee_recipients: Vec<FeeRecipient>,
+) -> Challenge {
+    Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        fee_recipients,
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..Default::default()
+    }
+}
+
+async fn create_challenge(
+    context: &mut ProgramTestContext,
+    fee_recipients: Vec<FeeRecipient>,
+) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_fee_recipients(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+        fee_recipients,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed create challenge");
+}
+
+async fn admit(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    challenger: Pubkey,
+) {
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        challenge,
+        challenger,
+        None,
+    )
+    .expect("failed to create instruction");
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect("Failed to admit challenger");
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+#[tokio::test]
+async fn create_challenge_with_fee_recipients() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let fee_recipients = vec![
+        FeeRecipient {
+            recipient: creator,
+            basis_points: 5_000,
+        },
+        FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            basis_points: 5_000,
+        },
+    ];
+    create_challenge(&mut context, fee_recipients.clone()).await;
+
+    let (challenge_pda, _) =
+        Challenge::shank_pda(&challenge_id(), &creator, ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+
+    assert_matches!(
+        value,
+        Challenge { fee_recipients: stored, .. } => {
+            assert_eq!(stored, fee_recipients);
+        }
+    );
+}
+
+// -----------------
+// Admit Challenger
+// -----------------
+#[tokio::test]
+async fn admit_challenger_splits_admit_cost_with_dust_going_to_creator() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let partner = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let creator_lamports = airdrop_rent(&mut context, &creator, 0).await;
+    let partner_lamports = airdrop_rent(&mut context, &partner, 0).await;
+    let treasury_lamports = airdrop_rent(&mut context, &treasury, 0).await;
+
+    let challenge = challenge_with_fee_recipients(
+        creator,
+        vec![
+            FeeRecipient {
+                recipient: creator,
+                basis_points: 3_333,
+            },
+            FeeRecipient {
+                recipient: partner,
+                basis_points: 3_333,
+            },
+            FeeRecipient {
+                recipient: treasury,
+                basis_points: 3_334,
+            },
+        ],
+    );
+    add_pda_account(&mut context, &challenge);
+
+    admit(&mut context, &challenge, Pubkey::new_unique()).await;
+
+    // 1_001 * 3_333 / 10_000 = 333 and 1_001 * 3_334 / 10_000 = 333
+    // which leaves 2 lamports of dust for the creator
+    let creator_acc = get_account(&mut context, &creator).await;
+    let partner_acc = get_account(&mut context, &partner).await;
+    let treasury_acc = get_account(&mut context, &treasury).await;
+    assert_eq!(creator_acc.lamports, creator_lamports + 333 + 2);
+    assert_eq!(partner_acc.lamports, partner_lamports + 333);
+    assert_eq!(treasury_acc.lamports, treasury_lamports + 333);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn create_challenge_with_fee_recipients_not_adding_up() {
+    let mut context = program_test().start_with_context().await;
+
+    let fee_recipients = vec![
+        FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            basis_points: 5_000,
+        },
+        FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            basis_points: 4_999,
+        },
+    ];
+    create_challenge(&mut context, fee_recipients).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn admit_challenger_providing_incorrect_fee_recipient() {
+    let mut context = program_test().start_with_context().await;
+
+    let creator = Pubkey::new_unique();
+    let partner = Pubkey::new_unique();
+    let impostor = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    airdrop_rent(&mut context, &partner, 0).await;
+    airdrop_rent(&mut context, &impostor, 0).await;
+
+    let fee_recipients = |recipient| {
+        vec![FeeRecipient {
+            recipient,
+            basis_points: 10_000,
+        }]
+    };
+    add_pda_account(
+        &mut context,
+        &challenge_with_fee_recipients(creator, fee_recipients(partner)),
+    );
+
+    // Builder adds the impostor since it doesn't know about the stored recipients
+    let challenge =
+        challenge_with_fee_recipients(creator, fee_recipients(impostor));
+    admit(&mut context, &challenge, Pubkey::new_unique()).await;
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    challenge_id,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, FeeRecipient, HasPda, HasSize, Pot},
+};
+
+use solana_program::{pubkey::Pubkey, rent::Rent};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{add_challenge_with_solutions, airdrop_rent};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{get_account, get_deserialized, process_ix, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const TRIES_PER_ADMIT: u8 = 11;
+
+async fn set_jackpot(context: &mut ProgramTestContext, jackpot: Option<u16>) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::set_jackpot(creator, creator, ID.to_string(), jackpot)
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+}
+
+// -----------------
+// Set Jackpot
+// -----------------
+#[tokio::test]
+async fn set_jackpot_creates_pot() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+    set_jackpot(&mut context, Some(5_000)).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            jackpot: Some(5_000),
+            ..
+        }
+    );
+    assert_eq!(acc.data.len(), value.size());
+
+    let (pot_pda, _) = Pot::new(challenge_pda).pda();
+    let pot_acc = get_account(&mut context, &pot_pda).await;
+    assert_eq!(pot_acc.owner, challenge_id());
+    assert_eq!(pot_acc.lamports, Rent::default().minimum_balance(0));
+    assert!(pot_acc.data.is_empty());
+}
+
+// -----------------
+// Admit and Redeem
+// -----------------
+#[tokio::test]
+async fn jackpot_pays_share_of_pot_to_solvers_and_remainder_when_finished() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    // 1. create challenge in jackpot mode paying half the pot per solution
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    set_jackpot(&mut context, Some(5_000)).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (pot_pda, _) = Pot::new(challenge_pda).pda();
+    let pot_rent = Rent::default().minimum_balance(0);
+
+    // 2. admit two challengers whose admit costs go into the pot
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let first = Keypair::new();
+    let second = Keypair::new();
+    for challenger in [&first, &second] {
+        airdrop_rent(&mut context, &challenger.pubkey(), 0).await;
+        let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+            creator,
+            &challenge,
+            challenger.pubkey(),
+            None,
+        )
+        .expect("failed to create instruction");
+        process_ix(&mut context, ix, &[]).await;
+    }
+
+    let pot_acc = get_account(&mut context, &pot_pda).await;
+    assert_eq!(pot_acc.lamports, pot_rent + 2 * ADMIT_COST);
+
+    // 3. first solver receives half of the pot
+    let first_lamports =
+        get_account(&mut context, &first.pubkey()).await.lamports;
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        first.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&first]).await;
+
+    let first_acc = get_account(&mut context, &first.pubkey()).await;
+    let pot_acc = get_account(&mut context, &pot_pda).await;
+    assert_eq!(first_acc.lamports, first_lamports + ADMIT_COST);
+    assert_eq!(pot_acc.lamports, pot_rent + ADMIT_COST);
+
+    // 4. second solver finishes the challenge and receives the remaining pot
+    let second_lamports =
+        get_account(&mut context, &second.pubkey()).await.lamports;
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        second.pubkey(),
+        "world",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&second]).await;
+
+    let second_acc = get_account(&mut context, &second.pubkey()).await;
+    let pot_acc = get_account(&mut context, &pot_pda).await;
+    assert_eq!(second_acc.lamports, second_lamports + ADMIT_COST);
+    assert_eq!(pot_acc.lamports, pot_rent);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn set_jackpot_for_started_challenge() {
+    let mut context = program_test().start_with_context().await;
+
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+    let creator = context.payer.pubkey();
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    set_jackpot(&mut context, Some(5_000)).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn set_jackpot_with_invalid_share() {
+    let mut context = program_test().start_with_context().await;
+
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+    set_jackpot(&mut context, Some(10_001)).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn set_jackpot_for_challenge_with_fee_recipients() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_challenge_with_fee_recipients(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+        vec![FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            basis_points: 10_000,
+        }],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    set_jackpot(&mut context, Some(5_000)).await;
+}
@@ -0,0 +1,288 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Pot},
+};
+
+use solana_program::rent::Rent;
+use solana_program_test::*;
+
+use crate::utils::airdrop_rent;
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{get_account, get_deserialized, process_ix, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const TRIES_PER_ADMIT: u8 = 4;
+
+/// Creates and starts a challenge escrowing admit fees and admits one challenger.
+async fn escrowed_challenge_with_admitted_challenger(
+    context: &mut ProgramTestContext,
+) -> (Keypair, Keypair) {
+    let payer = context.payer.pubkey();
+    let creator = Keypair::new();
+    airdrop_rent(context, &creator.pubkey(), 0).await;
+
+    let ix = ixs::create_challenge(
+        payer,
+        creator.pubkey(),
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let ix = ixs::set_escrow(payer, creator.pubkey(), ID.to_string(), true)
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[&creator]).await;
+
+    let ix = ixs::start_challenge(creator.pubkey(), ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[&creator]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator.pubkey(), ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+
+    let challenger = Keypair::new();
+    airdrop_rent(context, &challenger.pubkey(), 0).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(payer, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    (creator, challenger)
+}
+
+async fn redeem(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    challenger: &Keypair,
+    solution: &str,
+) {
+    let (challenge_pda, _) = Challenge::pda_for(&creator.pubkey(), ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    let ix = ixs::redeem_challenge(
+        context.payer.pubkey(),
+        &challenge,
+        challenger.pubkey(),
+        solution,
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[challenger]).await;
+}
+
+async fn cancel(context: &mut ProgramTestContext, creator: &Keypair) {
+    let ix = ixs::cancel_challenge(creator.pubkey(), ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[creator]).await;
+}
+
+async fn claim_refund(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+    challenger: &Keypair,
+) {
+    let ix = ixs::claim_refund(creator.pubkey(), ID, challenger.pubkey())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[challenger]).await;
+}
+
+async fn withdraw(context: &mut ProgramTestContext, creator: &Keypair) {
+    let ix = ixs::withdraw_escrow(creator.pubkey(), ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[creator]).await;
+}
+
+async fn pot_lamports(
+    context: &mut ProgramTestContext,
+    creator: &Keypair,
+) -> u64 {
+    let (challenge_pda, _) = Challenge::pda_for(&creator.pubkey(), ID);
+    let (pot_pda, _) = Pot::new(challenge_pda).pda();
+    get_account(context, &pot_pda).await.lamports
+}
+
+// -----------------
+// Withdraw Escrow
+// -----------------
+#[tokio::test]
+async fn escrow_admit_fee_and_withdraw_once_finished() {
+    let mut context = program_test().start_with_context().await;
+    let pot_rent = Rent::default().minimum_balance(0);
+
+    let (creator, challenger) =
+        escrowed_challenge_with_admitted_challenger(&mut context).await;
+
+    let creator_lamports =
+        get_account(&mut context, &creator.pubkey()).await.lamports;
+    assert_eq!(
+        pot_lamports(&mut context, &creator).await,
+        pot_rent + ADMIT_COST
+    );
+
+    redeem(&mut context, &creator, &challenger, "hello").await;
+    redeem(&mut context, &creator, &challenger, "world").await;
+    withdraw(&mut context, &creator).await;
+
+    let creator_acc = get_account(&mut context, &creator.pubkey()).await;
+    assert_eq!(creator_acc.lamports, creator_lamports + ADMIT_COST);
+    assert_eq!(pot_lamports(&mut context, &creator).await, pot_rent);
+}
+
+// -----------------
+// Cancel and Refund
+// -----------------
+#[tokio::test]
+async fn cancel_challenge_and_refund_unused_tries() {
+    let mut context = program_test().start_with_context().await;
+    let pot_rent = Rent::default().minimum_balance(0);
+
+    let (creator, challenger) =
+        escrowed_challenge_with_admitted_challenger(&mut context).await;
+
+    // use up one of four tries
+    redeem(&mut context, &creator, &challenger, "not-hello").await;
+    cancel(&mut context, &creator).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator.pubkey(), ID);
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            finished: true,
+            cancelled: true,
+            escrowed_tries: 3,
+            ..
+        }
+    );
+
+    let challenger_lamports = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    claim_refund(&mut context, &creator, &challenger).await;
+
+    let challenger_acc = get_account(&mut context, &challenger.pubkey()).await;
+    assert_eq!(
+        challenger_acc.lamports,
+        challenger_lamports + ADMIT_COST * 3 / 4
+    );
+    assert_eq!(
+        pot_lamports(&mut context, &creator).await,
+        pot_rent + ADMIT_COST / 4
+    );
+
+    let challenger_pda = Challenger::new(challenger.pubkey(), challenge_pda, 0)
+        .pda()
+        .0;
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(value.tries_remaining, 0);
+
+    // the fee of the used try is left over for the creator
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(value.escrowed_tries, 0);
+
+    let creator_lamports =
+        get_account(&mut context, &creator.pubkey()).await.lamports;
+    withdraw(&mut context, &creator).await;
+
+    let creator_acc = get_account(&mut context, &creator.pubkey()).await;
+    assert_eq!(creator_acc.lamports, creator_lamports + ADMIT_COST / 4);
+    assert_eq!(pot_lamports(&mut context, &creator).await, pot_rent);
+}
+
+#[tokio::test]
+async fn cancel_challenge_and_withdraw_before_refunds_are_claimed() {
+    let mut context = program_test().start_with_context().await;
+    let pot_rent = Rent::default().minimum_balance(0);
+
+    let (creator, challenger) =
+        escrowed_challenge_with_admitted_challenger(&mut context).await;
+
+    // use up two of four tries
+    redeem(&mut context, &creator, &challenger, "not-hello").await;
+    redeem(&mut context, &creator, &challenger, "hello").await;
+    cancel(&mut context, &creator).await;
+
+    // the refund owed for the unused tries stays in the pot
+    let creator_lamports =
+        get_account(&mut context, &creator.pubkey()).await.lamports;
+    withdraw(&mut context, &creator).await;
+
+    let creator_acc = get_account(&mut context, &creator.pubkey()).await;
+    assert_eq!(creator_acc.lamports, creator_lamports + ADMIT_COST / 2);
+    assert_eq!(
+        pot_lamports(&mut context, &creator).await,
+        pot_rent + ADMIT_COST / 2
+    );
+
+    let challenger_lamports = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    claim_refund(&mut context, &creator, &challenger).await;
+
+    let challenger_acc = get_account(&mut context, &challenger.pubkey()).await;
+    assert_eq!(
+        challenger_acc.lamports,
+        challenger_lamports + ADMIT_COST / 2
+    );
+    assert_eq!(pot_lamports(&mut context, &creator).await, pot_rent);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn claim_refund_twice() {
+    let mut context = program_test().start_with_context().await;
+
+    let (creator, challenger) =
+        escrowed_challenge_with_admitted_challenger(&mut context).await;
+    cancel(&mut context, &creator).await;
+
+    claim_refund(&mut context, &creator, &challenger).await;
+    context.last_blockhash = context
+        .banks_client
+        .get_new_latest_blockhash(&context.last_blockhash)
+        .await
+        .unwrap();
+    claim_refund(&mut context, &creator, &challenger).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn claim_refund_for_challenge_that_was_not_cancelled() {
+    let mut context = program_test().start_with_context().await;
+
+    let (creator, challenger) =
+        escrowed_challenge_with_admitted_challenger(&mut context).await;
+
+    claim_refund(&mut context, &creator, &challenger).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn withdraw_escrow_before_challenge_finished() {
+    let mut context = program_test().start_with_context().await;
+
+    let (creator, _) =
+        escrowed_challenge_with_admitted_challenger(&mut context).await;
+
+    withdraw(&mut context, &creator).await;
+}
@@ -0,0 +1,441 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, FeeRecipient, ProgramConfig},
+    utils::hash_solutions,
+};
+
+use solana_program::{pubkey::Pubkey, rent::Rent};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_pda_account, add_program_data_account,
+    airdrop_rent,
+};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{
+    get_account, get_challenge, get_deserialized, process_ix, program_test,
+    try_process_ix,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const TRIES_PER_ADMIT: u8 = 11;
+
+async fn init_config(
+    context: &mut ProgramTestContext,
+    protocol_fee_basis_points: u16,
+    treasury: Pubkey,
+) -> Keypair {
+    let admin = Keypair::new();
+    add_program_data_account(context, &admin.pubkey());
+    let ix = ixs::init_config(
+        context.payer.pubkey(),
+        admin.pubkey(),
+        protocol_fee_basis_points,
+        treasury,
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[&admin]).await;
+    admin
+}
+
+async fn get_config(context: &mut ProgramTestContext) -> ProgramConfig {
+    let (config_pda, _) = ProgramConfig::pda();
+    let (_, config) =
+        get_deserialized::<ProgramConfig>(context, &config_pda).await;
+    config
+}
+
+async fn set_paused(
+    context: &mut ProgramTestContext,
+    admin: &Keypair,
+    paused: bool,
+) {
+    let config = ProgramConfig {
+        paused,
+        ..get_config(context).await
+    };
+    let ix = ixs::update_config(admin.pubkey(), &config)
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[admin]).await;
+}
+
+async fn start_challenge(context: &mut ProgramTestContext) {
+    add_challenge_with_solutions(context, ID, vec!["hello"], None);
+    let ix = ixs::start_challenge(context.payer.pubkey(), ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+}
+
+fn started_challenge(creator: Pubkey) -> Challenge {
+    Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..Default::default()
+    }
+}
+
+// -----------------
+// Init and Update Config
+// -----------------
+#[tokio::test]
+async fn init_config_and_update_it() {
+    let mut context = program_test().start_with_context().await;
+    let treasury = Pubkey::new_unique();
+
+    let admin = init_config(&mut context, 100, treasury).await;
+    assert_matches!(
+        get_config(&mut context).await,
+        ProgramConfig {
+            admin: a,
+            protocol_fee_basis_points: 100,
+            treasury: t,
+            paused: false,
+            bump,
+        } => {
+            assert_eq!(a, admin.pubkey());
+            assert_eq!(t, treasury);
+            assert_eq!(bump, ProgramConfig::pda().1);
+        }
+    );
+
+    let new_admin = Pubkey::new_unique();
+    let ix = ixs::update_config(
+        admin.pubkey(),
+        &ProgramConfig {
+            admin: new_admin,
+            protocol_fee_basis_points: 200,
+            treasury,
+            paused: true,
+            ..Default::default()
+        },
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&admin]).await;
+
+    assert_matches!(
+        get_config(&mut context).await,
+        ProgramConfig {
+            admin: a,
+            protocol_fee_basis_points: 200,
+            paused: true,
+            bump,
+            ..
+        } => {
+            assert_eq!(a, new_admin);
+            assert_eq!(bump, ProgramConfig::pda().1);
+        }
+    );
+}
+
+#[tokio::test]
+async fn unpause_program_to_process_instructions_again() {
+    let mut context = program_test().start_with_context().await;
+
+    let admin = init_config(&mut context, 0, Pubkey::new_unique()).await;
+    set_paused(&mut context, &admin, true).await;
+    set_paused(&mut context, &admin, false).await;
+
+    start_challenge(&mut context).await;
+}
+
+// -----------------
+// Protocol Fee
+// -----------------
+#[tokio::test]
+async fn admit_challenger_charges_protocol_fee() {
+    let mut context = program_test().start_with_context().await;
+
+    let treasury = Pubkey::new_unique();
+    let treasury_lamports = airdrop_rent(&mut context, &treasury, 0).await;
+    init_config(&mut context, 1_000, treasury).await;
+    let config = get_config(&mut context).await;
+
+    let creator = Pubkey::new_unique();
+    let creator_lamports = airdrop_rent(&mut context, &creator, 0).await;
+    let challenge = started_challenge(creator);
+    add_pda_account(&mut context, &challenge);
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        &challenge,
+        Pubkey::new_unique(),
+        None,
+    )
+    .expect("failed to create instruction")
+    .with_protocol_fee(&config);
+    process_ix(&mut context, ix, &[]).await;
+
+    // the protocol fee is taken from the admit cost
+    let creator_acc = get_account(&mut context, &creator).await;
+    let treasury_acc = get_account(&mut context, &treasury).await;
+    assert_eq!(
+        creator_acc.lamports,
+        creator_lamports + ADMIT_COST - ADMIT_COST / 10
+    );
+    assert_eq!(treasury_acc.lamports, treasury_lamports + ADMIT_COST / 10);
+}
+
+#[tokio::test]
+async fn admit_challenger_splits_admit_cost_without_protocol_fee() {
+    let mut context = program_test().start_with_context().await;
+
+    let treasury = Pubkey::new_unique();
+    let treasury_lamports = airdrop_rent(&mut context, &treasury, 0).await;
+    init_config(&mut context, 1_000, treasury).await;
+    let config = get_config(&mut context).await;
+
+    let creator = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let creator_lamports = airdrop_rent(&mut context, &creator, 0).await;
+    let recipient_lamports = airdrop_rent(&mut context, &recipient, 0).await;
+    let challenge = Challenge {
+        fee_recipients: vec![FeeRecipient {
+            recipient,
+            basis_points: 5_000,
+        }],
+        ..started_challenge(creator)
+    };
+    add_pda_account(&mut context, &challenge);
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        &challenge,
+        Pubkey::new_unique(),
+        None,
+    )
+    .expect("failed to create instruction")
+    .with_protocol_fee(&config);
+    process_ix(&mut context, ix, &[]).await;
+
+    let admit_fees = ADMIT_COST - ADMIT_COST / 10;
+    let creator_acc = get_account(&mut context, &creator).await;
+    let recipient_acc = get_account(&mut context, &recipient).await;
+    let treasury_acc = get_account(&mut context, &treasury).await;
+    assert_eq!(creator_acc.lamports, creator_lamports + admit_fees / 2);
+    assert_eq!(recipient_acc.lamports, recipient_lamports + admit_fees / 2);
+    assert_eq!(treasury_acc.lamports, treasury_lamports + ADMIT_COST / 10);
+}
+
+#[tokio::test]
+async fn refund_escrowed_admit_fees_without_protocol_fee() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let pot_rent = Rent::default().minimum_balance(0);
+
+    let treasury = Pubkey::new_unique();
+    let treasury_lamports = airdrop_rent(&mut context, &treasury, 0).await;
+    init_config(&mut context, 1_000, treasury).await;
+    let config = get_config(&mut context).await;
+
+    // 1. create, escrow and start the challenge, then admit a challenger
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+    let ix = ixs::set_escrow(creator, creator, ID.to_string(), true)
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let challenge = get_challenge(&mut context, ID).await;
+    let challenger = Keypair::new();
+    airdrop_rent(&mut context, &challenger.pubkey(), 0).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction")
+            .with_protocol_fee(&config);
+    process_ix(&mut context, ix, &[]).await;
+
+    // only the admit fees without the protocol fee are escrowed
+    let admit_fees = ADMIT_COST - ADMIT_COST / 10;
+    let (pot_pda, _) = challenge.pot_pda();
+    let pot_acc = get_account(&mut context, &pot_pda).await;
+    let treasury_acc = get_account(&mut context, &treasury).await;
+    assert_eq!(pot_acc.lamports, pot_rent + admit_fees);
+    assert_eq!(treasury_acc.lamports, treasury_lamports + ADMIT_COST / 10);
+
+    // 2. use up one try, cancel and refund the unused tries from the escrowed fees
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "not-hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+    let ix = ixs::cancel_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let challenger_lamports = get_account(&mut context, &challenger.pubkey())
+        .await
+        .lamports;
+    let ix = ixs::claim_refund(creator, ID, challenger.pubkey())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+
+    let refund =
+        admit_fees * (TRIES_PER_ADMIT as u64 - 1) / TRIES_PER_ADMIT as u64;
+    let challenger_acc = get_account(&mut context, &challenger.pubkey()).await;
+    let pot_acc = get_account(&mut context, &pot_pda).await;
+    assert_eq!(challenger_acc.lamports, challenger_lamports + refund);
+    assert_eq!(pot_acc.lamports, pot_rent + admit_fees - refund);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+#[should_panic]
+async fn start_challenge_while_program_is_paused() {
+    let mut context = program_test().start_with_context().await;
+
+    let admin = init_config(&mut context, 0, Pubkey::new_unique()).await;
+    set_paused(&mut context, &admin, true).await;
+
+    start_challenge(&mut context).await;
+}
+
+#[tokio::test]
+#[should_panic]
+async fn update_config_by_someone_other_than_admin() {
+    let mut context = program_test().start_with_context().await;
+
+    init_config(&mut context, 0, Pubkey::new_unique()).await;
+    let impostor = Keypair::new();
+    set_paused(&mut context, &impostor, true).await;
+}
+
+#[tokio::test]
+async fn init_config_by_someone_other_than_upgrade_authority() {
+    let mut context = program_test().start_with_context().await;
+
+    let upgrade_authority = Pubkey::new_unique();
+    add_program_data_account(&mut context, &upgrade_authority);
+
+    let impostor = Keypair::new();
+    let ix = ixs::init_config(
+        context.payer.pubkey(),
+        impostor.pubkey(),
+        0,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[&impostor])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::NotUpgradeAuthority)
+    );
+}
+
+#[tokio::test]
+async fn init_config_with_program_data_of_another_program() {
+    let mut context = program_test().start_with_context().await;
+
+    let admin = Keypair::new();
+    add_program_data_account(&mut context, &admin.pubkey());
+
+    let mut ix = ixs::init_config(
+        context.payer.pubkey(),
+        admin.pubkey(),
+        0,
+        Pubkey::new_unique(),
+    )
+    .expect("failed to create instruction");
+    ix.accounts[2].pubkey = Pubkey::new_unique();
+    let err = try_process_ix(&mut context, ix, &[&admin])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectProgramData)
+    );
+}
+
+#[tokio::test]
+async fn start_challenge_with_config_whose_bump_is_not_the_pda_bump() {
+    let mut context = program_test().start_with_context().await;
+
+    let (_, bump) = ProgramConfig::pda();
+    add_pda_account(
+        &mut context,
+        &ProgramConfig {
+            bump: bump.wrapping_sub(1),
+            ..Default::default()
+        },
+    );
+
+    add_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+    let ix = ixs::start_challenge(context.payer.pubkey(), ID.to_string())
+        .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectPda)
+    );
+}
+
+#[tokio::test]
+#[should_panic]
+async fn init_config_with_protocol_fee_above_max() {
+    let mut context = program_test().start_with_context().await;
+
+    init_config(&mut context, 10_001, Pubkey::new_unique()).await;
+}
+
+#[tokio::test]
+async fn admit_challenger_without_treasury_when_charging_protocol_fee() {
+    let mut context = program_test().start_with_context().await;
+
+    init_config(&mut context, 1_000, Pubkey::new_unique()).await;
+
+    let creator = Pubkey::new_unique();
+    airdrop_rent(&mut context, &creator, 0).await;
+    let challenge = started_challenge(creator);
+    add_pda_account(&mut context, &challenge);
+
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        &challenge,
+        Pubkey::new_unique(),
+        None,
+    )
+    .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectTreasury)
+    );
+}
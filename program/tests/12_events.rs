@@ -0,0 +1,407 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    events::{decode_events, ChallengeEvent, EVENT_DISCRIMINATOR},
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, ProgramConfig},
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{
+    add_program_data_account, airdrop_rent, get_deserialized, process_ix,
+    process_ix_logging_events, program_test,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 11;
+
+fn program_data_log(event: &ChallengeEvent) -> String {
+    format!(
+        "Program data: {} {}",
+        base64::encode(EVENT_DISCRIMINATOR),
+        base64::encode(event.try_to_vec().unwrap())
+    )
+}
+
+// -----------------
+// Decoder
+// -----------------
+#[test]
+fn decode_events_only_for_this_program() {
+    let started = ChallengeEvent::Started {
+        challenge_pda: Pubkey::new_unique(),
+    };
+    let finished = ChallengeEvent::Finished {
+        challenge_pda: Pubkey::new_unique(),
+    };
+    let program_id = challenge_id();
+    let other_program_id = Pubkey::new_unique();
+
+    let log_messages = vec![
+        format!("Program {} invoke [1]", program_id),
+        "Program log: IX: redeem".to_string(),
+        program_data_log(&started),
+        format!("Program {} invoke [2]", other_program_id),
+        program_data_log(&finished),
+        format!("Program {} success", other_program_id),
+        "Program data: aGVsbG8=".to_string(),
+        program_data_log(&finished),
+        format!(
+            "Program {} consumed 1000 of 200000 compute units",
+            program_id
+        ),
+        format!("Program {} success", program_id),
+        program_data_log(&started),
+    ];
+
+    assert_eq!(decode_events(&log_messages), vec![started, finished]);
+}
+
+// -----------------
+// Emitted Events
+// -----------------
+#[tokio::test]
+async fn emit_events_over_challenge_lifecycle() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    // Create, add solutions and start
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::Created {
+            challenge_pda,
+            creator,
+            id: ID.to_string(),
+            admit_cost: ADMIT_COST,
+            tries_per_admit: TRIES_PER_ADMIT,
+            solutions: 1,
+        }]
+    );
+
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let ix = ixs::add_challenge_solutions(creator, &challenge, vec!["world"])
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::SolutionsAdded {
+            challenge_pda,
+            added: 1,
+            solutions: 2,
+        }]
+    );
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(events, vec![ChallengeEvent::Started { challenge_pda }]);
+
+    // Admit
+    let challenger = Keypair::new();
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::Admitted {
+            challenge_pda,
+            challenger: challenger.pubkey(),
+            admit_cost: ADMIT_COST,
+        }]
+    );
+
+    // Redeem
+    let redeem = |solution| {
+        ixs::redeem_challenge(
+            creator,
+            &challenge,
+            challenger.pubkey(),
+            solution,
+            vec![],
+        )
+        .expect("failed to create instruction")
+    };
+
+    let events = process_ix_logging_events(
+        &mut context,
+        redeem("hallo"),
+        &[&challenger],
+    )
+    .await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::AttemptFailed {
+            challenge_pda,
+            challenger: challenger.pubkey(),
+            tries_remaining: TRIES_PER_ADMIT - 1,
+        }]
+    );
+
+    let events = process_ix_logging_events(
+        &mut context,
+        redeem("hello"),
+        &[&challenger],
+    )
+    .await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::Solved {
+            challenge_pda,
+            challenger: challenger.pubkey(),
+            solution_index: 0,
+        }]
+    );
+
+    let events = process_ix_logging_events(
+        &mut context,
+        redeem("world"),
+        &[&challenger],
+    )
+    .await;
+    assert_eq!(
+        events,
+        vec![
+            ChallengeEvent::Solved {
+                challenge_pda,
+                challenger: challenger.pubkey(),
+                solution_index: 1,
+            },
+            ChallengeEvent::Finished { challenge_pda }
+        ]
+    );
+}
+
+#[tokio::test]
+async fn emit_events_when_configuring_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let ix = ixs::set_admission_root(
+        creator,
+        creator,
+        ID.to_string(),
+        Some([1; 32]),
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::AdmissionRootSet {
+            challenge_pda,
+            admission_root: Some([1; 32]),
+        }]
+    );
+
+    let required_mint = Pubkey::new_unique();
+    let ix = ixs::set_required_token(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(required_mint),
+        2,
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::RequiredTokenSet {
+            challenge_pda,
+            required_mint: Some(required_mint),
+            required_amount: 2,
+        }]
+    );
+
+    let hook_program = Pubkey::new_unique();
+    let ix = ixs::set_hook_program(
+        creator,
+        creator,
+        ID.to_string(),
+        Some(hook_program),
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::HookProgramSet {
+            challenge_pda,
+            hook_program: Some(hook_program),
+        }]
+    );
+
+    let ix = ixs::set_jackpot(creator, creator, ID.to_string(), Some(5_000))
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::JackpotSet {
+            challenge_pda,
+            jackpot: Some(5_000),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn emit_events_when_refunding_and_withdrawing_escrow() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let ix = ixs::set_escrow(creator, creator, ID.to_string(), true)
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::EscrowSet {
+            challenge_pda,
+            escrow: true,
+        }]
+    );
+
+    // Start, admit and use up one try before the challenge is cancelled
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let challenger = Keypair::new();
+    airdrop_rent(&mut context, &challenger.pubkey(), 0).await;
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hallo",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+
+    let ix = ixs::cancel_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    // Refund the unused tries and withdraw the rest
+    let refund =
+        ADMIT_COST * (TRIES_PER_ADMIT as u64 - 1) / TRIES_PER_ADMIT as u64;
+    let ix = ixs::claim_refund(creator, ID, challenger.pubkey())
+        .expect("failed to create instruction");
+    let events =
+        process_ix_logging_events(&mut context, ix, &[&challenger]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::RefundClaimed {
+            challenge_pda,
+            challenger: challenger.pubkey(),
+            refund,
+        }]
+    );
+
+    let ix = ixs::withdraw_escrow(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::EscrowWithdrawn {
+            challenge_pda,
+            withdrawal: ADMIT_COST - refund,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn emit_events_when_initializing_and_updating_config() {
+    let mut context = program_test().start_with_context().await;
+    let admin = Keypair::new();
+    let treasury = Pubkey::new_unique();
+    add_program_data_account(&mut context, &admin.pubkey());
+
+    let ix =
+        ixs::init_config(context.payer.pubkey(), admin.pubkey(), 100, treasury)
+            .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[&admin]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::ConfigInitialized {
+            admin: admin.pubkey(),
+            protocol_fee_basis_points: 100,
+            treasury,
+        }]
+    );
+
+    let new_admin = Pubkey::new_unique();
+    let ix = ixs::update_config(
+        admin.pubkey(),
+        &ProgramConfig {
+            admin: new_admin,
+            protocol_fee_basis_points: 200,
+            treasury,
+            paused: true,
+            ..Default::default()
+        },
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[&admin]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::ConfigUpdated {
+            admin: new_admin,
+            protocol_fee_basis_points: 200,
+            treasury,
+            paused: true,
+        }]
+    );
+}
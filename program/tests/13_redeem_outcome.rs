@@ -0,0 +1,149 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    ixs::{self, AdmitChallengerIx},
+    outcome::{RedeemOutcome, RedeemResult},
+    state::Challenge,
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{
+    get_challenge, get_deserialized, process_ix, program_test, tx_for,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 11;
+
+/// Simulates the redeem returning its outcome and then processes it.
+async fn redeem(
+    context: &mut ProgramTestContext,
+    challenger: &Keypair,
+    solution: &str,
+) -> RedeemOutcome {
+    let creator = context.payer.pubkey();
+    let challenge = get_challenge(context, ID).await;
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        solution,
+        vec![],
+    )
+    .expect("failed to create instruction");
+
+    let tx = tx_for(context, ix.clone(), &[challenger]);
+    let simulation = context
+        .banks_client
+        .simulate_transaction(tx)
+        .await
+        .expect("Failed to simulate transaction");
+    simulation.result.unwrap().expect("Transaction failed");
+
+    let return_data = simulation
+        .simulation_details
+        .expect("Simulation has no details")
+        .return_data
+        .expect("Redeem did not set return data");
+    let outcome = RedeemOutcome::from_return_data(
+        &return_data.program_id,
+        &return_data.data,
+    )
+    .expect("Return data is not a redeem outcome");
+
+    process_ix(context, ix, &[challenger]).await;
+    outcome
+}
+
+// -----------------
+// Decoder
+// -----------------
+#[test]
+fn decode_outcome_with_stripped_trailing_zeros() {
+    let outcome = RedeemOutcome {
+        result: RedeemResult::Incorrect,
+        solution_index: 1,
+        tries_remaining: 0,
+        finished: false,
+    };
+    let data = outcome.try_to_vec().unwrap();
+    assert_eq!(
+        RedeemOutcome::from_return_data(&challenge_id(), &data[..2]),
+        Some(outcome)
+    );
+    assert_eq!(
+        RedeemOutcome::from_return_data(&Pubkey::new_unique(), &data),
+        None
+    );
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_returns_outcome() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let challenger = Keypair::new();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    assert_eq!(
+        redeem(&mut context, &challenger, "hallo").await,
+        RedeemOutcome {
+            result: RedeemResult::Incorrect,
+            solution_index: 0,
+            tries_remaining: TRIES_PER_ADMIT - 1,
+            finished: false,
+        }
+    );
+    assert_eq!(
+        redeem(&mut context, &challenger, "hello").await,
+        RedeemOutcome {
+            result: RedeemResult::Correct,
+            solution_index: 0,
+            tries_remaining: TRIES_PER_ADMIT - 2,
+            finished: false,
+        }
+    );
+    assert_eq!(
+        redeem(&mut context, &challenger, "world").await,
+        RedeemOutcome {
+            result: RedeemResult::Correct,
+            solution_index: 1,
+            tries_remaining: TRIES_PER_ADMIT - 3,
+            finished: true,
+        }
+    );
+}
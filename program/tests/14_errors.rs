@@ -0,0 +1,135 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::Challenge,
+};
+
+use solana_program::{
+    instruction::{Instruction, InstructionError},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use crate::utils::{
+    add_challenge_with_solutions, add_started_challenge_with_solutions,
+};
+use solana_sdk::{
+    signature::Keypair, signer::Signer, transaction::TransactionError,
+};
+
+use crate::utils::{get_deserialized, program_test, try_process_ix};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+
+async fn process_ix_failing_with(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Option<ChallengeError> {
+    let err = try_process_ix(context, ix, signers)
+        .await
+        .expect_err("Transaction should have failed");
+    ChallengeError::from_transaction_error(&err.unwrap())
+}
+
+// -----------------
+// Decoding
+// -----------------
+#[test]
+fn decode_challenge_error_from_transaction_error() {
+    let code = match ProgramError::from(ChallengeError::OutOfSolutions) {
+        ProgramError::Custom(code) => code,
+        err => panic!("Expected custom error, got {:?}", err),
+    };
+    assert_eq!(
+        ChallengeError::from_transaction_error(
+            &TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(code)
+            )
+        ),
+        Some(ChallengeError::OutOfSolutions)
+    );
+    assert_eq!(
+        ChallengeError::from_transaction_error(
+            &TransactionError::InstructionError(
+                0,
+                InstructionError::MissingAccount
+            )
+        ),
+        None
+    );
+    assert_eq!(
+        ChallengeError::from_transaction_error(
+            &TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(u32::MAX)
+            )
+        ),
+        None
+    );
+}
+
+// -----------------
+// Account Mismatches
+// -----------------
+#[tokio::test]
+async fn start_challenge_providing_pda_of_other_creator() {
+    let mut context = program_test().start_with_context().await;
+    let other_creator = Pubkey::new_unique();
+    add_challenge_with_solutions(
+        &mut context,
+        ID,
+        vec!["hello"],
+        Some(other_creator),
+    );
+
+    let mut ix = ixs::start_challenge(other_creator, ID.to_string())
+        .expect("failed to create instruction");
+    ix.accounts[0].pubkey = context.payer.pubkey();
+
+    assert_eq!(
+        process_ix_failing_with(&mut context, ix, &[]).await,
+        Some(ChallengeError::IncorrectPda)
+    );
+}
+
+#[tokio::test]
+async fn redeem_providing_incorrect_redeem_mint() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    add_started_challenge_with_solutions(&mut context, ID, vec!["hello"], None);
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    let mut ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    ix.accounts[4].pubkey = Pubkey::new_unique();
+
+    assert_eq!(
+        process_ix_failing_with(&mut context, ix, &[&challenger]).await,
+        Some(ChallengeError::IncorrectRedeemMint)
+    );
+}
@@ -0,0 +1,207 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda, Redeem},
+    utils::{hash_solution_challenger_sends, hash_solutions},
+};
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    system_program,
+};
+use solana_program_test::*;
+
+use crate::cpi_caller::{
+    add_caller_program, host_pda, player_pda, CallerInstruction, CALLER_ID,
+};
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::account::AccountSharedData;
+
+use crate::utils::{
+    get_deserialized, program_test, try_process_ix,
+    verify_minted_when_redeeming,
+};
+
+mod cpi_caller;
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000_000;
+const TRIES_PER_ADMIT: u8 = 3;
+
+fn program_test_with_caller() -> ProgramTest {
+    let mut program_test = program_test();
+    add_caller_program(&mut program_test);
+    program_test
+}
+
+/// The PDAs of the caller pay for the challenge and admission and thus need to be
+/// owned by the system program.
+fn fund_system_account(context: &mut ProgramTestContext, address: &Pubkey) {
+    let account =
+        AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program::id());
+    context.set_account(address, &account);
+}
+
+/// Wraps the accounts of the challenge instruction into an instruction for the caller.
+/// The caller signs for its PDAs, the challenge program is passed last.
+fn caller_ix(challenge_ix: Instruction, ix: CallerInstruction) -> Instruction {
+    let pdas = [host_pda().0, player_pda().0];
+    let mut accounts = challenge_ix
+        .accounts
+        .into_iter()
+        .map(|mut x| {
+            if pdas.contains(&x.pubkey) {
+                x.is_signer = false;
+            }
+            x
+        })
+        .collect::<Vec<_>>();
+    accounts.push(AccountMeta::new_readonly(challenge_id(), false));
+
+    Instruction {
+        program_id: CALLER_ID,
+        accounts,
+        data: ix.try_to_vec().unwrap(),
+    }
+}
+
+fn host_challenge_ix() -> Instruction {
+    let (host, _) = host_pda();
+    let solutions = vec!["hello", "world"];
+    caller_ix(
+        ixs::create_challenge(
+            host,
+            host,
+            ID.to_string(),
+            ADMIT_COST,
+            TRIES_PER_ADMIT,
+            solutions.clone(),
+        )
+        .expect("failed to create instruction"),
+        CallerInstruction::HostChallenge {
+            id: ID.to_string(),
+            admit_cost: ADMIT_COST,
+            tries_per_admit: TRIES_PER_ADMIT,
+            solutions: hash_solutions(&solutions, &Default::default()),
+        },
+    )
+}
+
+/// Has the caller host a challenge and admit its player.
+async fn host_challenge_and_admit_player(context: &mut ProgramTestContext) {
+    let (host, _) = host_pda();
+    let (player, _) = player_pda();
+    fund_system_account(context, &host);
+    fund_system_account(context, &player);
+
+    let ix = host_challenge_ix();
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to host challenge");
+
+    let (challenge_pda, _) = Challenge::pda_for(&host, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    assert!(challenge.started);
+
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(player, &challenge, player, None)
+            .expect("failed to create instruction");
+    try_process_ix(context, caller_ix(ix, CallerInstruction::AdmitPlayer), &[])
+        .await
+        .expect("Failed to admit player");
+}
+
+async fn redeem_for_player_ix(
+    context: &mut ProgramTestContext,
+    solution: &str,
+) -> Instruction {
+    let (host, _) = host_pda();
+    let (player, _) = player_pda();
+    let (challenge_pda, _) = Challenge::pda_for(&host, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    caller_ix(
+        ixs::redeem_challenge(player, &challenge, player, solution, vec![])
+            .expect("failed to create instruction"),
+        CallerInstruction::RedeemForPlayer {
+            solution: hash_solution_challenger_sends(
+                solution,
+                &Default::default(),
+            ),
+        },
+    )
+}
+
+// -----------------
+// Host, Admit and Redeem via CPI
+// -----------------
+#[tokio::test]
+async fn caller_hosts_challenge_and_redeems_for_player() {
+    let mut context = program_test_with_caller().start_with_context().await;
+    host_challenge_and_admit_player(&mut context).await;
+
+    let ix = redeem_for_player_ix(&mut context, "hello").await;
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to redeem for player");
+
+    let (host, _) = host_pda();
+    let (player, _) = player_pda();
+    let (challenge_pda, _) = Challenge::pda_for(&host, ID);
+    let challenger = Challenger::new(player, challenge_pda, 0);
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger.pda().0).await;
+    assert_eq!(value.tries_remaining, TRIES_PER_ADMIT - 1);
+    assert!(value.redeemed);
+
+    let redeem = Redeem::new(challenge_pda);
+    verify_minted_when_redeeming(
+        &mut context,
+        redeem.pda,
+        1,
+        &redeem,
+        &challenger,
+    )
+    .await;
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn caller_fails_redeem_for_player_with_incorrect_solution() {
+    let mut context = program_test_with_caller().start_with_context().await;
+    host_challenge_and_admit_player(&mut context).await;
+
+    let ix = redeem_for_player_ix(&mut context, "hallo").await;
+    let result = try_process_ix(&mut context, ix, &[]).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn caller_providing_incorrect_challenge_program() {
+    let mut context = program_test_with_caller().start_with_context().await;
+    let (host, _) = host_pda();
+    fund_system_account(&mut context, &host);
+
+    let mut ix = host_challenge_ix();
+    let last = ix.accounts.len() - 1;
+    ix.accounts[last].pubkey = Pubkey::new_unique();
+
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectProgramId)
+    );
+}
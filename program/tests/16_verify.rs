@@ -0,0 +1,285 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, Challenger, HasPda},
+    verify::{verify_solver, SolverStatus},
+};
+
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{
+    account::Account, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+use crate::cpi_caller::{add_caller_program, CallerInstruction, CALLER_ID};
+use crate::utils::{get_account, get_deserialized, process_ix, program_test};
+
+mod cpi_caller;
+mod utils;
+
+fn program_test_with_caller() -> ProgramTest {
+    let mut program_test = program_test();
+    add_caller_program(&mut program_test);
+    program_test
+}
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 5;
+
+/// Creates and starts a challenge, admits a challenger and has it redeem the solutions.
+/// Returns the challenge PDA and the challenger.
+async fn challenger_redeeming(
+    context: &mut ProgramTestContext,
+    solutions: &[&str],
+) -> (Pubkey, Keypair) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world", "!"],
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    for solution in solutions {
+        let ix = ixs::redeem_challenge(
+            creator,
+            &challenge,
+            challenger.pubkey(),
+            solution,
+            vec![],
+        )
+        .expect("failed to create instruction");
+        process_ix(context, ix, &[&challenger]).await;
+    }
+
+    (challenge_pda, challenger)
+}
+
+fn verify(
+    (challenge_key, mut challenge_acc): (Pubkey, Account),
+    (challenger_key, mut challenger_acc): (Pubkey, Account),
+    wallet: &Pubkey,
+) -> Result<SolverStatus, Option<ChallengeError>> {
+    let challenge_info = AccountInfo::new(
+        &challenge_key,
+        false,
+        false,
+        &mut challenge_acc.lamports,
+        &mut challenge_acc.data,
+        &challenge_acc.owner,
+        false,
+        0,
+    );
+    let challenger_info = AccountInfo::new(
+        &challenger_key,
+        false,
+        false,
+        &mut challenger_acc.lamports,
+        &mut challenger_acc.data,
+        &challenger_acc.owner,
+        false,
+        0,
+    );
+    verify_solver(&challenge_info, &challenger_info, wallet)
+        .map_err(|err: ProgramError| ChallengeError::from_program_error(&err))
+}
+
+async fn accounts(
+    context: &mut ProgramTestContext,
+    challenge_pda: Pubkey,
+    challenger: &Pubkey,
+) -> ((Pubkey, Account), (Pubkey, Account)) {
+    let challenger_pda = Challenger::new(*challenger, challenge_pda, 0).pda().0;
+    (
+        (challenge_pda, get_account(context, &challenge_pda).await),
+        (challenger_pda, get_account(context, &challenger_pda).await),
+    )
+}
+
+// -----------------
+// Verify Solver
+// -----------------
+// Successful verifications don't log and thus can be verified outside of a program.
+#[tokio::test]
+async fn verify_challenger_that_solved() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, challenger) =
+        challenger_redeeming(&mut context, &["hello", "nope", "world"]).await;
+
+    let (challenge, challenger_acc) =
+        accounts(&mut context, challenge_pda, &challenger.pubkey()).await;
+    assert_eq!(
+        verify(challenge, challenger_acc, &challenger.pubkey()),
+        Ok(SolverStatus {
+            solved: true,
+            times_solved: 2,
+            solved_indexes: vec![0, 1],
+        })
+    );
+}
+
+#[tokio::test]
+async fn verify_challenger_that_did_not_solve() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, challenger) =
+        challenger_redeeming(&mut context, &["nope"]).await;
+
+    let (challenge, challenger_acc) =
+        accounts(&mut context, challenge_pda, &challenger.pubkey()).await;
+    assert_eq!(
+        verify(challenge, challenger_acc, &challenger.pubkey()),
+        Ok(SolverStatus::default())
+    );
+}
+
+#[tokio::test]
+async fn verify_wallet_that_was_never_admitted() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, _) = challenger_redeeming(&mut context, &[]).await;
+
+    let wallet = Pubkey::new_unique();
+    let challenger_pda = Challenger::new(wallet, challenge_pda, 0).pda().0;
+    let challenge = (
+        challenge_pda,
+        get_account(&mut context, &challenge_pda).await,
+    );
+    assert_eq!(
+        verify(challenge, (challenger_pda, Account::default()), &wallet),
+        Ok(SolverStatus::default())
+    );
+}
+
+// -----------------
+// Error Cases
+// -----------------
+// Failing verifications log errors and thus are verified via the caller program.
+async fn verify_via_caller(
+    context: &mut ProgramTestContext,
+    challenge_pda: Pubkey,
+    challenger_pda: Pubkey,
+    wallet: Pubkey,
+) -> Option<ChallengeError> {
+    let ix = Instruction {
+        program_id: CALLER_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(challenge_pda, false),
+            AccountMeta::new_readonly(challenger_pda, false),
+        ],
+        data: CallerInstruction::VerifySolver { wallet }
+            .try_to_vec()
+            .unwrap(),
+    };
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .expect_err("Verification should have failed");
+    ChallengeError::from_transaction_error(&err.unwrap())
+}
+
+#[tokio::test]
+async fn verify_challenger_of_other_wallet() {
+    let mut context = program_test_with_caller().start_with_context().await;
+    let (challenge_pda, challenger) =
+        challenger_redeeming(&mut context, &["hello"]).await;
+
+    let challenger_pda = Challenger::new(challenger.pubkey(), challenge_pda, 0)
+        .pda()
+        .0;
+    assert_eq!(
+        verify_via_caller(
+            &mut context,
+            challenge_pda,
+            challenger_pda,
+            Pubkey::new_unique()
+        )
+        .await,
+        Some(ChallengeError::IncorrectPda)
+    );
+}
+
+#[tokio::test]
+async fn verify_challenger_with_incorrect_discriminator() {
+    let mut context = program_test_with_caller().start_with_context().await;
+    let (challenge_pda, challenger) =
+        challenger_redeeming(&mut context, &["hello"]).await;
+
+    let challenger_pda = Challenger::new(challenger.pubkey(), challenge_pda, 0)
+        .pda()
+        .0;
+    let mut challenger_acc = get_account(&mut context, &challenger_pda).await;
+    challenger_acc.data[0] ^= 0xff;
+    context.set_account(&challenger_pda, &challenger_acc.into());
+
+    assert_eq!(
+        verify_via_caller(
+            &mut context,
+            challenge_pda,
+            challenger_pda,
+            challenger.pubkey()
+        )
+        .await,
+        Some(ChallengeError::AccountDiscriminatorMismatch)
+    );
+}
+
+#[tokio::test]
+async fn verify_challenge_not_owned_by_program() {
+    let mut context = program_test_with_caller().start_with_context().await;
+    let (challenge_pda, challenger) =
+        challenger_redeeming(&mut context, &["hello"]).await;
+
+    let mut challenge_acc = get_account(&mut context, &challenge_pda).await;
+    challenge_acc.owner = Pubkey::new_unique();
+    context.set_account(&challenge_pda, &challenge_acc.into());
+
+    let challenger_pda = Challenger::new(challenger.pubkey(), challenge_pda, 0)
+        .pda()
+        .0;
+    assert_eq!(
+        verify_via_caller(
+            &mut context,
+            challenge_pda,
+            challenger_pda,
+            challenger.pubkey()
+        )
+        .await,
+        Some(ChallengeError::IncorrectAccountOwner)
+    );
+}
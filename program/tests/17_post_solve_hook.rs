@@ -0,0 +1,286 @@
+#![cfg(feature = "test-sbf")]
+
+use assert_matches::assert_matches;
+use borsh::BorshDeserialize;
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    hook::PostSolveHook,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, HasSize},
+};
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_program_test::*;
+
+use crate::hook_program::{add_hook_program, HOOK_ID, RECORD_SIZE};
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{
+    account::AccountSharedData, signature::Keypair, signer::Signer,
+};
+
+use crate::utils::{
+    get_account, get_challenge, get_deserialized, program_test, try_process_ix,
+};
+
+mod hook_program;
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 1_000;
+const TRIES_PER_ADMIT: u8 = 5;
+
+fn program_test_with_hook() -> ProgramTest {
+    let mut program_test = program_test();
+    add_hook_program(&mut program_test);
+    program_test
+}
+
+async fn create_challenge(context: &mut ProgramTestContext) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+}
+
+async fn set_hook_program(
+    context: &mut ProgramTestContext,
+    hook_program: Option<Pubkey>,
+) -> Result<(), BanksClientError> {
+    let creator = context.payer.pubkey();
+    let ix =
+        ixs::set_hook_program(creator, creator, ID.to_string(), hook_program)
+            .expect("failed to create instruction");
+    try_process_ix(context, ix, &[]).await
+}
+
+/// Creates a challenge with the hook, starts it and admits a challenger.
+/// Returns the challenger and the account the hook records solves into.
+async fn challenger_of_hooked_challenge(
+    context: &mut ProgramTestContext,
+) -> (Keypair, Pubkey) {
+    let creator = context.payer.pubkey();
+    create_challenge(context).await;
+    set_hook_program(context, Some(HOOK_ID))
+        .await
+        .expect("Failed to set hook program");
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to start challenge");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    let record = Pubkey::new_unique();
+    context.set_account(
+        &record,
+        &AccountSharedData::new(1_000_000_000, RECORD_SIZE, &HOOK_ID),
+    );
+
+    (challenger, record)
+}
+
+/// Redeems the challenge passing the [hook_program] which may differ from the one of
+/// the challenge.
+async fn redeem_with_hook_ix(
+    context: &mut ProgramTestContext,
+    challenger: Pubkey,
+    solution: &str,
+    hook_program: Pubkey,
+    record: Pubkey,
+) -> Instruction {
+    let challenge = Challenge {
+        hook_program: Some(hook_program),
+        ..get_challenge(context, ID).await
+    };
+    ixs::redeem_challenge(
+        context.payer.pubkey(),
+        &challenge,
+        challenger,
+        solution,
+        vec![AccountMeta::new(record, false)],
+    )
+    .expect("failed to create instruction")
+}
+
+// -----------------
+// Set Hook Program
+// -----------------
+#[tokio::test]
+async fn set_and_remove_hook_program() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    create_challenge(&mut context).await;
+    set_hook_program(&mut context, Some(HOOK_ID))
+        .await
+        .expect("Failed to set hook program");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            hook_program: Some(HOOK_ID),
+            ..
+        }
+    );
+    assert_eq!(acc.data.len(), value.size());
+
+    set_hook_program(&mut context, None)
+        .await
+        .expect("Failed to remove hook program");
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_matches!(
+        value,
+        Challenge {
+            hook_program: None,
+            ..
+        }
+    );
+    assert_eq!(acc.data.len(), value.size());
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_correct_solution_invokes_hook() {
+    let mut context = program_test_with_hook().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let (challenger, record) =
+        challenger_of_hooked_challenge(&mut context).await;
+
+    let ix = redeem_with_hook_ix(
+        &mut context,
+        challenger.pubkey(),
+        "hello",
+        HOOK_ID,
+        record,
+    )
+    .await;
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect("Failed to redeem");
+
+    let record_acc = get_account(&mut context, &record).await;
+    let hook = PostSolveHook::try_from_slice(&record_acc.data).unwrap();
+    assert_eq!(
+        hook,
+        PostSolveHook {
+            challenge_pda: Challenge::pda_for(&creator, ID).0,
+            challenger: challenger.pubkey(),
+            solution_index: 0,
+        }
+    );
+}
+
+#[tokio::test]
+async fn redeem_incorrect_solution_does_not_invoke_hook() {
+    let mut context = program_test_with_hook().start_with_context().await;
+    let (challenger, record) =
+        challenger_of_hooked_challenge(&mut context).await;
+
+    let ix = redeem_with_hook_ix(
+        &mut context,
+        challenger.pubkey(),
+        "nope",
+        HOOK_ID,
+        record,
+    )
+    .await;
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect("Failed to redeem");
+
+    let record_acc = get_account(&mut context, &record).await;
+    assert_eq!(record_acc.data, vec![0; RECORD_SIZE]);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_providing_incorrect_hook_program() {
+    let mut context = program_test_with_hook().start_with_context().await;
+    let (challenger, record) =
+        challenger_of_hooked_challenge(&mut context).await;
+
+    let ix = redeem_with_hook_ix(
+        &mut context,
+        challenger.pubkey(),
+        "hello",
+        Pubkey::new_unique(),
+        record,
+    )
+    .await;
+    let err = try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect_err("Redeem should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectHookProgram)
+    );
+}
+
+#[tokio::test]
+async fn set_hook_program_to_challenge_program() {
+    let mut context = program_test().start_with_context().await;
+    create_challenge(&mut context).await;
+
+    let err = set_hook_program(&mut context, Some(challenge_id()))
+        .await
+        .expect_err("Setting hook program should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::InvalidHookProgram)
+    );
+}
+
+#[tokio::test]
+async fn set_hook_program_after_challenge_started() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    create_challenge(&mut context).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to start challenge");
+
+    let err = set_hook_program(&mut context, Some(HOOK_ID))
+        .await
+        .expect_err("Setting hook program should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::ChallengeAlreadyStarted)
+    );
+}
@@ -0,0 +1,322 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use challenge::{
+    ixs::{self, AdmitChallengerIx},
+    state::{
+        Challenge, ChallengeView, FeeRecipient, SolutionHashing,
+        SolutionNormalization,
+    },
+    utils::hash_solutions,
+};
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{
+    signature::Keypair,
+    signer::{keypair::keypair_from_seed, Signer},
+};
+
+use crate::utils::{get_deserialized, process_ix, program_test, tx_for};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const SOLUTIONS_PER_TX: usize = 25;
+
+/// The amount the compute units consumed by a redeem may grow from a challenge
+/// with two solutions to one with the maximum amount of solutions.
+/// This accounts for the runtime charging for the larger challenge account that is
+/// passed along when minting the redeem token, but not for processing its solutions.
+const MAX_COMPUTE_UNITS_GROWTH: u64 = 1_000;
+
+/// Upper bounds of the compute units consumed by a redeem regardless of the amount of
+/// solutions. The first redeem of a challenger also creates its token account and
+/// consumed ~25K compute units when this was recorded, any following one ~9K.
+const MAX_FIRST_REDEEM_COMPUTE_UNITS: u64 = 30_000;
+const MAX_REDEEM_COMPUTE_UNITS: u64 = 12_000;
+
+/// Processes the [ix] and returns the compute units it consumed.
+async fn process_ix_consuming_units(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> u64 {
+    let tx = tx_for(context, ix, signers);
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+    result.result.expect("Transaction failed");
+    result
+        .metadata
+        .expect("Transaction has no metadata")
+        .compute_units_consumed
+}
+
+/// Compute units consumed by the instructions of a challenge with a given amount
+/// of solutions.
+struct ComputeUnits {
+    /// Starting the challenge, which deserializes and serializes the full
+    /// challenge including its solutions, i.e. what redeem did before it
+    /// accessed the challenge via [ChallengeView].
+    start: u64,
+    /// Redeeming the first solution, which also creates the challenger's token account.
+    first_redeem: u64,
+    /// Redeeming the last solution.
+    last_redeem: u64,
+}
+
+/// Creates and starts a challenge with the given amount of solutions, admits a
+/// challenger and returns the compute units consumed by starting the challenge and
+/// by the challenger redeeming the first and the last solution.
+/// Creator and challenger are the same for each run so that deriving the challenge
+/// related addresses costs the same amount of compute units.
+async fn compute_units(solutions_len: usize) -> ComputeUnits {
+    let mut context = program_test().start_with_context().await;
+    let creator_keypair = keypair_from_seed(&[1; 32]).unwrap();
+    let challenger = keypair_from_seed(&[2; 32]).unwrap();
+    let payer = context.payer.pubkey();
+    let creator = creator_keypair.pubkey();
+
+    let solutions = (0..solutions_len)
+        .map(|x| format!("solution-{}", x))
+        .collect::<Vec<_>>();
+    let mut chunks = solutions.chunks(SOLUTIONS_PER_TX);
+
+    let first = chunks.next().unwrap().iter().map(String::as_str).collect();
+    let ix = ixs::create_challenge(
+        payer,
+        creator,
+        ID.to_string(),
+        0,
+        u8::MAX,
+        first,
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    for chunk in chunks {
+        let (_, challenge) = get_deserialized::<Challenge>(
+            &mut context,
+            &Challenge::pda_for(&creator, ID).0,
+        )
+        .await;
+        let ix = ixs::add_challenge_solutions(
+            payer,
+            &challenge,
+            chunk.iter().map(String::as_str).collect(),
+        )
+        .expect("failed to create instruction");
+        process_ix(&mut context, ix, &[&creator_keypair]).await;
+    }
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let start =
+        process_ix_consuming_units(&mut context, ix, &[&creator_keypair]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(payer, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let mut compute_units = vec![];
+    for (idx, solution) in solutions.iter().enumerate() {
+        let ix = ixs::redeem_challenge(
+            payer,
+            &challenge,
+            challenger.pubkey(),
+            solution,
+            vec![],
+        )
+        .expect("failed to create instruction");
+        let consumed =
+            process_ix_consuming_units(&mut context, ix, &[&challenger]).await;
+        if idx == 0 || idx == solutions_len - 1 {
+            compute_units.push(consumed);
+        }
+        context.last_blockhash = context
+            .banks_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+    }
+    ComputeUnits {
+        start,
+        first_redeem: compute_units[0],
+        last_redeem: *compute_units.last().unwrap(),
+    }
+}
+
+// -----------------
+// Challenge View
+// -----------------
+#[test]
+fn challenge_view_reads_serialized_challenge_in_place() {
+    let creator = Pubkey::new_unique();
+    let hook_program = Pubkey::new_unique();
+    let challenge = Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        pot_bump: 254,
+        started: true,
+        escrow: true,
+        admit_cost: 1_000,
+        tries_per_admit: 3,
+        redeem: Pubkey::new_unique(),
+        solving: 1,
+        required_amount: 2,
+        normalization: SolutionNormalization {
+            nfkc: true,
+            case_fold: true,
+            trim_whitespace: false,
+            collapse_whitespace: true,
+        },
+        soulbound: true,
+        escrowed_tries: 6,
+        id: ID.to_string(),
+        admission_root: Some([1; 32]),
+        required_mint: Some(Pubkey::new_unique()),
+        fee_recipients: vec![FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            basis_points: 10_000,
+        }],
+        jackpot: Some(5_000),
+        hook_program: Some(hook_program),
+        hashing: Some(SolutionHashing {
+            salt: [2; 32],
+            iterations: 10,
+        }),
+        solutions: hash_solutions(
+            &["hello", "world", "!"],
+            &Default::default(),
+        ),
+        ..Default::default()
+    };
+    let mut data = challenge.try_to_vec().unwrap();
+
+    let view = ChallengeView::from_data(&mut data).unwrap();
+    assert_eq!(view.header.authority, challenge.authority);
+    assert_eq!(view.header.bump, challenge.bump);
+    assert_eq!(view.header.pot_bump, 254);
+    assert!(view.header.started());
+    assert!(!view.header.finished());
+    assert!(!view.header.cancelled());
+    assert!(view.header.escrow());
+    assert_eq!({ view.header.admit_cost }, 1_000);
+    assert_eq!(view.header.tries_per_admit, 3);
+    assert_eq!(view.header.redeem, challenge.redeem);
+    assert_eq!(view.header.solving, 1);
+    assert_eq!({ view.header.required_amount }, 2);
+    assert_eq!(view.header.normalization(), challenge.normalization);
+    assert!(view.header.soulbound());
+    assert_eq!({ view.header.escrowed_tries }, 6);
+    assert_eq!(view.id, ID);
+    assert_eq!(view.jackpot, Some(5_000));
+    assert_eq!(view.hook_program, Some(hook_program));
+    assert_eq!(view.hashing, challenge.hashing);
+    assert_eq!(view.solutions_len(), 3);
+    assert_eq!(view.current_solution(), Some(&challenge.solutions[1][..]));
+    assert_eq!(view.solution(3), None);
+}
+
+#[test]
+fn challenge_view_updates_header_in_place() {
+    let creator = Pubkey::new_unique();
+    let challenge = Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        started: true,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        id: ID.to_string(),
+        solutions: hash_solutions(&["hello"], &Default::default()),
+        ..Default::default()
+    };
+    let mut data = challenge.try_to_vec().unwrap();
+    {
+        let view = ChallengeView::from_data(&mut data).unwrap();
+        view.header.solving += 1;
+        view.header.set_finished(true);
+    }
+
+    let updated = Challenge::try_from_slice(&data).unwrap();
+    assert_eq!(updated.solving, 1);
+    assert!(updated.finished);
+    assert_eq!(updated.solutions, challenge.solutions);
+}
+
+#[test]
+fn challenge_view_rejects_truncated_data() {
+    let creator = Pubkey::new_unique();
+    let challenge = Challenge {
+        authority: creator,
+        tries_per_admit: 1,
+        redeem: Pubkey::new_unique(),
+        id: ID.to_string(),
+        solutions: hash_solutions(&["hello"], &Default::default()),
+        ..Default::default()
+    };
+    let data = challenge.try_to_vec().unwrap();
+    let mut truncated = data[..data.len() - 1].to_vec();
+    assert!(ChallengeView::from_data(&mut truncated).is_err());
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_compute_units_do_not_grow_with_solutions() {
+    let few = compute_units(2).await;
+    let max = compute_units(u8::MAX as usize).await;
+
+    // Starting the challenge deserializes all its solutions and thus serves as
+    // the baseline of how much a redeem would grow without the ChallengeView
+    let (few_start, max_start) = (few.start, max.start);
+    let start_growth = max_start.saturating_sub(few_start);
+    for (name, few, max, bound) in [
+        (
+            "first redeem",
+            few.first_redeem,
+            max.first_redeem,
+            MAX_FIRST_REDEEM_COMPUTE_UNITS,
+        ),
+        (
+            "redeem",
+            few.last_redeem,
+            max.last_redeem,
+            MAX_REDEEM_COMPUTE_UNITS,
+        ),
+    ] {
+        let growth = max.saturating_sub(few);
+        assert!(
+            growth <= MAX_COMPUTE_UNITS_GROWTH && growth <= start_growth,
+            "{} consumed {} compute units with {} solutions vs {} with two, \
+             growing by {} while the full borsh deserialization of the start \
+             grew by {} ({} vs {})",
+            name,
+            max,
+            u8::MAX,
+            few,
+            growth,
+            start_growth,
+            max_start,
+            few_start
+        );
+        assert!(
+            max <= bound,
+            "{} consumed {} compute units exceeding the bound of {}",
+            name,
+            max,
+            bound
+        );
+    }
+}
@@ -0,0 +1,366 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshDeserialize;
+use challenge::{
+    error::ChallengeError,
+    ixs::{
+        self, AdmitChallengerIx, ChallengeInstruction, CreateChallengeOptions,
+        MAX_TRANSACTION_SIZE, SOLUTIONS_PER_INSTRUCTION,
+    },
+    state::{
+        Challenge, FeeRecipient, HasPda, HasSize, SolutionHashing,
+        SolutionNormalization, SoulboundRedeem, MAX_FEE_RECIPIENTS,
+    },
+    utils::hash_solutions,
+};
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{
+    packet::PACKET_DATA_SIZE,
+    signature::{Keypair, Signature},
+    signer::Signer,
+};
+
+use crate::utils::{
+    get_challenge, get_deserialized, process_ix, program_test, tx_for,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+
+async fn create_challenge_with_capacity(
+    context: &mut ProgramTestContext,
+    solutions: Vec<&str>,
+    capacity: u8,
+) -> Challenge {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_capacity(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions,
+        capacity,
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    get_deserialized::<Challenge>(context, &challenge_pda)
+        .await
+        .1
+}
+
+async fn add_solutions(context: &mut ProgramTestContext, solutions: Vec<&str>) {
+    let creator = context.payer.pubkey();
+    let challenge = get_challenge(context, ID).await;
+    let ix = ixs::add_challenge_solutions(creator, &challenge, solutions)
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+}
+
+// -----------------
+// Create Challenge with Capacity
+// -----------------
+#[tokio::test]
+async fn create_challenge_reserving_space_for_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let challenge =
+        create_challenge_with_capacity(&mut context, vec!["hello"], 4).await;
+    let challenge_pda = challenge.pda().0;
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(
+        value.solutions,
+        hash_solutions(&["hello"], &Default::default())
+    );
+    assert_eq!(
+        acc.data.len(),
+        Challenge::needed_size(
+            &hash_solutions(&["1", "2", "3", "4"], &Default::default()),
+            ID
+        )
+    );
+    assert_eq!(value.reserved_space(acc.data.len()), 3 * 32);
+}
+
+#[tokio::test]
+async fn create_challenge_with_capacity_below_provided_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let challenge =
+        create_challenge_with_capacity(&mut context, vec!["hello", "world"], 1)
+            .await;
+    let challenge_pda = challenge.pda().0;
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(value.solutions.len(), 2);
+    assert_eq!(acc.data.len(), value.size());
+}
+
+// -----------------
+// Add Solutions
+// -----------------
+#[tokio::test]
+async fn add_solutions_using_reserved_space_before_growing() {
+    let mut context = program_test().start_with_context().await;
+    let challenge =
+        create_challenge_with_capacity(&mut context, vec!["hello"], 3).await;
+    let challenge_pda = challenge.pda().0;
+    let (created_acc, _) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+
+    add_solutions(&mut context, vec!["world", "!"]).await;
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(
+        value.solutions,
+        hash_solutions(&["hello", "world", "!"], &Default::default())
+    );
+    assert_eq!(acc.data.len(), created_acc.data.len());
+    assert_eq!(
+        acc.lamports, created_acc.lamports,
+        "does not pay extra rent"
+    );
+    assert_eq!(value.reserved_space(acc.data.len()), 0);
+
+    add_solutions(&mut context, vec!["more"]).await;
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(value.solutions.len(), 4);
+    assert_eq!(acc.data.len(), value.size());
+    assert!(acc.lamports > created_acc.lamports, "does pay extra rent");
+}
+
+#[tokio::test]
+async fn set_admission_root_keeping_reserved_space() {
+    let mut context = program_test().start_with_context().await;
+    let challenge =
+        create_challenge_with_capacity(&mut context, vec!["hello"], 3).await;
+    let challenge_pda = challenge.pda().0;
+    let creator = context.payer.pubkey();
+
+    for admission_root in [Some([1; 32]), None] {
+        let ix = ixs::set_admission_root(
+            creator,
+            creator,
+            ID.to_string(),
+            admission_root,
+        )
+        .expect("failed to create instruction");
+        process_ix(&mut context, ix, &[]).await;
+
+        let (acc, value) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_eq!(value.admission_root, admission_root);
+        assert_eq!(acc.data.len(), value.size() + 2 * 32);
+    }
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_challenge_with_reserved_space() {
+    let mut context = program_test().start_with_context().await;
+    create_challenge_with_capacity(&mut context, vec!["hello", "world"], 8)
+        .await;
+    let creator = context.payer.pubkey();
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+
+    let (_, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(value.solving, 1);
+}
+
+// -----------------
+// Create Challenge Chunked
+// -----------------
+#[tokio::test]
+async fn create_challenge_chunked_with_max_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let creator = Keypair::new();
+    // ids are PDA seeds and thus at most 32 bytes long
+    let id = "c".repeat(32);
+
+    let solutions = (0..u8::MAX)
+        .map(|x| format!("solution-{}", x))
+        .collect::<Vec<_>>();
+    let solutions = solutions.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let ixs = ixs::create_challenge_chunked(
+        context.payer.pubkey(),
+        creator.pubkey(),
+        id.clone(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions.clone(),
+        Default::default(),
+    )
+    .expect("failed to create instructions");
+    assert_eq!(
+        ixs.len(),
+        (u8::MAX as usize).div_ceil(SOLUTIONS_PER_INSTRUCTION)
+    );
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator.pubkey(), &id);
+    let mut size = None;
+    for (idx, ix) in ixs.into_iter().enumerate() {
+        let signers: &[&Keypair] = if idx == 0 { &[] } else { &[&creator] };
+        let tx = tx_for(&context, ix, signers);
+        // short vec length of the signatures followed by signatures and message
+        let tx_size = 1
+            + tx.signatures.len() * std::mem::size_of::<Signature>()
+            + tx.message.serialize().len();
+        assert!(
+            tx_size <= PACKET_DATA_SIZE,
+            "transaction {} is {} bytes",
+            idx,
+            tx_size
+        );
+        context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .expect("Failed to process transaction");
+
+        let (acc, _) =
+            get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+        assert_eq!(
+            *size.get_or_insert(acc.data.len()),
+            acc.data.len(),
+            "account does not grow after creation"
+        );
+    }
+
+    let (acc, value) =
+        get_deserialized::<Challenge>(&mut context, &challenge_pda).await;
+    assert_eq!(
+        value.solutions,
+        hash_solutions(&solutions, &Default::default())
+    );
+    assert_eq!(acc.data.len(), value.size());
+}
+
+#[test]
+fn create_challenge_chunked_with_max_solutions_and_options() {
+    let payer = Keypair::new().pubkey();
+    let creator = Keypair::new().pubkey();
+    let id = "c".repeat(32);
+
+    let solutions = (0..u8::MAX)
+        .map(|x| format!("solution-{}", x))
+        .collect::<Vec<_>>();
+    let solutions = solutions.iter().map(String::as_str).collect::<Vec<_>>();
+
+    // every option that adds to the first instruction is set
+    let options = CreateChallengeOptions {
+        fee_recipients: (0..MAX_FEE_RECIPIENTS)
+            .map(|_| FeeRecipient {
+                recipient: Pubkey::new_unique(),
+                basis_points: 1_000,
+            })
+            .collect(),
+        capacity: 0,
+        hashing: Some(SolutionHashing {
+            salt: [1; 32],
+            iterations: 1,
+        }),
+        normalization: SolutionNormalization {
+            nfkc: true,
+            case_fold: true,
+            trim_whitespace: true,
+            collapse_whitespace: true,
+        },
+        soulbound: Some(SoulboundRedeem {
+            metadata_address: Some(Pubkey::new_unique()),
+            revocable: true,
+        }),
+    };
+    let ixs = ixs::create_challenge_chunked(
+        payer,
+        creator,
+        id,
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions,
+        options,
+    )
+    .expect("failed to create instructions");
+
+    assert_eq!(MAX_TRANSACTION_SIZE, PACKET_DATA_SIZE);
+    let mut solutions_len = 0;
+    for (idx, ix) in ixs.iter().enumerate() {
+        let tx_size = ixs::transaction_size(&[ix.clone()], &payer);
+        assert!(
+            tx_size <= PACKET_DATA_SIZE,
+            "transaction {} is {} bytes",
+            idx,
+            tx_size
+        );
+        solutions_len += match ChallengeInstruction::try_from_slice(&ix.data)
+            .expect("failed to decode instruction")
+        {
+            ChallengeInstruction::CreateChallenge { solutions, .. } => {
+                assert!(solutions.len() < SOLUTIONS_PER_INSTRUCTION);
+                solutions.len()
+            }
+            ChallengeInstruction::AddSolutions { solutions, .. } => {
+                solutions.len()
+            }
+            ix => panic!("unexpected instruction {:?}", ix),
+        };
+    }
+    assert_eq!(solutions_len, u8::MAX as usize);
+}
+
+#[test]
+fn create_challenge_chunked_exceeding_max_solutions() {
+    let solutions = vec!["solution"; u8::MAX as usize + 1];
+    let creator = Keypair::new().pubkey();
+    let err = ixs::create_challenge_chunked(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions,
+        Default::default(),
+    )
+    .expect_err("should not create instructions");
+    assert_eq!(
+        err,
+        ProgramError::from(ChallengeError::ExceedingMaxSupportedSolutions)
+    );
+}
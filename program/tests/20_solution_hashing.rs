@@ -0,0 +1,312 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx, ChallengeInstruction},
+    state::{Challenge, HasPda, SolutionHashing, MAX_HASH_ITERATIONS},
+    utils::{
+        hash_bound_solutions, hash_salted_solution_challenger_sends,
+        hash_solutions,
+    },
+};
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{
+    get_challenge, get_challenger, program_test, try_process_ix,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const OTHER_ID: &str = "other-challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+const HASHING: SolutionHashing = SolutionHashing {
+    salt: [7; 32],
+    iterations: 100,
+};
+
+/// Creates and starts a challenge with solution hashing and admits a challenger.
+async fn challenge_with_challenger(
+    context: &mut ProgramTestContext,
+    id: &str,
+    solutions: Vec<&str>,
+) -> (Challenge, Keypair) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_hashing(
+        creator,
+        creator,
+        id.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions,
+        HASHING,
+    )
+    .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let ix = ixs::start_challenge(creator, id.to_string())
+        .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to start challenge");
+
+    let challenge = get_challenge(context, id).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    (challenge, challenger)
+}
+
+/// Redeems by sending the provided solution as is.
+fn redeem_sending_ix(
+    challenge: &Challenge,
+    challenger: &Pubkey,
+    solution: [u8; 32],
+) -> Instruction {
+    let mut ix = ixs::redeem_challenge(
+        challenge.authority,
+        challenge,
+        *challenger,
+        "",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    ix.data = ChallengeInstruction::Redeem { solution }
+        .try_to_vec()
+        .unwrap();
+    ix
+}
+
+// -----------------
+// Create Challenge with Hashing
+// -----------------
+#[tokio::test]
+async fn create_challenge_storing_bound_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let solutions = vec!["same", "same"];
+    let (challenge, _) =
+        challenge_with_challenger(&mut context, ID, solutions.clone()).await;
+
+    assert_eq!(challenge.hashing, Some(HASHING));
+    assert_eq!(
+        challenge.solutions,
+        hash_bound_solutions(
+            &solutions,
+            &Default::default(),
+            &challenge.pda().0,
+            &HASHING,
+            0
+        )
+    );
+    assert_ne!(
+        challenge.solutions,
+        hash_solutions(&solutions, &Default::default())
+    );
+    assert_ne!(
+        challenge.solutions[0], challenge.solutions[1],
+        "binds solutions to their index"
+    );
+}
+
+#[tokio::test]
+async fn add_challenge_solutions_binding_them_to_their_index() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_hashing(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+        HASHING,
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let challenge = get_challenge(&mut context, ID).await;
+    let ix = ixs::add_challenge_solutions(creator, &challenge, vec!["world"])
+        .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to add solutions");
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(
+        challenge.solutions,
+        hash_bound_solutions(
+            &["hello", "world"],
+            &Default::default(),
+            &challenge.pda().0,
+            &HASHING,
+            0
+        )
+    );
+}
+
+#[tokio::test]
+async fn create_challenge_exceeding_max_hash_iterations() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_hashing(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+        SolutionHashing {
+            salt: [7; 32],
+            iterations: MAX_HASH_ITERATIONS + 1,
+        },
+    )
+    .expect("failed to create instruction");
+
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::ExceedingMaxHashIterations)
+    );
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_bound_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let (_, challenger) =
+        challenge_with_challenger(&mut context, ID, vec!["hello", "world"])
+            .await;
+
+    for solution in ["hello", "world"] {
+        let challenge = get_challenge(&mut context, ID).await;
+        let ix = ixs::redeem_challenge(
+            context.payer.pubkey(),
+            &challenge,
+            challenger.pubkey(),
+            solution,
+            vec![],
+        )
+        .expect("failed to create instruction");
+        try_process_ix(&mut context, ix, &[&challenger])
+            .await
+            .expect("Failed to redeem");
+    }
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(challenge.solving, 2);
+    assert!(challenge.finished);
+    let challenger =
+        get_challenger(&mut context, &challenge, &challenger.pubkey()).await;
+    assert_eq!(challenger.solved_indexes(), vec![0, 1]);
+}
+
+#[tokio::test]
+async fn redeem_unsalted_solution() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge, challenger) =
+        challenge_with_challenger(&mut context, ID, vec!["hello"]).await;
+
+    // the solution as it is sent to a challenge without solution hashing
+    let unsalted = Challenge {
+        hashing: None,
+        ..get_challenge(&mut context, ID).await
+    };
+    let creator = context.payer.pubkey();
+    let ix = ixs::redeem_challenge(
+        creator,
+        &unsalted,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect("Failed to redeem");
+
+    let value = get_challenge(&mut context, ID).await;
+    assert_eq!(value.solving, 0);
+    let challenger =
+        get_challenger(&mut context, &challenge, &challenger.pubkey()).await;
+    assert!(!challenger.redeemed);
+    assert_eq!(challenger.tries_remaining, TRIES_PER_ADMIT - 1);
+}
+
+#[tokio::test]
+async fn redeem_replaying_solution_sent_to_other_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let (other, _) =
+        challenge_with_challenger(&mut context, OTHER_ID, vec!["hello"]).await;
+    let (challenge, challenger) =
+        challenge_with_challenger(&mut context, ID, vec!["hello"]).await;
+
+    // the solution as it was sent to the other challenge with the same salt and answer
+    let observed = hash_salted_solution_challenger_sends(
+        "hello",
+        &Default::default(),
+        &HASHING.salt,
+        &other.pda().0,
+        0,
+    );
+    let ix = redeem_sending_ix(&challenge, &challenger.pubkey(), observed);
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect("Failed to redeem");
+
+    let value = get_challenge(&mut context, ID).await;
+    assert_eq!(value.solving, 0);
+    let challenger =
+        get_challenger(&mut context, &challenge, &challenger.pubkey()).await;
+    assert!(!challenger.redeemed);
+}
+
+#[tokio::test]
+async fn redeem_replaying_solution_sent_for_other_index() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge, challenger) =
+        challenge_with_challenger(&mut context, ID, vec!["same", "same"]).await;
+    let challenge_pda = challenge.pda().0;
+
+    let sent = hash_salted_solution_challenger_sends(
+        "same",
+        &Default::default(),
+        &HASHING.salt,
+        &challenge_pda,
+        0,
+    );
+    for _ in 0..2 {
+        let ix = redeem_sending_ix(&challenge, &challenger.pubkey(), sent);
+        try_process_ix(&mut context, ix, &[&challenger])
+            .await
+            .expect("Failed to redeem");
+        // the second transaction is identical and thus needs a new blockhash
+        context.last_blockhash = context
+            .get_new_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+    }
+
+    let value = get_challenge(&mut context, ID).await;
+    assert_eq!(value.solving, 1, "only solves the first solution");
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    ixs::{self, AdmitChallengerIx, CreateChallengeOptions},
+    state::{Challenge, SolutionHashing, SolutionNormalization},
+    utils::{hash_solutions, normalize_solution},
+};
+
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+use crate::utils::{get_challenge, process_ix, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+const NORMALIZATION: SolutionNormalization = SolutionNormalization {
+    nfkc: true,
+    case_fold: true,
+    trim_whitespace: true,
+    collapse_whitespace: true,
+};
+
+/// Creates and starts a challenge with the provided options, admits a challenger and
+/// has them redeem the provided solution.
+async fn redeem_normalized_challenge(
+    context: &mut ProgramTestContext,
+    solution: &str,
+    options: CreateChallengeOptions,
+) -> Challenge {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_options(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["Café au lait"],
+        options,
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let challenge = get_challenge(context, ID).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        solution,
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[&challenger]).await;
+
+    get_challenge(context, ID).await
+}
+
+// -----------------
+// Normalize Solution
+// -----------------
+#[test]
+fn normalize_solution_applying_each_step() {
+    let solution = " \u{FB01}ne  Caf\u{0065}\u{0301}\t";
+    let cases = [
+        (SolutionNormalization::default(), solution.to_string()),
+        (
+            SolutionNormalization {
+                nfkc: true,
+                ..Default::default()
+            },
+            " fine  Café\t".to_string(),
+        ),
+        (
+            SolutionNormalization {
+                case_fold: true,
+                ..Default::default()
+            },
+            solution.to_lowercase(),
+        ),
+        (
+            SolutionNormalization {
+                trim_whitespace: true,
+                ..Default::default()
+            },
+            solution.trim().to_string(),
+        ),
+        (
+            SolutionNormalization {
+                collapse_whitespace: true,
+                ..Default::default()
+            },
+            "\u{FB01}ne Caf\u{0065}\u{0301}".to_string(),
+        ),
+        (NORMALIZATION, "fine café".to_string()),
+    ];
+
+    for (normalization, expected) in cases {
+        assert_eq!(
+            normalize_solution(solution, &normalization),
+            expected,
+            "{:?}",
+            normalization
+        );
+    }
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+#[tokio::test]
+async fn create_challenge_storing_normalization() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_options(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["Hello  World"],
+        CreateChallengeOptions {
+            normalization: NORMALIZATION,
+            ..Default::default()
+        },
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(challenge.normalization, NORMALIZATION);
+    assert_eq!(
+        challenge.solutions,
+        hash_solutions(&["hello world"], &Default::default())
+    );
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_differently_written_solution_with_normalization() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = redeem_normalized_challenge(
+        &mut context,
+        "  CAFE\u{0301}   au LAIT ",
+        CreateChallengeOptions {
+            normalization: NORMALIZATION,
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(challenge.solving, 1);
+}
+
+#[tokio::test]
+async fn redeem_differently_written_solution_with_normalization_and_hashing() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = redeem_normalized_challenge(
+        &mut context,
+        "café AU lait",
+        CreateChallengeOptions {
+            normalization: NORMALIZATION,
+            hashing: Some(SolutionHashing {
+                salt: [7; 32],
+                iterations: 10,
+            }),
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(challenge.solving, 1);
+}
+
+#[tokio::test]
+async fn redeem_differently_written_solution_without_normalization() {
+    let mut context = program_test().start_with_context().await;
+    let challenge = redeem_normalized_challenge(
+        &mut context,
+        "café AU lait",
+        CreateChallengeOptions::default(),
+    )
+    .await;
+    assert_eq!(challenge.solving, 0);
+}
@@ -0,0 +1,379 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx, CreateChallengeOptions},
+    state::{Challenge, Challenger, HasPda, SoulboundRedeem},
+};
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token_2022::{
+    extension::{
+        metadata_pointer::MetadataPointer, non_transferable::NonTransferable,
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    state::{Account as TokenAccount, Mint},
+};
+
+use crate::utils::{
+    get_challenge, get_deserialized, program_test, try_process_ix,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+
+fn create_soulbound_challenge_ix(
+    context: &ProgramTestContext,
+    soulbound: SoulboundRedeem,
+) -> Instruction {
+    let creator = context.payer.pubkey();
+    ixs::create_challenge_with_options(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+        CreateChallengeOptions {
+            soulbound: Some(soulbound),
+            ..Default::default()
+        },
+    )
+    .expect("failed to create instruction")
+}
+
+/// Creates and starts a challenge with a soulbound redeem mint and admits a challenger.
+async fn soulbound_challenge_with_challenger(
+    context: &mut ProgramTestContext,
+) -> (Challenge, Keypair) {
+    let creator = context.payer.pubkey();
+    let ix = create_soulbound_challenge_ix(context, SoulboundRedeem::default());
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to start challenge");
+
+    let challenge = get_challenge(context, ID).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    (challenge, challenger)
+}
+
+async fn get_token_account(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+) -> TokenAccount {
+    let acc = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("get_account")
+        .expect("token account not found");
+    assert_eq!(acc.owner, spl_token_2022::id());
+    StateWithExtensions::<TokenAccount>::unpack(&acc.data)
+        .expect("failed to unpack token account")
+        .base
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+#[tokio::test]
+async fn create_challenge_with_soulbound_redeem_mint() {
+    let mut context = program_test().start_with_context().await;
+    let metadata_address = Pubkey::new_unique();
+    let ix = create_soulbound_challenge_ix(
+        &context,
+        SoulboundRedeem {
+            metadata_address: Some(metadata_address),
+            revocable: false,
+        },
+    );
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert!(challenge.soulbound);
+    assert_eq!(challenge.redeem_token_program(), spl_token_2022::id());
+
+    let mint_acc = context
+        .banks_client
+        .get_account(challenge.redeem)
+        .await
+        .expect("get_account")
+        .expect("redeem mint not found");
+    assert_eq!(mint_acc.owner, spl_token_2022::id());
+
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_acc.data)
+        .expect("failed to unpack mint");
+    assert_eq!(
+        Option::<Pubkey>::from(mint.base.mint_authority),
+        Some(challenge.pda().0)
+    );
+    assert_eq!(mint.base.decimals, 0);
+    assert!(mint.get_extension::<NonTransferable>().is_ok());
+    let metadata_pointer = mint
+        .get_extension::<MetadataPointer>()
+        .expect("missing metadata pointer");
+    assert_eq!(
+        Option::<Pubkey>::from(metadata_pointer.authority),
+        Some(challenge.pda().0)
+    );
+    assert_eq!(
+        Option::<Pubkey>::from(metadata_pointer.metadata_address),
+        Some(metadata_address)
+    );
+}
+
+#[tokio::test]
+async fn create_challenge_with_soulbound_redeem_mint_without_metadata_pointer()
+{
+    let mut context = program_test().start_with_context().await;
+    let ix =
+        create_soulbound_challenge_ix(&context, SoulboundRedeem::default());
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let challenge = get_challenge(&mut context, ID).await;
+    let mint_acc = context
+        .banks_client
+        .get_account(challenge.redeem)
+        .await
+        .expect("get_account")
+        .expect("redeem mint not found");
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_acc.data)
+        .expect("failed to unpack mint");
+    assert!(mint.get_extension::<NonTransferable>().is_ok());
+    assert!(mint.get_extension::<MetadataPointer>().is_err());
+}
+
+#[tokio::test]
+async fn create_challenge_with_soulbound_redeem_mint_and_spl_token_program() {
+    let mut context = program_test().start_with_context().await;
+    let mut ix =
+        create_soulbound_challenge_ix(&context, SoulboundRedeem::default());
+    ix.accounts[4].pubkey = spl_token::id();
+
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectTokenProgram)
+    );
+}
+
+// -----------------
+// Redeem
+// -----------------
+#[tokio::test]
+async fn redeem_soulbound_token_that_cannot_be_transferred() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge, challenger) =
+        soulbound_challenge_with_challenger(&mut context).await;
+
+    let ix = ixs::redeem_challenge(
+        context.payer.pubkey(),
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect("Failed to redeem");
+
+    let challenger_ata = challenge.redeem_ata(&challenger.pubkey());
+    let token_account = get_token_account(&mut context, &challenger_ata).await;
+    assert_eq!(token_account.mint, challenge.redeem);
+    assert_eq!(token_account.owner, challenger.pubkey());
+    assert_eq!(token_account.amount, 1);
+
+    // Transferring the redeemed token fails
+    let recvr = Pubkey::new_unique();
+    let ix = create_associated_token_account(
+        &context.payer.pubkey(),
+        &recvr,
+        &challenge.redeem,
+        &spl_token_2022::id(),
+    );
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to create receiver ATA");
+
+    let recvr_ata = challenge.redeem_ata(&recvr);
+    let ix = spl_token_2022::instruction::transfer_checked(
+        &spl_token_2022::id(),
+        &challenger_ata,
+        &challenge.redeem,
+        &recvr_ata,
+        &challenger.pubkey(),
+        &[],
+        1,
+        0,
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect_err("Transfer should have failed");
+
+    let token_account = get_token_account(&mut context, &challenger_ata).await;
+    assert_eq!(token_account.amount, 1);
+    let token_account = get_token_account(&mut context, &recvr_ata).await;
+    assert_eq!(token_account.amount, 0);
+}
+
+#[tokio::test]
+async fn redeem_soulbound_token_passing_spl_token_program() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge, challenger) =
+        soulbound_challenge_with_challenger(&mut context).await;
+
+    // derives the redeem ATA for and passes the spl_token program
+    let not_soulbound = Challenge {
+        soulbound: false,
+        ..challenge
+    };
+    let creator = context.payer.pubkey();
+    let ix = ixs::redeem_challenge(
+        creator,
+        &not_soulbound,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect_err("Transaction should have failed");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectTokenProgram)
+    );
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(challenge.solving, 0);
+}
+
+// -----------------
+// Token Gated Admission
+// -----------------
+#[tokio::test]
+async fn admit_challenger_holding_soulbound_token_of_prerequisite_challenge() {
+    let mut context = program_test().start_with_context().await;
+    let (prerequisite, challenger) =
+        soulbound_challenge_with_challenger(&mut context).await;
+
+    let creator = context.payer.pubkey();
+    let ix = ixs::redeem_challenge(
+        creator,
+        &prerequisite,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect("Failed to redeem");
+
+    // Create a challenge that requires the soulbound token to be held
+    const GATED_ID: &str = "gated-challenge-id";
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        GATED_ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["world"],
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let ix = ixs::set_required_token(
+        creator,
+        creator,
+        GATED_ID.to_string(),
+        Some(prerequisite.redeem),
+        1,
+    )
+    .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to set required token");
+
+    let ix = ixs::start_challenge(creator, GATED_ID.to_string())
+        .expect("failed to create instruction");
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to start challenge");
+
+    let (gated_pda, _) = Challenge::pda_for(&creator, GATED_ID);
+    let gated = get_deserialized::<Challenge>(&mut context, &gated_pda)
+        .await
+        .1;
+
+    // A challenger that did not solve the prerequisite challenge is rejected
+    let other = Keypair::new();
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        creator,
+        &gated,
+        other.pubkey(),
+        Some(spl_token_2022::id()),
+    )
+    .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("should not admit challenger without soulbound token");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::IncorrectAccountOwner)
+    );
+
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger(
+        creator,
+        &gated,
+        challenger.pubkey(),
+        Some(spl_token_2022::id()),
+    )
+    .expect("failed to create instruction");
+    assert_eq!(
+        ix.accounts[6].pubkey,
+        prerequisite.redeem_ata(&challenger.pubkey())
+    );
+    try_process_ix(&mut context, ix, &[])
+        .await
+        .expect("Failed to admit challenger holding soulbound token");
+
+    let (_, admitted) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(admitted.authority, challenger.pubkey());
+    assert_eq!(admitted.tries_remaining, TRIES_PER_ADMIT);
+}
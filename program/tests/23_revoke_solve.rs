@@ -0,0 +1,320 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    error::ChallengeError,
+    ixs::{self, AdmitChallengerIx, CreateChallengeOptions},
+    state::{Challenge, HasPda, SoulboundRedeem},
+};
+
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use spl_token_2022::{
+    extension::{
+        permanent_delegate::PermanentDelegate, BaseStateWithExtensions,
+        StateWithExtensions,
+    },
+    state::{Account as TokenAccount, Mint},
+};
+
+use crate::utils::{
+    get_challenge, get_challenger, program_test, try_process_ix,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+const REVOCABLE: SoulboundRedeem = SoulboundRedeem {
+    metadata_address: None,
+    revocable: true,
+};
+
+async fn get_redeemed_amount(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    challenger: &Pubkey,
+) -> u64 {
+    let acc = context
+        .banks_client
+        .get_account(challenge.redeem_ata(challenger))
+        .await
+        .expect("get_account")
+        .expect("token account not found");
+    StateWithExtensions::<TokenAccount>::unpack(&acc.data)
+        .expect("failed to unpack token account")
+        .base
+        .amount
+}
+
+/// Creates and starts a challenge with a soulbound redeem mint.
+async fn start_soulbound_challenge(
+    context: &mut ProgramTestContext,
+    solutions: Vec<&str>,
+    soulbound: SoulboundRedeem,
+) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_options(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions,
+        CreateChallengeOptions {
+            soulbound: Some(soulbound),
+            ..Default::default()
+        },
+    )
+    .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to create challenge");
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to start challenge");
+}
+
+/// Admits a new challenger that redeems the provided solutions in order.
+async fn admit_and_solve(
+    context: &mut ProgramTestContext,
+    solutions: &[&str],
+) -> Keypair {
+    let challenge = get_challenge(context, ID).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } = ixs::admit_challenger(
+        context.payer.pubkey(),
+        &challenge,
+        challenger.pubkey(),
+        None,
+    )
+    .expect("failed to create instruction");
+    try_process_ix(context, ix, &[])
+        .await
+        .expect("Failed to admit challenger");
+
+    for solution in solutions {
+        let challenge = get_challenge(context, ID).await;
+        let ix = ixs::redeem_challenge(
+            context.payer.pubkey(),
+            &challenge,
+            challenger.pubkey(),
+            solution,
+            vec![],
+        )
+        .expect("failed to create instruction");
+        try_process_ix(context, ix, &[&challenger])
+            .await
+            .expect("Failed to redeem");
+    }
+    challenger
+}
+
+async fn revoke_solve(
+    context: &mut ProgramTestContext,
+    challenger: &Pubkey,
+    solution_index: u8,
+    reopen: bool,
+) -> Result<(), BanksClientError> {
+    let ix = ixs::revoke_solve(
+        context.payer.pubkey(),
+        ID.to_string(),
+        *challenger,
+        solution_index,
+        reopen,
+    )
+    .expect("failed to create instruction");
+    try_process_ix(context, ix, &[]).await
+}
+
+fn assert_challenge_error(err: BanksClientError, expected: ChallengeError) {
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(expected)
+    );
+}
+
+// -----------------
+// Create Challenge
+// -----------------
+#[tokio::test]
+async fn create_challenge_with_revocable_redeem_mint() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(&mut context, vec!["hello"], REVOCABLE).await;
+
+    let challenge = get_challenge(&mut context, ID).await;
+    let mint_acc = context
+        .banks_client
+        .get_account(challenge.redeem)
+        .await
+        .expect("get_account")
+        .expect("redeem mint not found");
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_acc.data)
+        .expect("failed to unpack mint");
+    let permanent_delegate = mint
+        .get_extension::<PermanentDelegate>()
+        .expect("missing permanent delegate");
+    assert_eq!(
+        Option::<Pubkey>::from(permanent_delegate.delegate),
+        Some(challenge.pda().0)
+    );
+}
+
+// -----------------
+// Revoke Solve
+// -----------------
+#[tokio::test]
+async fn revoke_solve_burning_redeemed_token() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(&mut context, vec!["hello", "world"], REVOCABLE)
+        .await;
+    let cheater = admit_and_solve(&mut context, &["hello"]).await;
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(
+        get_redeemed_amount(&mut context, &challenge, &cheater.pubkey()).await,
+        1
+    );
+
+    revoke_solve(&mut context, &cheater.pubkey(), 0, false)
+        .await
+        .expect("Failed to revoke solve");
+
+    assert_eq!(
+        get_redeemed_amount(&mut context, &challenge, &cheater.pubkey()).await,
+        0
+    );
+    let challenger =
+        get_challenger(&mut context, &challenge, &cheater.pubkey()).await;
+    assert!(!challenger.redeemed);
+    assert!(challenger.solved_indexes().is_empty());
+    assert_eq!(challenger.revoked_indexes(), vec![0]);
+
+    let value = get_challenge(&mut context, ID).await;
+    assert_eq!(value.solving, 1, "does not reopen the solution");
+}
+
+#[tokio::test]
+async fn revoke_solve_reopening_solution() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(&mut context, vec!["hello"], REVOCABLE).await;
+    let cheater = admit_and_solve(&mut context, &["hello"]).await;
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert!(challenge.finished);
+
+    revoke_solve(&mut context, &cheater.pubkey(), 0, true)
+        .await
+        .expect("Failed to revoke solve");
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(challenge.solving, 0);
+    assert!(!challenge.finished);
+
+    // The solution can be solved again
+    let solver = admit_and_solve(&mut context, &["hello"]).await;
+    let challenge = get_challenge(&mut context, ID).await;
+    assert!(challenge.finished);
+    let challenger =
+        get_challenger(&mut context, &challenge, &solver.pubkey()).await;
+    assert_eq!(challenger.solved_indexes(), vec![0]);
+}
+
+#[tokio::test]
+async fn revoke_solve_keeping_other_solves() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(
+        &mut context,
+        vec!["hello", "world", "!"],
+        REVOCABLE,
+    )
+    .await;
+    let cheater = admit_and_solve(&mut context, &["hello", "world"]).await;
+
+    revoke_solve(&mut context, &cheater.pubkey(), 1, true)
+        .await
+        .expect("Failed to revoke solve");
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(challenge.solving, 1);
+    assert_eq!(
+        get_redeemed_amount(&mut context, &challenge, &cheater.pubkey()).await,
+        1
+    );
+    let challenger =
+        get_challenger(&mut context, &challenge, &cheater.pubkey()).await;
+    assert!(challenger.redeemed);
+    assert_eq!(challenger.solved_indexes(), vec![0]);
+    assert_eq!(challenger.revoked_indexes(), vec![1]);
+}
+
+#[tokio::test]
+async fn revoke_solve_reopening_earlier_solution() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(&mut context, vec!["hello", "world"], REVOCABLE)
+        .await;
+    let cheater = admit_and_solve(&mut context, &["hello", "world"]).await;
+
+    let err = revoke_solve(&mut context, &cheater.pubkey(), 0, true)
+        .await
+        .expect_err("Transaction should have failed");
+    assert_challenge_error(err, ChallengeError::CannotReopenSolution);
+}
+
+#[tokio::test]
+async fn revoke_solve_not_solved_by_challenger() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(&mut context, vec!["hello", "world"], REVOCABLE)
+        .await;
+    let cheater = admit_and_solve(&mut context, &["hello"]).await;
+
+    let err = revoke_solve(&mut context, &cheater.pubkey(), 1, false)
+        .await
+        .expect_err("Transaction should have failed");
+    assert_challenge_error(err, ChallengeError::SolveNotFound);
+}
+
+#[tokio::test]
+async fn revoke_solve_twice() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(&mut context, vec!["hello", "world"], REVOCABLE)
+        .await;
+    let cheater = admit_and_solve(&mut context, &["hello"]).await;
+
+    revoke_solve(&mut context, &cheater.pubkey(), 0, false)
+        .await
+        .expect("Failed to revoke solve");
+    context.last_blockhash = context
+        .get_new_latest_blockhash()
+        .await
+        .expect("Failed to get blockhash");
+
+    let err = revoke_solve(&mut context, &cheater.pubkey(), 0, false)
+        .await
+        .expect_err("Transaction should have failed");
+    assert_challenge_error(err, ChallengeError::SolveNotFound);
+}
+
+#[tokio::test]
+async fn revoke_solve_of_non_revocable_redeem_mint() {
+    let mut context = program_test().start_with_context().await;
+    start_soulbound_challenge(
+        &mut context,
+        vec!["hello"],
+        SoulboundRedeem::default(),
+    )
+    .await;
+    let cheater = admit_and_solve(&mut context, &["hello"]).await;
+
+    let err = revoke_solve(&mut context, &cheater.pubkey(), 0, false)
+        .await
+        .expect_err("Transaction should have failed");
+    assert_challenge_error(err, ChallengeError::SolveNotRevocable);
+}
@@ -0,0 +1,338 @@
+#![cfg(all(feature = "test-sbf", feature = "client"))]
+
+use assert_matches::assert_matches;
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    client::{
+        challengers_filters, challenges_by_creator_filters,
+        is_solution_correct, ChallengeClient, ChallengeClientError,
+    },
+    state::{
+        Challenge, Challenger, HasPda, ProgramConfig, SolutionHashing,
+        SolutionNormalization,
+    },
+    utils::{hash_bound_solutions, hash_solutions},
+};
+use serde_json::{json, Value};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_client::{
+    rpc_client::{Mocks, RpcClient},
+    rpc_filter::RpcFilterType,
+    rpc_request::RpcRequest,
+    rpc_response::{Response, RpcKeyedAccount, RpcResponseContext},
+};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer};
+
+const ID: &str = "challenge-id";
+
+fn challenge_with_solutions(creator: Pubkey, solutions: &[&str]) -> Challenge {
+    Challenge {
+        authority: creator,
+        bump: Challenge::pda_for(&creator, ID).1,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: 200,
+        tries_per_admit: 3,
+        redeem: Pubkey::new_unique(),
+        solutions: hash_solutions(solutions, &Default::default()),
+        ..Default::default()
+    }
+}
+
+fn program_account<T: BorshSerialize>(value: &T, reserved: usize) -> Account {
+    let mut data = value.try_to_vec().expect("failed to serialize");
+    data.extend(vec![0; reserved]);
+    Account {
+        lamports: 1_000_000,
+        data,
+        owner: challenge_id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn ui_account(address: &Pubkey, account: &Account) -> UiAccount {
+    UiAccount::encode(address, account, UiAccountEncoding::Base64, None, None)
+}
+
+fn account_info_response(address: &Pubkey, account: &Account) -> Value {
+    json!(Response {
+        context: RpcResponseContext {
+            slot: 1,
+            api_version: None
+        },
+        value: Some(ui_account(address, account)),
+    })
+}
+
+fn program_accounts_response(accounts: &[(Pubkey, Account)]) -> Value {
+    json!(accounts
+        .iter()
+        .map(|(address, account)| RpcKeyedAccount {
+            pubkey: address.to_string(),
+            account: ui_account(address, account),
+        })
+        .collect::<Vec<_>>())
+}
+
+fn mock_client(mocks: Vec<(RpcRequest, Value)>) -> ChallengeClient {
+    let mocks: Mocks = mocks.into_iter().collect();
+    ChallengeClient::new(RpcClient::new_mock_with_mocks(
+        "succeeds".to_string(),
+        mocks,
+    ))
+}
+
+fn filters_match(filters: &[RpcFilterType], data: &[u8]) -> bool {
+    filters.iter().all(|filter| match filter {
+        RpcFilterType::DataSize(size) => data.len() as u64 == *size,
+        RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(data),
+        _ => unreachable!("unexpected filter"),
+    })
+}
+
+// -----------------
+// Challenge
+// -----------------
+#[test]
+fn get_challenge_decoding_account_with_reserved_space() {
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_solutions(creator, &["hello", "world"]);
+    let (challenge_pda, _) = challenge.pda();
+    let client = mock_client(vec![(
+        RpcRequest::GetAccountInfo,
+        account_info_response(&challenge_pda, &program_account(&challenge, 64)),
+    )]);
+
+    let value = client
+        .get_challenge_for(&creator, ID)
+        .expect("failed to get challenge");
+    assert_eq!(value.authority, creator);
+    assert_eq!(value.id, ID);
+    assert_eq!(value.solutions, challenge.solutions);
+}
+
+#[test]
+fn get_challenge_that_does_not_exist() {
+    let client = mock_client(vec![]);
+    let challenge_pda = Pubkey::new_unique();
+
+    let err = client
+        .get_challenge(&challenge_pda)
+        .expect_err("should not find challenge");
+    assert_matches!(err, ChallengeClientError::AccountNotFound(address) => {
+        assert_eq!(address, challenge_pda);
+    });
+}
+
+#[test]
+fn get_challenge_not_owned_by_program() {
+    let challenge = challenge_with_solutions(Pubkey::new_unique(), &["hello"]);
+    let (challenge_pda, _) = challenge.pda();
+    let mut account = program_account(&challenge, 0);
+    account.owner = Pubkey::new_unique();
+    let client = mock_client(vec![(
+        RpcRequest::GetAccountInfo,
+        account_info_response(&challenge_pda, &account),
+    )]);
+
+    let err = client
+        .get_challenge(&challenge_pda)
+        .expect_err("should reject account");
+    assert_matches!(err, ChallengeClientError::AccountNotOwnedByProgram(_));
+}
+
+#[test]
+fn find_challenges_by_creator_skipping_other_accounts() {
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_solutions(creator, &["hello"]);
+    let (challenge_pda, _) = challenge.pda();
+    let config = ProgramConfig {
+        admin: creator,
+        protocol_fee_basis_points: 0,
+        treasury: Pubkey::new_unique(),
+        ..Default::default()
+    };
+    let mut garbage = program_account(&challenge, 0);
+    garbage.data.truncate(40);
+
+    let client = mock_client(vec![(
+        RpcRequest::GetProgramAccounts,
+        program_accounts_response(&[
+            (challenge_pda, program_account(&challenge, 0)),
+            (ProgramConfig::pda().0, program_account(&config, 0)),
+            (Pubkey::new_unique(), garbage),
+        ]),
+    )]);
+
+    let challenges = client
+        .find_challenges_by_creator(&creator)
+        .expect("failed to find challenges");
+    assert_eq!(challenges.len(), 1);
+    assert_eq!(challenges[0].0, challenge_pda);
+    assert_eq!(challenges[0].1.id, ID);
+}
+
+#[test]
+fn challenges_by_creator_filters_match_authority() {
+    let creator = Pubkey::new_unique();
+    let challenge = challenge_with_solutions(creator, &["hello"]);
+    let data = challenge.try_to_vec().unwrap();
+
+    assert!(filters_match(
+        &challenges_by_creator_filters(&creator),
+        &data
+    ));
+    assert!(!filters_match(
+        &challenges_by_creator_filters(&Pubkey::new_unique()),
+        &data
+    ));
+}
+
+// -----------------
+// Challenger
+// -----------------
+#[test]
+fn get_challenger_of_wallet_that_was_not_admitted() {
+    let client = mock_client(vec![]);
+
+    let challenger = client
+        .get_challenger(&Pubkey::new_unique(), &Pubkey::new_unique())
+        .expect("failed to get challenger");
+    assert!(challenger.is_none());
+}
+
+#[test]
+fn get_challenger_of_admitted_wallet() {
+    let challenge_pda = Pubkey::new_unique();
+    let wallet = Pubkey::new_unique();
+    let challenger = Challenger::new(wallet, challenge_pda, 3);
+    let client = mock_client(vec![(
+        RpcRequest::GetAccountInfo,
+        account_info_response(
+            &challenger.pda().0,
+            &program_account(&challenger, 0),
+        ),
+    )]);
+
+    let value = client
+        .get_challenger(&challenge_pda, &wallet)
+        .expect("failed to get challenger")
+        .expect("challenger not found");
+    assert_eq!(value.authority, wallet);
+    assert_eq!(value.challenge_pda, challenge_pda);
+    assert_eq!(value.tries_remaining, 3);
+}
+
+#[test]
+fn find_challengers_of_challenge() {
+    let challenge_pda = Pubkey::new_unique();
+    let challengers = [
+        Challenger::new(Pubkey::new_unique(), challenge_pda, 1),
+        Challenger::new(Pubkey::new_unique(), challenge_pda, 2),
+    ];
+    let client = mock_client(vec![(
+        RpcRequest::GetProgramAccounts,
+        program_accounts_response(
+            &challengers
+                .iter()
+                .map(|c| (c.pda().0, program_account(c, 0)))
+                .collect::<Vec<_>>(),
+        ),
+    )]);
+
+    let found = client
+        .find_challengers(&challenge_pda)
+        .expect("failed to find challengers");
+    assert_eq!(found.len(), 2);
+    for ((address, challenger), expected) in found.iter().zip(&challengers) {
+        assert_eq!(address, &expected.pda().0);
+        assert_eq!(challenger.authority, expected.authority);
+        assert_eq!(challenger.tries_remaining, expected.tries_remaining);
+    }
+}
+
+#[test]
+fn challengers_filters_match_challenge_pda() {
+    let challenge_pda = Pubkey::new_unique();
+    let challenger = Challenger::new(Pubkey::new_unique(), challenge_pda, 1);
+    let data = challenger.try_to_vec().unwrap();
+
+    assert!(filters_match(&challengers_filters(&challenge_pda), &data));
+    assert!(!filters_match(
+        &challengers_filters(&Pubkey::new_unique()),
+        &data
+    ));
+
+    let challenge =
+        challenge_with_solutions(challenger.challenge_pda, &["hello"]);
+    assert!(!filters_match(
+        &challengers_filters(&challenge_pda),
+        &challenge.try_to_vec().unwrap()
+    ));
+}
+
+// -----------------
+// Solution Check
+// -----------------
+#[test]
+fn is_solution_correct_for_current_solution() {
+    let mut challenge =
+        challenge_with_solutions(Pubkey::new_unique(), &["hello", "world"]);
+
+    assert!(is_solution_correct(&challenge, "hello"));
+    assert!(!is_solution_correct(&challenge, "world"));
+
+    challenge.solving = 1;
+    assert!(is_solution_correct(&challenge, "world"));
+
+    challenge.solving = 2;
+    assert!(!is_solution_correct(&challenge, "world"));
+}
+
+#[test]
+fn is_solution_correct_with_normalization_and_hashing() {
+    let mut challenge = challenge_with_solutions(Pubkey::new_unique(), &[]);
+    let normalization = SolutionNormalization {
+        nfkc: true,
+        case_fold: true,
+        trim_whitespace: true,
+        collapse_whitespace: true,
+    };
+    let hashing = SolutionHashing {
+        salt: [7; 32],
+        iterations: 10,
+    };
+    challenge.solutions = hash_bound_solutions(
+        &["hello world"],
+        &normalization,
+        &challenge.pda().0,
+        &hashing,
+        0,
+    );
+    challenge.normalization = normalization;
+    challenge.hashing = Some(hashing);
+
+    assert!(is_solution_correct(&challenge, "  Hello   WORLD "));
+    assert!(!is_solution_correct(&challenge, "hello"));
+}
+
+// -----------------
+// Transactions
+// -----------------
+#[test]
+fn redeem_sending_transaction() {
+    let payer = Keypair::new();
+    let challenge = challenge_with_solutions(payer.pubkey(), &["hello"]);
+    let (challenge_pda, _) = challenge.pda();
+    let client = mock_client(vec![(
+        RpcRequest::GetAccountInfo,
+        account_info_response(&challenge_pda, &program_account(&challenge, 0)),
+    )]);
+
+    client
+        .redeem(&payer, &challenge_pda, &Keypair::new(), "hello")
+        .expect("failed to send redeem");
+}
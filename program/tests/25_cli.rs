@@ -0,0 +1,208 @@
+#![cfg(all(feature = "test-sbf", feature = "cli"))]
+
+use std::io::Cursor;
+
+use challenge::{
+    audit::{audit_solutions, AuditOptions},
+    cli::{
+        read_solutions, render_audit, render_challenge, render_challenger,
+        render_challenges, render_sent, OutputFormat,
+    },
+    ixs::{self, AdmitChallengerIx, CreateChallengeOptions},
+    state::{Challenger, HasPda, Pot},
+    utils::hex_string,
+};
+use serde_json::Value;
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::{
+    signature::{Keypair, Signature},
+    signer::Signer,
+};
+
+use crate::utils::{get_challenge, get_deserialized, process_ix, program_test};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+
+fn parse_json(s: &str) -> Value {
+    serde_json::from_str(s).expect("output should be valid JSON")
+}
+
+// -----------------
+// Solutions Input
+// -----------------
+#[test]
+fn read_solutions_one_per_line_skipping_empty_lines() {
+    let input = "hello\n\nhello world \r\nlast";
+    let solutions =
+        read_solutions(Cursor::new(input)).expect("failed to read solutions");
+    assert_eq!(solutions, vec!["hello", "hello world ", "last"]);
+}
+
+#[test]
+fn parse_output_format() {
+    assert_eq!("text".parse(), Ok(OutputFormat::Text));
+    assert_eq!("json".parse(), Ok(OutputFormat::Json));
+    assert!("yaml".parse::<OutputFormat>().is_err());
+}
+
+// -----------------
+// Rendering
+// -----------------
+#[test]
+fn render_sent_addresses_and_signatures() {
+    let challenge_pda = Pubkey::new_unique();
+    let signature = Signature::default();
+
+    let json = parse_json(&render_sent(
+        &[("challenge", challenge_pda)],
+        &[signature],
+        OutputFormat::Json,
+    ));
+    assert_eq!(json["challenge"], challenge_pda.to_string());
+    assert_eq!(json["signatures"][0], signature.to_string());
+
+    let text = render_sent(
+        &[("challenge", challenge_pda)],
+        &[signature],
+        OutputFormat::Text,
+    );
+    assert_eq!(
+        text,
+        format!(
+            "challenge:       {}\nsignature:       {}\n",
+            challenge_pda, signature
+        )
+    );
+}
+
+#[test]
+fn render_audit_without_leaking_solutions() {
+    let audits = audit_solutions(
+        &["zq", "Correct-Horse7 staple?Battery!xq2"],
+        &AuditOptions {
+            brute_force_max_len: 3,
+            brute_force_max_tries: 100_000,
+            ..Default::default()
+        },
+    );
+
+    let json: Value =
+        serde_json::from_str(&render_audit(&audits, OutputFormat::Json))
+            .expect("output should be valid JSON");
+    assert_eq!(json[0]["index"], 0);
+    assert_eq!(json[0]["weak"], true);
+    assert_eq!(json[1]["weak"], false);
+    assert_eq!(json[1]["findings"], serde_json::json!([]));
+
+    let text = render_audit(&audits, OutputFormat::Text);
+    let lines = text.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("0 weak"), "{}", lines[0]);
+    assert!(lines[0].contains("cracked instantly"), "{}", lines[0]);
+    assert!(lines[0].contains("brute forced after 693 tries"));
+    assert!(lines[1].starts_with("1 ok"), "{}", lines[1]);
+    assert!(lines[1].ends_with("cracked in more than a million years"));
+    assert!(!text.contains("zq") && !text.contains("Horse"));
+}
+
+// -----------------
+// Lifecycle
+// -----------------
+#[tokio::test]
+async fn lifecycle_rendering_challenge_and_challenger_state() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    // create, reading solutions the way the CLI does
+    let solutions = read_solutions(Cursor::new("hello\nworld\n")).unwrap();
+    let create_ixs = ixs::create_challenge_chunked(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        solutions.iter().map(String::as_str).collect(),
+        CreateChallengeOptions::default(),
+    )
+    .expect("failed to create instructions");
+    for ix in create_ixs {
+        process_ix(&mut context, ix, &[]).await;
+    }
+
+    // add-solutions
+    let challenge = get_challenge(&mut context, ID).await;
+    let ix = ixs::add_challenge_solutions(creator, &challenge, vec!["!"])
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    // start
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    // admit
+    let challenge = get_challenge(&mut context, ID).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx {
+        ix, challenger_pda, ..
+    } = ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    // redeem
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+
+    // show challenge
+    let challenge = get_challenge(&mut context, ID).await;
+    let json = parse_json(&render_challenge(&challenge, OutputFormat::Json));
+    assert_eq!(json["address"], challenge.pda().0.to_string());
+    assert_eq!(json["authority"], creator.to_string());
+    assert_eq!(json["id"], ID);
+    assert_eq!(json["started"], true);
+    assert_eq!(json["admit_cost"], ADMIT_COST);
+    assert_eq!(json["solving"], 1);
+    assert_eq!(json["solutions"].as_array().unwrap().len(), 3);
+    assert_eq!(json["solutions"][0], hex_string(&challenge.solutions[0]));
+    assert_eq!(json["remaining_solutions"], 2);
+    assert_eq!(json["pot"], Pot::new(challenge.pda().0).pda.to_string());
+    assert_eq!(json["hashing"], Value::Null);
+
+    let text = render_challenge(&challenge, OutputFormat::Text);
+    assert!(text.contains(&format!("id:              {}\n", ID)));
+    assert!(text.contains("solving:         1/3\n"));
+
+    // show challenger
+    let (_, value) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    let json = parse_json(&render_challenger(
+        &challenger_pda,
+        &value,
+        OutputFormat::Json,
+    ));
+    assert_eq!(json["address"], challenger_pda.to_string());
+    assert_eq!(json["authority"], challenger.pubkey().to_string());
+    assert_eq!(json["tries_remaining"], TRIES_PER_ADMIT - 1);
+    assert_eq!(json["redeemed"], true);
+    assert_eq!(json["solved_indexes"], serde_json::json!([0]));
+
+    // list
+    let challenges = vec![(challenge.pda().0, challenge)];
+    let json = parse_json(&render_challenges(&challenges, OutputFormat::Json));
+    assert_eq!(json.as_array().unwrap().len(), 1);
+    assert_eq!(json[0]["id"], ID);
+
+    let text = render_challenges(&challenges, OutputFormat::Text);
+    assert_eq!(text, format!("{} {} solving 1/3\n", challenges[0].0, ID));
+}
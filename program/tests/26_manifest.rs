@@ -0,0 +1,336 @@
+#![cfg(all(feature = "test-sbf", feature = "cli"))]
+
+use std::{fs, path::PathBuf};
+
+use challenge::{
+    ixs::{self, CreateChallengeOptions},
+    manifest::{
+        plan, Change, Conflict, Manifest, ManifestError, Plan, Schedule,
+    },
+    state::SolutionHashing,
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::*;
+use solana_sdk::signer::Signer;
+
+use crate::utils::{process_ix, program_test, try_get_challenge};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 3;
+const NOW: i64 = 1_700_000_000;
+
+fn manifest(start: bool, start_at: Option<i64>) -> Manifest {
+    Manifest {
+        id: ID.to_string(),
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        solutions_file: PathBuf::from("solutions.txt"),
+        normalize: false,
+        soulbound: false,
+        schedule: Schedule { start, start_at },
+    }
+}
+
+fn solutions(solutions: &[&str]) -> Vec<String> {
+    solutions.iter().map(|s| s.to_string()).collect()
+}
+
+async fn plan_manifest(
+    context: &mut ProgramTestContext,
+    manifest: &Manifest,
+    solutions: &[String],
+    now: i64,
+) -> Plan {
+    let creator = context.payer.pubkey();
+    let challenge = try_get_challenge(context, ID).await;
+    plan(
+        manifest,
+        solutions,
+        creator,
+        creator,
+        challenge.as_ref(),
+        now,
+    )
+    .expect("failed to plan")
+}
+
+async fn apply(context: &mut ProgramTestContext, plan: Plan) {
+    assert!(plan.conflicts.is_empty(), "{:?}", plan.conflicts);
+    for ix in plan.instructions {
+        process_ix(context, ix, &[]).await;
+    }
+}
+
+// -----------------
+// Parse
+// -----------------
+#[test]
+fn parse_toml_and_yaml_manifests() {
+    let toml = r#"
+id = "challenge-id"
+admit_cost = 200
+tries_per_admit = 3
+solutions_file = "solutions.txt"
+
+[schedule]
+start = true
+start_at = 1700000000
+"#;
+    let yaml = r#"
+id: challenge-id
+admit_cost: 200
+tries_per_admit: 3
+solutions_file: solutions.txt
+schedule:
+  start: true
+  start_at: 1700000000
+"#;
+    let expected = manifest(true, Some(NOW));
+    assert_eq!(Manifest::from_toml(toml).unwrap(), expected);
+    assert_eq!(Manifest::from_yaml(yaml).unwrap(), expected);
+}
+
+#[test]
+fn parse_manifest_with_unknown_field() {
+    let toml = r#"
+id = "challenge-id"
+admit_cost = 200
+tries_per_admit = 3
+solutions_file = "solutions.txt"
+admit_fee = 1
+"#;
+    assert!(matches!(
+        Manifest::from_toml(toml),
+        Err(ManifestError::Toml(_))
+    ));
+}
+
+#[test]
+fn load_manifest_resolving_solutions_file_relative_to_it() {
+    let dir = std::env::temp_dir().join(Pubkey::new_unique().to_string());
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("challenge.yml"),
+        "id: challenge-id\nadmit_cost: 200\ntries_per_admit: 3\nsolutions_file: solutions.txt\n",
+    )
+    .unwrap();
+    fs::write(dir.join("solutions.txt"), "hello\nworld\n").unwrap();
+    fs::write(dir.join("challenge.json"), "{}").unwrap();
+
+    let manifest = Manifest::from_path(dir.join("challenge.yml"))
+        .expect("failed to load manifest");
+    assert_eq!(manifest.solutions_file, dir.join("solutions.txt"));
+    assert_eq!(
+        manifest.read_solutions().unwrap(),
+        solutions(&["hello", "world"])
+    );
+
+    assert!(matches!(
+        Manifest::from_path(dir.join("challenge.json")),
+        Err(ManifestError::UnsupportedFormat(_))
+    ));
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+// -----------------
+// Plan / Apply
+// -----------------
+#[tokio::test]
+async fn plan_and_apply_new_challenge_repeatedly() {
+    let mut context = program_test().start_with_context().await;
+    let manifest = manifest(true, None);
+    let solutions = solutions(&["hello", "world"]);
+
+    let plan = plan_manifest(&mut context, &manifest, &solutions, NOW).await;
+    assert_eq!(
+        plan.changes,
+        vec![Change::Create { solutions: 2 }, Change::Start]
+    );
+    apply(&mut context, plan).await;
+
+    let challenge = try_get_challenge(&mut context, ID).await.unwrap();
+    assert!(challenge.started);
+    assert_eq!(challenge.admit_cost, ADMIT_COST);
+    assert_eq!(challenge.solutions.len(), 2);
+
+    let plan = plan_manifest(&mut context, &manifest, &solutions, NOW).await;
+    assert!(plan.is_empty());
+    assert!(plan.conflicts.is_empty());
+    assert!(plan.instructions.is_empty());
+}
+
+#[tokio::test]
+async fn plan_and_apply_adding_missing_solutions() {
+    let mut context = program_test().start_with_context().await;
+    let manifest = manifest(false, None);
+
+    let plan = plan_manifest(
+        &mut context,
+        &manifest,
+        &solutions(&["hello", "world"]),
+        NOW,
+    )
+    .await;
+    apply(&mut context, plan).await;
+
+    let all_solutions: Vec<String> =
+        (0..25).map(|x| format!("solution {}", x)).collect();
+    let mut with_first = solutions(&["hello", "world"]);
+    with_first.extend(all_solutions);
+
+    let plan = plan_manifest(&mut context, &manifest, &with_first, NOW).await;
+    assert_eq!(
+        plan.changes,
+        vec![Change::AddSolutions {
+            from_index: 2,
+            count: 25
+        }]
+    );
+    assert_eq!(plan.instructions.len(), 2, "adds solutions in chunks");
+    apply(&mut context, plan).await;
+
+    let challenge = try_get_challenge(&mut context, ID).await.unwrap();
+    assert_eq!(challenge.solutions.len(), 27);
+    assert!(!challenge.started);
+
+    let plan = plan_manifest(&mut context, &manifest, &with_first, NOW).await;
+    assert!(plan.is_empty());
+}
+
+#[tokio::test]
+async fn plan_deferring_scheduled_start() {
+    let mut context = program_test().start_with_context().await;
+    let manifest = manifest(true, Some(NOW + 60));
+    let solutions = solutions(&["hello"]);
+
+    let plan = plan_manifest(&mut context, &manifest, &solutions, NOW).await;
+    assert_eq!(plan.changes, vec![Change::Create { solutions: 1 }]);
+    assert_eq!(plan.deferred_start, Some(NOW + 60));
+    apply(&mut context, plan).await;
+
+    let plan = plan_manifest(&mut context, &manifest, &solutions, NOW).await;
+    assert!(plan.is_empty());
+    assert_eq!(plan.deferred_start, Some(NOW + 60));
+
+    let plan =
+        plan_manifest(&mut context, &manifest, &solutions, NOW + 60).await;
+    assert_eq!(plan.changes, vec![Change::Start]);
+    apply(&mut context, plan).await;
+
+    assert!(try_get_challenge(&mut context, ID).await.unwrap().started);
+}
+
+#[tokio::test]
+async fn plan_comparing_solutions_of_challenge_with_hashing() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge_with_options(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+        CreateChallengeOptions {
+            hashing: Some(SolutionHashing {
+                salt: [7; 32],
+                iterations: 10,
+            }),
+            ..Default::default()
+        },
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let manifest = manifest(false, None);
+    let plan = plan_manifest(
+        &mut context,
+        &manifest,
+        &solutions(&["hello", "world", "!"]),
+        NOW,
+    )
+    .await;
+    assert!(plan.conflicts.is_empty(), "{:?}", plan.conflicts);
+    assert_eq!(
+        plan.changes,
+        vec![Change::AddSolutions {
+            from_index: 2,
+            count: 1
+        }]
+    );
+    apply(&mut context, plan).await;
+
+    let challenge = try_get_challenge(&mut context, ID).await.unwrap();
+    assert_eq!(challenge.solutions.len(), 3);
+    let plan = plan_manifest(
+        &mut context,
+        &manifest,
+        &solutions(&["hello", "world", "!"]),
+        NOW,
+    )
+    .await;
+    assert!(plan.is_empty());
+    assert!(plan.conflicts.is_empty(), "{:?}", plan.conflicts);
+}
+
+#[tokio::test]
+async fn plan_reporting_conflicts() {
+    let mut context = program_test().start_with_context().await;
+    let plan = plan_manifest(
+        &mut context,
+        &manifest(false, None),
+        &solutions(&["hello", "world"]),
+        NOW,
+    )
+    .await;
+    apply(&mut context, plan).await;
+
+    let changed = Manifest {
+        admit_cost: ADMIT_COST + 1,
+        tries_per_admit: TRIES_PER_ADMIT + 1,
+        normalize: true,
+        ..manifest(false, None)
+    };
+    let plan = plan_manifest(
+        &mut context,
+        &changed,
+        &solutions(&["hello", "WORLD"]),
+        NOW,
+    )
+    .await;
+    assert!(plan.is_empty());
+    assert_eq!(
+        plan.conflicts,
+        vec![
+            Conflict::AdmitCost {
+                manifest: ADMIT_COST + 1,
+                on_chain: ADMIT_COST
+            },
+            Conflict::TriesPerAdmit {
+                manifest: TRIES_PER_ADMIT + 1,
+                on_chain: TRIES_PER_ADMIT
+            },
+            Conflict::Normalization,
+            Conflict::SolutionMismatch { index: 1 },
+        ]
+    );
+
+    let plan = plan_manifest(
+        &mut context,
+        &manifest(false, None),
+        &solutions(&["hello"]),
+        NOW,
+    )
+    .await;
+    assert_eq!(
+        plan.conflicts,
+        vec![Conflict::MissingSolutions {
+            manifest: 1,
+            on_chain: 2
+        }]
+    );
+}
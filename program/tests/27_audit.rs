@@ -0,0 +1,146 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    audit::{
+        audit_solution, audit_solutions, AuditOptions, Finding, Pattern,
+        SolutionAudit,
+    },
+    state::SolutionNormalization,
+};
+
+fn options() -> AuditOptions {
+    AuditOptions {
+        brute_force_max_len: 3,
+        brute_force_max_tries: 100_000,
+        ..Default::default()
+    }
+}
+
+fn has_finding(audit: &SolutionAudit, finding: &Finding) -> bool {
+    audit.findings.iter().any(|f| f == finding)
+}
+
+// -----------------
+// Findings
+// -----------------
+#[test]
+fn audit_strong_solution() {
+    let audit = audit_solution("Correct-Horse7 staple?Battery!xq2", &options());
+    assert!(!audit.is_weak(), "{:?}", audit);
+    assert!(audit.findings.is_empty(), "{:?}", audit.findings);
+    assert!(audit.entropy_bits > 100.0);
+}
+
+#[test]
+fn audit_short_solution_brute_forced() {
+    let audit = audit_solution("zq", &options());
+    assert!(audit.is_weak());
+    // all single characters followed by 'a'..'z' and 'za'..'zq'
+    assert!(has_finding(
+        &audit,
+        &Finding::BruteForced {
+            tries: 26 + 25 * 26 + 17
+        }
+    ));
+    assert!(matches!(audit.findings[0], Finding::LowEntropy { .. }));
+}
+
+#[test]
+fn audit_brute_force_honors_normalization() {
+    let options = AuditOptions {
+        normalization: SolutionNormalization {
+            case_fold: true,
+            trim_whitespace: true,
+            ..Default::default()
+        },
+        ..options()
+    };
+    let audit = audit_solution("  AB ", &options);
+    assert!(has_finding(&audit, &Finding::BruteForced { tries: 26 + 2 }));
+}
+
+#[test]
+fn audit_brute_force_bounded_by_tries() {
+    let options = AuditOptions {
+        brute_force_max_tries: 10,
+        ..options()
+    };
+    let audit = audit_solution("zz", &options);
+    assert!(!audit
+        .findings
+        .iter()
+        .any(|f| matches!(f, Finding::BruteForced { .. })));
+    assert!(audit.is_weak(), "still weak due to low entropy");
+}
+
+#[test]
+fn audit_whitespace_solution_without_normalization() {
+    let options = AuditOptions {
+        normalization: SolutionNormalization::default(),
+        ..options()
+    };
+    let audit = audit_solution("\t", &options);
+    assert!(audit.is_weak());
+    assert!(!audit
+        .findings
+        .iter()
+        .any(|f| matches!(f, Finding::BruteForced { .. })));
+}
+
+#[test]
+fn audit_dictionary_solutions() {
+    let audit = audit_solution("sunshine", &options());
+    assert!(audit.is_weak());
+    assert!(has_finding(&audit, &Finding::CommonPassword));
+
+    let audit = audit_solution("Treasure Map 42", &options());
+    assert!(audit.is_weak());
+    assert!(has_finding(&audit, &Finding::DictionaryWords { words: 2 }));
+    assert!(audit.entropy_bits < 30.0);
+}
+
+#[test]
+fn audit_patterns() {
+    for (solution, pattern) in [
+        ("xyxyxyxyxyxy", Pattern::Repeated),
+        ("abcdefghijk", Pattern::Sequence),
+        ("987654321", Pattern::Sequence),
+        ("asdfghjk", Pattern::Keyboard),
+        ("19991231", Pattern::Date),
+    ] {
+        let audit = audit_solution(solution, &options());
+        assert!(
+            has_finding(&audit, &Finding::Pattern(pattern)),
+            "{} {:?}",
+            solution,
+            audit.findings
+        );
+        assert!(audit.is_weak(), "{}", solution);
+    }
+}
+
+#[test]
+fn audit_duplicates_and_empty_solutions() {
+    let options = AuditOptions {
+        normalization: SolutionNormalization {
+            case_fold: true,
+            trim_whitespace: true,
+            ..Default::default()
+        },
+        ..options()
+    };
+    let audits = audit_solutions(
+        &[
+            "Correct-Horse7 staple?Battery!xq2",
+            "   ",
+            " correct-horse7 STAPLE?battery!xq2",
+        ],
+        &options,
+    );
+    assert_eq!(audits[0].index, 0);
+    assert!(!audits[0].is_weak());
+    assert_eq!(audits[1].findings, vec![Finding::Empty]);
+    assert_eq!(audits[2].index, 2);
+    assert_eq!(audits[2].findings, vec![Finding::Duplicate { index: 0 }]);
+    assert!(audits[2].is_weak());
+}
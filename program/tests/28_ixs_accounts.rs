@@ -0,0 +1,533 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshDeserialize;
+use challenge::{
+    challenge_id,
+    ixs::{self, ChallengeInstruction, ClaimRefundAccounts, RedeemAccounts},
+    program_data_address,
+    state::{Challenge, Challenger, FeeRecipient, Pot, ProgramConfig, Redeem},
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, get_associated_token_address_with_program_id,
+};
+
+const ID: &str = "challenge-id";
+
+struct Pdas {
+    challenge: Pubkey,
+    challenger: Pubkey,
+    redeem: Pubkey,
+    pot: Pubkey,
+    program_config: Pubkey,
+}
+
+fn pdas(creator: &Pubkey, challenger: &Pubkey) -> Pdas {
+    let (challenge, _) = Challenge::pda_for(creator, ID);
+    Pdas {
+        challenge,
+        challenger: Challenger::shank_pda(
+            &challenge_id(),
+            &challenge,
+            challenger,
+        )
+        .0,
+        redeem: Redeem::new(challenge).pda,
+        pot: Pot::new(challenge).pda,
+        program_config: ProgramConfig::pda().0,
+    }
+}
+
+fn challenge(creator: Pubkey) -> Challenge {
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Redeem::new(Challenge::pda_for(&creator, ID).0).pda,
+        ..Default::default()
+    }
+}
+
+/// Asserts that the [ix] has exactly the named [accounts] and that each account declared
+/// via `#[account]` is passed at its declared index with the declared flags, or as the
+/// program id if it is optional and not provided.
+/// Accounts that are not declared, i.e. fee recipients, need to follow the declared ones
+/// in the given order and are only checked by pubkey.
+fn assert_accounts_match_attrs(ix: &Instruction, accounts: &[(&str, Pubkey)]) {
+    let data = ChallengeInstruction::try_from_slice(&ix.data)
+        .expect("failed to decode instruction");
+    let attrs = data.account_attrs();
+    assert!(
+        ix.accounts.len() >= attrs.len(),
+        "{:?} is missing declared accounts",
+        data
+    );
+
+    for (meta, attr) in ix.accounts.iter().zip(attrs) {
+        match accounts.iter().find(|(name, _)| *name == attr.name) {
+            Some((_, pubkey)) => {
+                assert_eq!(
+                    (meta.pubkey, meta.is_writable, meta.is_signer),
+                    (*pubkey, attr.writable, attr.signer),
+                    "{:?} account '{}' at index {}",
+                    data,
+                    attr.name,
+                    attr.index
+                );
+            }
+            None => {
+                assert!(
+                    attr.optional,
+                    "{:?} is missing account '{}'",
+                    data, attr.name
+                );
+                assert_eq!(
+                    meta,
+                    &AccountMeta::new_readonly(challenge_id(), false),
+                    "{:?} account '{}' should be passed as the program id",
+                    data,
+                    attr.name
+                );
+            }
+        }
+    }
+
+    let remaining = accounts
+        .iter()
+        .filter(|(name, _)| attrs.iter().all(|attr| attr.name != *name))
+        .map(|(_, pubkey)| *pubkey)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ix.accounts[attrs.len()..]
+            .iter()
+            .map(|x| x.pubkey)
+            .collect::<Vec<_>>(),
+        remaining,
+        "{:?} accounts following the declared ones",
+        data
+    );
+}
+
+// -----------------
+// Attributes
+// -----------------
+#[test]
+fn every_instruction_declares_program_config_last() {
+    let instructions = [
+        ixs::start_challenge(Pubkey::new_unique(), ID.to_string()).unwrap(),
+        ixs::init_config(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+            Pubkey::new_unique(),
+        )
+        .unwrap(),
+        ixs::claim_refund(Pubkey::new_unique(), ID, Pubkey::new_unique())
+            .unwrap(),
+    ];
+    for ix in instructions {
+        let data = ChallengeInstruction::try_from_slice(&ix.data).unwrap();
+        let attrs = data.account_attrs();
+        assert_eq!(attrs.last().unwrap().name, "program_config");
+        assert!(attrs.iter().enumerate().all(|(idx, x)| x.index == idx));
+    }
+}
+
+#[test]
+fn generated_accounts_pass_program_id_for_missing_optional_accounts() {
+    let accounts = RedeemAccounts {
+        payer: Pubkey::new_unique(),
+        challenge_pda: Pubkey::new_unique(),
+        challenger: Pubkey::new_unique(),
+        challenger_pda: Pubkey::new_unique(),
+        redeem: Pubkey::new_unique(),
+        redeem_ata: Pubkey::new_unique(),
+        token_program: spl_token::id(),
+        associated_token_program: spl_associated_token_account::id(),
+        system_program: system_program::id(),
+        pot: None,
+        hook_program: None,
+        program_config: ProgramConfig::pda().0,
+    };
+    let metas = accounts.to_account_metas();
+    assert_eq!(metas.len(), RedeemAccounts::ATTRS.len());
+    assert_eq!(metas[0], AccountMeta::new(accounts.payer, true));
+    for idx in [9, 10] {
+        assert!(RedeemAccounts::ATTRS[idx].optional);
+        assert_eq!(
+            metas[idx],
+            AccountMeta::new_readonly(challenge_id(), false)
+        );
+    }
+    assert_eq!(
+        metas.last().unwrap(),
+        &AccountMeta::new_readonly(accounts.program_config, false)
+    );
+
+    let ix = ClaimRefundAccounts {
+        challenge_pda: Pubkey::new_unique(),
+        challenger: Pubkey::new_unique(),
+        challenger_pda: Pubkey::new_unique(),
+        pot: Pubkey::new_unique(),
+        program_config: ProgramConfig::pda().0,
+    }
+    .instruction()
+    .unwrap();
+    assert_eq!(ix.program_id, challenge_id());
+    assert!(matches!(
+        ChallengeInstruction::try_from_slice(&ix.data).unwrap(),
+        ChallengeInstruction::ClaimRefund
+    ));
+}
+
+// -----------------
+// Builders
+// -----------------
+#[test]
+fn create_and_add_solutions_accounts() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let p = pdas(&creator, &Pubkey::new_unique());
+
+    let ix = ixs::create_challenge(
+        payer,
+        creator,
+        ID.to_string(),
+        200,
+        1,
+        vec!["hello"],
+    )
+    .unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[
+            ("payer", payer),
+            ("creator", creator),
+            ("challenge_pda", p.challenge),
+            ("redeem_pda", p.redeem),
+            ("token_program", spl_token::id()),
+            ("system_program", system_program::id()),
+            ("program_config", p.program_config),
+        ],
+    );
+
+    let ix =
+        ixs::add_challenge_solutions(payer, &challenge(creator), vec!["world"])
+            .unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[
+            ("payer", payer),
+            ("creator", creator),
+            ("challenge_pda", p.challenge),
+            ("system_program", system_program::id()),
+            ("program_config", p.program_config),
+        ],
+    );
+}
+
+#[test]
+fn creator_settings_accounts() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let p = pdas(&creator, &Pubkey::new_unique());
+    let settings = [
+        ixs::set_admission_root(payer, creator, ID.to_string(), None).unwrap(),
+        ixs::set_required_token(payer, creator, ID.to_string(), None, 0)
+            .unwrap(),
+        ixs::set_hook_program(payer, creator, ID.to_string(), None).unwrap(),
+    ];
+    for ix in settings {
+        assert_accounts_match_attrs(
+            &ix,
+            &[
+                ("payer", payer),
+                ("creator", creator),
+                ("challenge_pda", p.challenge),
+                ("system_program", system_program::id()),
+                ("program_config", p.program_config),
+            ],
+        );
+    }
+
+    let pots = [
+        ixs::set_jackpot(payer, creator, ID.to_string(), Some(100)).unwrap(),
+        ixs::set_escrow(payer, creator, ID.to_string(), true).unwrap(),
+    ];
+    for ix in pots {
+        assert_accounts_match_attrs(
+            &ix,
+            &[
+                ("payer", payer),
+                ("creator", creator),
+                ("challenge_pda", p.challenge),
+                ("pot", p.pot),
+                ("system_program", system_program::id()),
+                ("program_config", p.program_config),
+            ],
+        );
+    }
+
+    for ix in [
+        ixs::start_challenge(creator, ID.to_string()).unwrap(),
+        ixs::cancel_challenge(creator, ID.to_string()).unwrap(),
+    ] {
+        assert_accounts_match_attrs(
+            &ix,
+            &[
+                ("creator", creator),
+                ("challenge_pda", p.challenge),
+                ("program_config", p.program_config),
+            ],
+        );
+    }
+
+    let ix = ixs::withdraw_escrow(creator, ID.to_string()).unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[
+            ("creator", creator),
+            ("challenge_pda", p.challenge),
+            ("pot", p.pot),
+            ("program_config", p.program_config),
+        ],
+    );
+}
+
+#[test]
+fn admit_challenger_accounts() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let p = pdas(&creator, &challenger);
+    let base = [
+        ("payer", payer),
+        ("creator", creator),
+        ("challenge_pda", p.challenge),
+        ("challenger", challenger),
+        ("challenger_pda", p.challenger),
+        ("system_program", system_program::id()),
+    ];
+
+    let ix =
+        ixs::admit_challenger(payer, &challenge(creator), challenger, None)
+            .unwrap()
+            .ix;
+    let mut expected = base.to_vec();
+    expected.push(("program_config", p.program_config));
+    assert_accounts_match_attrs(&ix, &expected);
+
+    // required token, fee recipients and protocol fee
+    let mint = Pubkey::new_unique();
+    let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let treasury = Pubkey::new_unique();
+    let challenge = Challenge {
+        required_mint: Some(mint),
+        required_amount: 1,
+        fee_recipients: recipients
+            .iter()
+            .map(|recipient| FeeRecipient {
+                recipient: *recipient,
+                basis_points: 5_000,
+            })
+            .collect(),
+        ..challenge(creator)
+    };
+    let ix = ixs::admit_challenger(
+        payer,
+        &challenge,
+        challenger,
+        Some(spl_token::id()),
+    )
+    .unwrap()
+    .with_protocol_fee(&ProgramConfig {
+        admin: Pubkey::new_unique(),
+        protocol_fee_basis_points: 100,
+        treasury,
+        ..Default::default()
+    })
+    .ix;
+    let mut expected = base.to_vec();
+    expected.extend([
+        (
+            "challenger_token_account",
+            get_associated_token_address(&challenger, &mint),
+        ),
+        ("treasury", treasury),
+        ("program_config", p.program_config),
+        ("fee_recipient", recipients[0]),
+        ("fee_recipient", recipients[1]),
+    ]);
+    assert_accounts_match_attrs(&ix, &expected);
+
+    // the token account of a required Token-2022 mint, i.e. a soulbound redeem mint
+    assert!(ixs::admit_challenger(payer, &challenge, challenger, None).is_err());
+
+    // escrowing admit fees in the pot
+    let challenge = Challenge {
+        escrow: true,
+        ..challenge
+    };
+    let ix = ixs::admit_challenger(
+        payer,
+        &challenge,
+        challenger,
+        Some(spl_token_2022::id()),
+    )
+    .unwrap()
+    .ix;
+    let mut expected = base.to_vec();
+    expected.extend([
+        (
+            "challenger_token_account",
+            get_associated_token_address_with_program_id(
+                &challenger,
+                &mint,
+                &spl_token_2022::id(),
+            ),
+        ),
+        ("program_config", p.program_config),
+        ("escrow_pot", p.pot),
+    ]);
+    assert_accounts_match_attrs(&ix, &expected);
+}
+
+#[test]
+fn redeem_accounts() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let p = pdas(&creator, &challenger);
+    let base = [
+        ("payer", payer),
+        ("challenge_pda", p.challenge),
+        ("challenger", challenger),
+        ("challenger_pda", p.challenger),
+        ("redeem", p.redeem),
+        (
+            "redeem_ata",
+            get_associated_token_address(&challenger, &p.redeem),
+        ),
+        ("token_program", spl_token::id()),
+        (
+            "associated_token_program",
+            spl_associated_token_account::id(),
+        ),
+        ("system_program", system_program::id()),
+        ("pot", p.pot),
+    ];
+
+    let ix = ixs::redeem_challenge(
+        payer,
+        &challenge(creator),
+        challenger,
+        "hello",
+        vec![],
+    )
+    .unwrap();
+    let mut expected = base.to_vec();
+    expected.push(("program_config", p.program_config));
+    assert_accounts_match_attrs(&ix, &expected);
+
+    let hook_program = Pubkey::new_unique();
+    let hook_account = Pubkey::new_unique();
+    let ix = ixs::redeem_challenge(
+        payer,
+        &Challenge {
+            hook_program: Some(hook_program),
+            ..challenge(creator)
+        },
+        challenger,
+        "hello",
+        vec![AccountMeta::new(hook_account, false)],
+    )
+    .unwrap();
+    let mut expected = base.to_vec();
+    expected.extend([
+        ("hook_program", hook_program),
+        ("program_config", p.program_config),
+        ("hook_account", hook_account),
+    ]);
+    assert_accounts_match_attrs(&ix, &expected);
+}
+
+#[test]
+fn challenger_and_revoke_accounts() {
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let p = pdas(&creator, &challenger);
+
+    let ix = ixs::claim_refund(creator, ID, challenger).unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[
+            ("challenge_pda", p.challenge),
+            ("challenger", challenger),
+            ("challenger_pda", p.challenger),
+            ("pot", p.pot),
+            ("program_config", p.program_config),
+        ],
+    );
+
+    let token_program = Redeem::token_program(true);
+    let ix = ixs::revoke_solve(creator, ID.to_string(), challenger, 0, false)
+        .unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[
+            ("creator", creator),
+            ("challenge_pda", p.challenge),
+            ("challenger", challenger),
+            ("challenger_pda", p.challenger),
+            ("redeem", p.redeem),
+            (
+                "redeem_ata",
+                Redeem::new(p.challenge)
+                    .ata_with_program_id(&challenger, &token_program),
+            ),
+            ("token_program", token_program),
+            ("program_config", p.program_config),
+        ],
+    );
+}
+
+#[test]
+fn program_config_accounts() {
+    let payer = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let program_config = ProgramConfig::pda().0;
+
+    let ix = ixs::init_config(payer, admin, 100, Pubkey::new_unique()).unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[
+            ("payer", payer),
+            ("admin", admin),
+            ("program_data", program_data_address()),
+            ("system_program", system_program::id()),
+            ("program_config", program_config),
+        ],
+    );
+
+    let ix = ixs::update_config(
+        admin,
+        &ProgramConfig {
+            admin,
+            protocol_fee_basis_points: 0,
+            treasury: Pubkey::new_unique(),
+            paused: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_accounts_match_attrs(
+        &ix,
+        &[("admin", admin), ("program_config", program_config)],
+    );
+}
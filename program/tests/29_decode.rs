@@ -0,0 +1,434 @@
+#![cfg(feature = "test-sbf")]
+
+use challenge::{
+    challenge_id,
+    decode::{
+        decode_instruction, decode_ix, decode_transaction, DecodeError,
+        DecodedInstruction, InnerInstructions,
+    },
+    ixs::{self, ChallengeInstruction},
+    state::{
+        Challenge, Challenger, FeeRecipient, HasPda, Pot, ProgramConfig, Redeem,
+    },
+    utils::{hash_solution_challenger_sends, hex_string},
+};
+use solana_program::{
+    instruction::{AccountMeta, CompiledInstruction},
+    message::Message,
+    pubkey::Pubkey,
+    system_instruction,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+const ID: &str = "challenge-id";
+
+fn challenge(creator: Pubkey) -> Challenge {
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: 200,
+        tries_per_admit: 1,
+        redeem: Redeem::new(challenge_pda).pda,
+        ..Default::default()
+    }
+}
+
+fn account_names(decoded: &DecodedInstruction) -> Vec<&str> {
+    decoded.accounts.iter().map(|acc| acc.name).collect()
+}
+
+// -----------------
+// Instructions
+// -----------------
+#[test]
+fn decode_redeem_with_named_accounts_and_hex_solution() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let ix = ixs::redeem_challenge(
+        payer,
+        &challenge(creator),
+        challenger,
+        "hello",
+        vec![],
+    )
+    .unwrap();
+
+    let decoded = decode_ix(&ix).expect("failed to decode");
+    assert_eq!(decoded.name(), "Redeem");
+    assert!(matches!(
+        decoded.instruction,
+        ChallengeInstruction::Redeem { .. }
+    ));
+
+    let solution = hash_solution_challenger_sends("hello", &Default::default());
+    assert_eq!(decoded.args, vec![("solution", hex_string(&solution))]);
+    assert_eq!(hex_string(&[0x0a, 0xff]), "0aff");
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    assert_eq!(decoded.account("payer"), Some(payer));
+    assert_eq!(decoded.account("challenge_pda"), Some(challenge_pda));
+    assert_eq!(
+        decoded.account("challenger_pda"),
+        Some(
+            Challenger::shank_pda(&challenge_id(), &challenge_pda, &challenger)
+                .0
+        )
+    );
+    assert_eq!(decoded.account("pot"), Some(Pot::new(challenge_pda).pda));
+    assert_eq!(
+        decoded.account("program_config"),
+        Some(ProgramConfig::pda().0)
+    );
+
+    let challenger = &decoded.accounts[2];
+    assert_eq!(challenger.name, "challenger");
+    assert!(challenger.signer && challenger.writable);
+
+    let text = decoded.to_string();
+    assert!(text.starts_with("Redeem\n"), "{}", text);
+    assert!(text.contains(&hex_string(&solution)));
+    assert!(text.contains(&format!("{} mut sig", payer)));
+}
+
+#[test]
+fn decode_admit_naming_fee_recipients_and_pot() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let challenge = Challenge {
+        fee_recipients: recipients
+            .iter()
+            .map(|recipient| FeeRecipient {
+                recipient: *recipient,
+                basis_points: 5_000,
+            })
+            .collect(),
+        ..challenge(creator)
+    };
+    let ix =
+        ixs::admit_challenger(payer, &challenge, Pubkey::new_unique(), None)
+            .unwrap()
+            .ix;
+
+    let decoded = decode_ix(&ix).unwrap();
+    assert_eq!(
+        account_names(&decoded),
+        [
+            "payer",
+            "creator",
+            "challenge_pda",
+            "challenger",
+            "challenger_pda",
+            "system_program",
+            "challenger_token_account",
+            "treasury",
+            "program_config",
+            "fee_recipient",
+            "fee_recipient"
+        ]
+    );
+    assert_eq!(decoded.accounts[9].pubkey, recipients[0]);
+    assert_eq!(decoded.accounts[10].pubkey, recipients[1]);
+
+    // escrowed admit fees go to the pot which is recognized by its address
+    let challenge = Challenge {
+        escrow: true,
+        fee_recipients: vec![],
+        ..challenge
+    };
+    let ix =
+        ixs::admit_challenger(payer, &challenge, Pubkey::new_unique(), None)
+            .unwrap()
+            .ix;
+    let decoded = decode_ix(&ix).unwrap();
+    assert_eq!(decoded.accounts[9].name, "pot");
+    assert_eq!(
+        decoded.account("pot"),
+        Some(Pot::new(challenge.pda().0).pda)
+    );
+}
+
+#[test]
+fn decode_admit_with_required_mint_and_protocol_fee() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let challenger = Pubkey::new_unique();
+    let required_mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let challenge = Challenge {
+        required_mint: Some(required_mint),
+        fee_recipients: vec![FeeRecipient {
+            recipient,
+            basis_points: 10_000,
+        }],
+        ..challenge(creator)
+    };
+    let config = ProgramConfig {
+        protocol_fee_basis_points: 500,
+        treasury: Pubkey::new_unique(),
+        ..Default::default()
+    };
+    let ix = ixs::admit_challenger(
+        payer,
+        &challenge,
+        challenger,
+        Some(spl_token::id()),
+    )
+    .unwrap()
+    .with_protocol_fee(&config)
+    .ix;
+
+    let decoded = decode_ix(&ix).unwrap();
+    assert_eq!(
+        account_names(&decoded),
+        [
+            "payer",
+            "creator",
+            "challenge_pda",
+            "challenger",
+            "challenger_pda",
+            "system_program",
+            "challenger_token_account",
+            "treasury",
+            "program_config",
+            "fee_recipient"
+        ]
+    );
+    assert_eq!(
+        decoded.account("challenger_token_account"),
+        Some(get_associated_token_address(&challenger, &required_mint))
+    );
+    assert_eq!(decoded.account("treasury"), Some(config.treasury));
+    assert_eq!(
+        decoded.account("program_config"),
+        Some(ProgramConfig::pda().0)
+    );
+    assert_eq!(decoded.account("fee_recipient"), Some(recipient));
+
+    // without a required mint nor protocol fee the program id is passed in their place
+    let challenge = Challenge {
+        required_mint: None,
+        ..challenge
+    };
+    let ix = ixs::admit_challenger(payer, &challenge, challenger, None)
+        .unwrap()
+        .ix;
+    let decoded = decode_ix(&ix).unwrap();
+    assert_eq!(
+        decoded.account("challenger_token_account"),
+        Some(challenge_id())
+    );
+    assert_eq!(decoded.account("treasury"), Some(challenge_id()));
+    assert_eq!(decoded.account("fee_recipient"), Some(recipient));
+}
+
+#[test]
+fn decode_redeem_naming_hook_accounts() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let hook_program = Pubkey::new_unique();
+    let hook_accounts = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let challenge = Challenge {
+        hook_program: Some(hook_program),
+        ..challenge(creator)
+    };
+    let ix = ixs::redeem_challenge(
+        payer,
+        &challenge,
+        payer,
+        "hello",
+        hook_accounts
+            .iter()
+            .map(|pubkey| AccountMeta::new_readonly(*pubkey, false))
+            .collect(),
+    )
+    .unwrap();
+
+    let decoded = decode_ix(&ix).unwrap();
+    let names = account_names(&decoded);
+    assert_eq!(
+        &names[names.len() - 3..],
+        ["program_config", "hook_account", "hook_account"]
+    );
+    assert_eq!(decoded.account("hook_program"), Some(hook_program));
+    assert_eq!(decoded.account("hook_account"), Some(hook_accounts[0]));
+    assert!(decoded
+        .to_string()
+        .contains(&format!("hook_account:              {}", hook_accounts[1])));
+}
+
+#[test]
+fn decode_create_challenge_args() {
+    let creator = Pubkey::new_unique();
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        200,
+        3,
+        vec!["hello"],
+    )
+    .unwrap();
+
+    let decoded = decode_ix(&ix).unwrap();
+    let arg = |name: &str| {
+        decoded
+            .args
+            .iter()
+            .find(|(arg, _)| *arg == name)
+            .map(|(_, value)| value.as_str())
+            .unwrap()
+    };
+    assert_eq!(arg("id"), ID);
+    assert_eq!(arg("admit_cost"), "200");
+    assert_eq!(arg("tries_per_admit"), "3");
+    assert_eq!(arg("fee_recipients"), "[]");
+    assert_eq!(arg("normalization"), "none");
+    assert_eq!(arg("hashing"), "none");
+    assert!(arg("solutions").starts_with('['));
+    assert_eq!(arg("solutions").len(), 2 + 64);
+}
+
+#[test]
+fn decode_invalid_instructions() {
+    let ix = system_instruction::transfer(
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        1,
+    );
+    assert_eq!(
+        decode_ix(&ix).unwrap_err(),
+        DecodeError::WrongProgram(ix.program_id)
+    );
+
+    assert!(matches!(
+        decode_instruction(&[255], &[]),
+        Err(DecodeError::InvalidData(_))
+    ));
+
+    let ix =
+        ixs::start_challenge(Pubkey::new_unique(), ID.to_string()).unwrap();
+    assert_eq!(
+        decode_instruction(&ix.data, &ix.accounts[..1]).unwrap_err(),
+        DecodeError::NotEnoughAccounts {
+            name: "StartChallenge",
+            expected: 3,
+            actual: 1
+        }
+    );
+}
+
+// -----------------
+// Transactions
+// -----------------
+#[test]
+fn decode_transaction_with_inner_instructions() {
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let message = Message::new(
+        &[
+            system_instruction::transfer(&payer, &creator, 1),
+            ixs::start_challenge(creator, ID.to_string()).unwrap(),
+            ixs::redeem_challenge(
+                payer,
+                &challenge(creator),
+                payer,
+                "hello",
+                vec![],
+            )
+            .unwrap(),
+        ],
+        Some(&payer),
+    );
+
+    // a program invoked by the first instruction starts the challenge via CPI
+    let start = &message.instructions[1];
+    let inner_instructions = vec![InnerInstructions {
+        index: 0,
+        instructions: vec![
+            message.instructions[0].clone(),
+            start.clone(),
+            CompiledInstruction {
+                program_id_index: start.program_id_index,
+                accounts: vec![u8::MAX],
+                data: start.data.clone(),
+            },
+        ],
+    }];
+
+    let decoded = decode_transaction(&message, &inner_instructions);
+    let positions = decoded
+        .iter()
+        .map(|ix| (ix.index, ix.inner_index))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        positions,
+        vec![(0, Some(1)), (0, Some(2)), (1, None), (2, None)]
+    );
+
+    let inner = decoded[0].decoded.as_ref().unwrap();
+    assert_eq!(inner.name(), "StartChallenge");
+    assert_eq!(inner.account("creator"), Some(creator));
+    assert!(inner.accounts[0].signer);
+    assert_eq!(
+        decoded[1].decoded.as_ref().unwrap_err(),
+        &DecodeError::InvalidAccountIndex(u8::MAX)
+    );
+
+    let redeem = decoded[3].decoded.as_ref().unwrap();
+    assert_eq!(redeem.name(), "Redeem");
+    assert_eq!(redeem.account("challenger"), Some(payer));
+    assert!(redeem.accounts[2].signer && redeem.accounts[2].writable);
+}
+
+#[cfg(feature = "cli")]
+#[test]
+fn render_decoded_transaction_as_text_and_json() {
+    use challenge::cli::{render_decoded_transaction, OutputFormat};
+    use serde_json::Value;
+
+    let payer = Pubkey::new_unique();
+    let creator = Pubkey::new_unique();
+    let message = Message::new(
+        &[
+            ixs::start_challenge(creator, ID.to_string()).unwrap(),
+            ixs::redeem_challenge(
+                payer,
+                &challenge(creator),
+                payer,
+                "hello",
+                vec![],
+            )
+            .unwrap(),
+        ],
+        Some(&payer),
+    );
+    let decoded = decode_transaction(&message, &[]);
+    let solution = hex_string(&hash_solution_challenger_sends(
+        "hello",
+        &Default::default(),
+    ));
+
+    let json: Value = serde_json::from_str(&render_decoded_transaction(
+        &decoded,
+        OutputFormat::Json,
+    ))
+    .expect("output should be valid JSON");
+    assert_eq!(json[0]["instruction"], "StartChallenge");
+    assert_eq!(json[0]["index"], 0);
+    assert_eq!(json[0]["inner_index"], Value::Null);
+    assert_eq!(json[0]["args"]["id"], ID);
+    assert_eq!(json[1]["instruction"], "Redeem");
+    assert_eq!(json[1]["args"]["solution"], solution);
+    assert_eq!(json[1]["accounts"][0]["name"], "payer");
+    assert_eq!(json[1]["accounts"][0]["pubkey"], payer.to_string());
+    assert_eq!(json[1]["accounts"][0]["signer"], true);
+
+    let text = render_decoded_transaction(&decoded, OutputFormat::Text);
+    assert!(text.starts_with("#0 StartChallenge\n"), "{}", text);
+    assert!(text.contains("#1 Redeem\n"), "{}", text);
+    assert!(text.contains(&solution));
+}
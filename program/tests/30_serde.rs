@@ -0,0 +1,249 @@
+#![cfg(all(feature = "test-sbf", feature = "serde"))]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use challenge::{
+    challenge_id,
+    state::{
+        Challenge, Challenger, FeeRecipient, Pot, ProgramConfig, Redeem,
+        SolutionHashing, SolutionNormalization, SoulboundRedeem,
+        CHALLENGE_DISCRIMINATOR,
+    },
+    utils::hex_string,
+};
+use serde_json::{json, Value};
+use solana_program::pubkey::Pubkey;
+
+const ID: &str = "challenge-id";
+
+fn challenge(creator: Pubkey) -> Challenge {
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    Challenge {
+        discriminator: CHALLENGE_DISCRIMINATOR,
+        authority: creator,
+        bump: 254,
+        pot_bump: 253,
+        id: ID.to_string(),
+        started: true,
+        finished: false,
+        admit_cost: 200,
+        tries_per_admit: 3,
+        redeem: Redeem::new(challenge_pda).pda,
+        solving: 1,
+        admission_root: Some([7; 32]),
+        required_mint: Some(Pubkey::new_unique()),
+        required_amount: 5,
+        normalization: SolutionNormalization {
+            case_fold: true,
+            trim_whitespace: true,
+            ..Default::default()
+        },
+        soulbound: true,
+        escrowed_tries: 3,
+        fee_recipients: vec![FeeRecipient {
+            recipient: Pubkey::new_unique(),
+            basis_points: 10_000,
+        }],
+        jackpot: Some(2_500),
+        escrow: true,
+        cancelled: false,
+        hook_program: None,
+        hashing: Some(SolutionHashing {
+            salt: [9; 32],
+            iterations: 10,
+        }),
+        solutions: vec![[1; 32], [2; 32], [0xab; 32]],
+    }
+}
+
+/// Round trips the [value] through JSON and checks that the borsh encoding is unchanged.
+fn assert_round_trip<T>(value: &T) -> Value
+where
+    T: BorshSerialize + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_value(value).expect("failed to serialize");
+    let decoded: T =
+        serde_json::from_value(json.clone()).expect("failed to deserialize");
+    assert_eq!(decoded.try_to_vec().unwrap(), value.try_to_vec().unwrap());
+    json
+}
+
+// -----------------
+// Challenge
+// -----------------
+#[test]
+fn challenge_json_with_base58_pubkeys_and_hex_solutions() {
+    let creator = Pubkey::new_unique();
+    let challenge = challenge(creator);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let json = assert_round_trip(&challenge);
+    assert_eq!(json["discriminator"], hex_string(b"chlnge01"));
+    assert_eq!(json["authority"], creator.to_string());
+    assert_eq!(json["redeem"], Redeem::new(challenge_pda).pda.to_string());
+    assert_eq!(
+        json["required_mint"],
+        challenge.required_mint.unwrap().to_string()
+    );
+    assert_eq!(json["hook_program"], Value::Null);
+    assert_eq!(json["admission_root"], hex_string(&[7; 32]));
+    assert_eq!(
+        json["solutions"],
+        json!([
+            hex_string(&[1; 32]),
+            hex_string(&[2; 32]),
+            hex_string(&[0xab; 32])
+        ])
+    );
+    assert_eq!(
+        json["fee_recipients"],
+        json!([{
+            "recipient": challenge.fee_recipients[0].recipient.to_string(),
+            "basis_points": 10_000,
+        }])
+    );
+    assert_eq!(
+        json["hashing"],
+        json!({ "salt": hex_string(&[9; 32]), "iterations": 10 })
+    );
+    assert_eq!(json["normalization"]["case_fold"], true);
+    assert_eq!(json["normalization"]["nfkc"], false);
+    assert_eq!(json["jackpot"], 2_500);
+}
+
+#[test]
+fn challenge_json_with_derived_fields() {
+    let creator = Pubkey::new_unique();
+    let challenge = challenge(creator);
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+
+    let json = assert_round_trip(&challenge);
+    assert_eq!(json["address"], challenge_pda.to_string());
+    assert_eq!(json["pot"], Pot::new(challenge_pda).pda.to_string());
+    assert_eq!(json["remaining_solutions"], 2);
+
+    // derived fields are ignored when deserializing
+    let mut changed = json.clone();
+    changed["address"] = Pubkey::new_unique().to_string().into();
+    changed["remaining_solutions"] = 0.into();
+    let decoded: Challenge = serde_json::from_value(changed).unwrap();
+    assert_eq!(
+        decoded.try_to_vec().unwrap(),
+        challenge.try_to_vec().unwrap()
+    );
+}
+
+#[test]
+fn challenge_round_trip_from_borsh_encoding() {
+    let data = challenge(Pubkey::new_unique()).try_to_vec().unwrap();
+    let challenge = Challenge::try_from_slice(&data).unwrap();
+
+    let json = serde_json::to_string(&challenge).unwrap();
+    let decoded: Challenge = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.try_to_vec().unwrap(), data);
+}
+
+#[test]
+fn challenge_json_with_invalid_values() {
+    let mut json =
+        serde_json::to_value(challenge(Pubkey::new_unique())).unwrap();
+    json["solutions"] = json!(["abcd"]);
+    let err = serde_json::from_value::<Challenge>(json.clone()).unwrap_err();
+    assert!(
+        err.to_string().contains("expected 32 bytes as hex"),
+        "{}",
+        err
+    );
+
+    json["solutions"] = json!([hex_string(&[1; 32])]);
+    json["authority"] = "not-a-pubkey".into();
+    let err = serde_json::from_value::<Challenge>(json).unwrap_err();
+    assert!(err.to_string().contains("invalid pubkey"), "{}", err);
+}
+
+// -----------------
+// Challenger
+// -----------------
+#[test]
+fn challenger_json_with_derived_fields() {
+    let (challenge_pda, _) = Challenge::pda_for(&Pubkey::new_unique(), ID);
+    let authority = Pubkey::new_unique();
+    let mut challenger = Challenger::new(authority, challenge_pda, 2);
+    challenger.redeemed = true;
+    challenger.mark_solved(0);
+    challenger.mark_solved(9);
+    challenger.mark_solved(3);
+    challenger.mark_revoked(3);
+
+    let json = assert_round_trip(&challenger);
+    assert_eq!(json["authority"], authority.to_string());
+    assert_eq!(json["challenge_pda"], challenge_pda.to_string());
+    assert_eq!(json["tries_remaining"], 2);
+    assert_eq!(json["discriminator"], hex_string(b"chlngr01"));
+    assert_eq!(json["solved"], hex_string(&challenger.solved));
+    assert_eq!(json["solved_indexes"], json!([0, 9]));
+    assert_eq!(json["revoked_indexes"], json!([3]));
+    assert_eq!(
+        json["address"],
+        Challenger::shank_pda(&challenge_id(), &challenge_pda, &authority)
+            .0
+            .to_string()
+    );
+    assert_eq!(
+        json["redeem_mint"],
+        Redeem::new(challenge_pda).pda.to_string()
+    );
+}
+
+// -----------------
+// Other State
+// -----------------
+#[test]
+fn program_config_and_soulbound_redeem_round_trip() {
+    let config = ProgramConfig {
+        admin: Pubkey::new_unique(),
+        protocol_fee_basis_points: 100,
+        treasury: Pubkey::new_unique(),
+        paused: true,
+        bump: 255,
+    };
+    let json = assert_round_trip(&config);
+    assert_eq!(json["admin"], config.admin.to_string());
+    assert_eq!(json["treasury"], config.treasury.to_string());
+
+    for soulbound in [
+        SoulboundRedeem::default(),
+        SoulboundRedeem {
+            metadata_address: Some(Pubkey::new_unique()),
+            revocable: true,
+        },
+    ] {
+        let json = assert_round_trip(&soulbound);
+        assert_eq!(
+            json["metadata_address"],
+            soulbound
+                .metadata_address
+                .map_or(Value::Null, |x| x.to_string().into())
+        );
+    }
+}
+
+#[test]
+fn pot_and_redeem_json() {
+    let (challenge_pda, _) = Challenge::pda_for(&Pubkey::new_unique(), ID);
+    let redeem = Redeem::new(challenge_pda);
+    let json = serde_json::to_value(&redeem).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "challenge_pda": challenge_pda.to_string(),
+            "pda": redeem.pda.to_string(),
+        })
+    );
+    let decoded: Redeem = serde_json::from_value(json).unwrap();
+    assert_eq!(decoded.pda, redeem.pda);
+
+    let pot = Pot::new(challenge_pda);
+    let decoded: Pot =
+        serde_json::from_value(serde_json::to_value(&pot).unwrap()).unwrap();
+    assert_eq!(decoded.pda, pot.pda);
+}
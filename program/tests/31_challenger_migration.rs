@@ -0,0 +1,261 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    events::ChallengeEvent,
+    ixs::{self, AdmitChallengerIx},
+    state::{
+        Challenge, Challenger, CHALLENGER_DISCRIMINATOR, CHALLENGER_SIZE,
+        LEGACY_CHALLENGER_SIZE_V0, LEGACY_CHALLENGER_SIZE_V1,
+    },
+};
+
+use solana_program::{pubkey::Pubkey, rent::Rent};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    signature::Keypair,
+    signer::Signer,
+};
+
+use crate::utils::{
+    get_account, get_challenge, get_deserialized, process_ix,
+    process_ix_logging_events, program_test, try_process_ix,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 5;
+
+/// Creates and starts a challenge and admits a challenger.
+/// Returns the challenge PDA, the challenger and its PDA.
+async fn admitted_challenger(
+    context: &mut ProgramTestContext,
+) -> (Pubkey, Keypair, Pubkey) {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let (_, challenge) =
+        get_deserialized::<Challenge>(context, &challenge_pda).await;
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let (challenger_pda, _) = Challenger::shank_pda(
+        &challenge_id(),
+        &challenge_pda,
+        &challenger.pubkey(),
+    );
+    (challenge_pda, challenger, challenger_pda)
+}
+
+/// Replaces the data of the challenger account with data stored in a legacy layout.
+fn store_legacy_challenger(
+    context: &mut ProgramTestContext,
+    challenger_pda: &Pubkey,
+    data: Vec<u8>,
+) {
+    let account = AccountSharedData::from(Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: challenge_id(),
+        ..Default::default()
+    });
+    context.set_account(challenger_pda, &account);
+}
+
+fn legacy_v0_data(challenger: &Pubkey, challenge_pda: &Pubkey) -> Vec<u8> {
+    (*challenger, *challenge_pda, TRIES_PER_ADMIT - 1, true)
+        .try_to_vec()
+        .unwrap()
+}
+
+fn legacy_v1_data(
+    challenger: &Pubkey,
+    challenge_pda: &Pubkey,
+    solved: [u8; 32],
+) -> Vec<u8> {
+    (
+        CHALLENGER_DISCRIMINATOR,
+        *challenger,
+        *challenge_pda,
+        TRIES_PER_ADMIT - 1,
+        true,
+        solved,
+    )
+        .try_to_vec()
+        .unwrap()
+}
+
+// -----------------
+// Decoding
+// -----------------
+#[test]
+fn decode_challengers_stored_in_legacy_layouts() {
+    let challenger = Pubkey::new_unique();
+    let challenge_pda = Pubkey::new_unique();
+
+    let data = legacy_v0_data(&challenger, &challenge_pda);
+    assert_eq!(data.len(), LEGACY_CHALLENGER_SIZE_V0);
+    assert!(Challenger::is_legacy_layout(&data));
+    let decoded = Challenger::from_account_data(&data).unwrap();
+    assert_eq!(decoded.discriminator, CHALLENGER_DISCRIMINATOR);
+    assert_eq!(decoded.authority, challenger);
+    assert_eq!(decoded.challenge_pda, challenge_pda);
+    assert_eq!(decoded.tries_remaining, TRIES_PER_ADMIT - 1);
+    assert!(decoded.redeemed);
+    assert_eq!(decoded.solved_indexes(), Vec::<u8>::new());
+
+    let mut solved = [0; 32];
+    solved[0] = 0b101;
+    let data = legacy_v1_data(&challenger, &challenge_pda, solved);
+    assert_eq!(data.len(), LEGACY_CHALLENGER_SIZE_V1);
+    assert!(Challenger::is_legacy_layout(&data));
+    let decoded = Challenger::from_account_data(&data).unwrap();
+    assert_eq!(decoded.authority, challenger);
+    assert_eq!(decoded.solved_indexes(), vec![0, 2]);
+    assert_eq!(decoded.revoked_indexes(), Vec::<u8>::new());
+
+    let data = Challenger::new(challenger, challenge_pda, TRIES_PER_ADMIT)
+        .try_to_vec()
+        .unwrap();
+    assert!(!Challenger::is_legacy_layout(&data));
+    let decoded = Challenger::from_account_data(&data).unwrap();
+    assert_eq!(decoded.tries_remaining, TRIES_PER_ADMIT);
+}
+
+// -----------------
+// Migrate Challenger
+// -----------------
+#[tokio::test]
+async fn migrate_legacy_challenger_and_redeem() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, challenger, challenger_pda) =
+        admitted_challenger(&mut context).await;
+    store_legacy_challenger(
+        &mut context,
+        &challenger_pda,
+        legacy_v0_data(&challenger.pubkey(), &challenge_pda),
+    );
+
+    let ix = ixs::migrate_challenger(
+        context.payer.pubkey(),
+        challenge_pda,
+        challenger.pubkey(),
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::ChallengerMigrated {
+            challenge_pda,
+            challenger: challenger.pubkey(),
+        }]
+    );
+
+    let account = get_account(&mut context, &challenger_pda).await;
+    assert_eq!(account.data.len(), CHALLENGER_SIZE);
+    assert_eq!(
+        account.lamports,
+        Rent::default().minimum_balance(CHALLENGER_SIZE)
+    );
+    let (_, migrated) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(migrated.authority, challenger.pubkey());
+    assert_eq!(migrated.tries_remaining, TRIES_PER_ADMIT - 1);
+    assert!(migrated.redeemed);
+
+    let creator = context.payer.pubkey();
+    let challenge = get_challenge(&mut context, ID).await;
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+
+    let (_, redeemed) =
+        get_deserialized::<Challenger>(&mut context, &challenger_pda).await;
+    assert_eq!(redeemed.tries_remaining, TRIES_PER_ADMIT - 2);
+    assert_eq!(redeemed.solved_indexes(), vec![0]);
+}
+
+#[tokio::test]
+async fn migrate_challenger_stored_in_current_layout() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, challenger, challenger_pda) =
+        admitted_challenger(&mut context).await;
+    let before = get_account(&mut context, &challenger_pda).await;
+
+    let ix = ixs::migrate_challenger(
+        context.payer.pubkey(),
+        challenge_pda,
+        challenger.pubkey(),
+    )
+    .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert!(events.is_empty());
+
+    let after = get_account(&mut context, &challenger_pda).await;
+    assert_eq!(after.data, before.data);
+    assert_eq!(after.lamports, before.lamports);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn redeem_with_legacy_challenger_that_was_not_migrated() {
+    let mut context = program_test().start_with_context().await;
+    let (challenge_pda, challenger, challenger_pda) =
+        admitted_challenger(&mut context).await;
+    store_legacy_challenger(
+        &mut context,
+        &challenger_pda,
+        legacy_v1_data(&challenger.pubkey(), &challenge_pda, [0; 32]),
+    );
+
+    let creator = context.payer.pubkey();
+    let challenge = get_challenge(&mut context, ID).await;
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect_err("should not redeem before the challenger is migrated");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::ChallengerNeedsMigration)
+    );
+}
@@ -0,0 +1,298 @@
+#![cfg(feature = "test-sbf")]
+
+use borsh::BorshSerialize;
+use challenge::{
+    challenge_id,
+    error::ChallengeError,
+    events::ChallengeEvent,
+    ixs::{self, AdmitChallengerIx},
+    state::{Challenge, ChallengeView, HasPda, HasSize},
+    utils::hash_solutions,
+};
+
+use solana_program::{program_error::ProgramError, pubkey::Pubkey, rent::Rent};
+use solana_program_test::*;
+
+#[allow(unused)]
+use crate::utils::dump_account;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    signature::Keypair,
+    signer::Signer,
+};
+
+use crate::utils::{
+    get_account, get_challenge, process_ix, process_ix_logging_events,
+    program_test, try_process_ix,
+};
+
+mod utils;
+
+const ID: &str = "challenge-id";
+const ADMIT_COST: u64 = 200;
+const TRIES_PER_ADMIT: u8 = 5;
+const RESERVED: usize = 64;
+
+/// Encodes the challenge in the layout challenges were stored in before the
+/// `discriminator` was added, followed by [RESERVED] bytes for solutions.
+fn legacy_data(challenge: &Challenge) -> Vec<u8> {
+    let mut data = (
+        challenge.authority,
+        challenge.id.clone(),
+        challenge.started,
+        challenge.finished,
+        challenge.admit_cost,
+        challenge.tries_per_admit,
+        challenge.redeem,
+        challenge.solving,
+        challenge.solutions.clone(),
+    )
+        .try_to_vec()
+        .unwrap();
+    data.extend([0; RESERVED]);
+    data
+}
+
+/// Creates and starts a challenge and replaces its data with the same challenge
+/// stored in the legacy layout.
+async fn started_legacy_challenge(context: &mut ProgramTestContext) -> Pubkey {
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(context, ix, &[]).await;
+
+    let challenge = get_challenge(context, ID).await;
+    let (challenge_pda, _) = challenge.pda();
+    let data = legacy_data(&Challenge {
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..challenge
+    });
+    let account = AccountSharedData::from(Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: challenge_id(),
+        ..Default::default()
+    });
+    context.set_account(&challenge_pda, &account);
+    challenge_pda
+}
+
+// -----------------
+// Decoding
+// -----------------
+#[test]
+fn decode_challenge_stored_in_legacy_layout() {
+    let creator = Pubkey::new_unique();
+    let (_, bump) = Challenge::pda_for(&creator, ID);
+    let legacy = Challenge {
+        authority: creator,
+        id: ID.to_string(),
+        started: true,
+        admit_cost: ADMIT_COST,
+        tries_per_admit: TRIES_PER_ADMIT,
+        redeem: Pubkey::new_unique(),
+        solving: 1,
+        solutions: hash_solutions(&["hello", "world"], &Default::default()),
+        ..Default::default()
+    };
+    let mut data = legacy_data(&legacy);
+    assert!(Challenge::is_legacy_layout(&data));
+
+    let challenge = Challenge::from_account_data(&data).unwrap();
+    assert_eq!(challenge.authority, creator);
+    assert_eq!(challenge.id, ID);
+    assert_eq!(challenge.bump, bump);
+    assert!(challenge.started);
+    assert_eq!(challenge.admit_cost, ADMIT_COST);
+    assert_eq!(challenge.tries_per_admit, TRIES_PER_ADMIT);
+    assert_eq!(challenge.redeem, legacy.redeem);
+    assert_eq!(challenge.solving, 1);
+    assert_eq!(challenge.solutions, legacy.solutions);
+    assert_eq!(challenge.hashing, None);
+    assert!(!challenge.soulbound);
+    assert_eq!(
+        challenge.legacy_size() + RESERVED,
+        data.len(),
+        "legacy size excludes reserved space"
+    );
+
+    assert_eq!(
+        ChallengeView::from_data(&mut data).err(),
+        Some(ProgramError::from(ChallengeError::ChallengeNeedsMigration))
+    );
+
+    let data = challenge.try_to_vec().unwrap();
+    assert!(!Challenge::is_legacy_layout(&data));
+    assert!(!Challenge::is_legacy_layout(&[]));
+}
+
+// -----------------
+// Migrate Challenge
+// -----------------
+#[tokio::test]
+async fn migrate_legacy_challenge_and_redeem() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenge_pda = started_legacy_challenge(&mut context).await;
+
+    let ix = ixs::migrate_challenge(creator, creator, ID.to_string())
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert_eq!(
+        events,
+        vec![ChallengeEvent::ChallengeMigrated { challenge_pda }]
+    );
+
+    // the space reserved for solutions is kept
+    let challenge = get_challenge(&mut context, ID).await;
+    let account = get_account(&mut context, &challenge_pda).await;
+    assert_eq!(account.data.len(), challenge.size() + RESERVED);
+    assert_eq!(
+        account.lamports,
+        Rent::default().minimum_balance(account.data.len())
+    );
+    assert!(!Challenge::is_legacy_layout(&account.data));
+    assert_eq!(challenge.bump, challenge.pda().1);
+    assert!(challenge.started);
+    assert_eq!(challenge.solutions.len(), 2);
+
+    let challenger = Keypair::new();
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[&challenger]).await;
+
+    let challenge = get_challenge(&mut context, ID).await;
+    assert_eq!(challenge.solving, 1);
+}
+
+#[tokio::test]
+async fn migrate_challenge_stored_in_current_layout() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    let (challenge_pda, _) = Challenge::pda_for(&creator, ID);
+    let before = get_account(&mut context, &challenge_pda).await;
+
+    let ix = ixs::migrate_challenge(creator, creator, ID.to_string())
+        .expect("failed to create instruction");
+    let events = process_ix_logging_events(&mut context, ix, &[]).await;
+    assert!(events.is_empty());
+
+    let after = get_account(&mut context, &challenge_pda).await;
+    assert_eq!(after.data, before.data);
+    assert_eq!(after.lamports, before.lamports);
+}
+
+// -----------------
+// Error Cases
+// -----------------
+#[tokio::test]
+async fn admit_and_redeem_with_legacy_challenge_that_was_not_migrated() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+    let challenger = Keypair::new();
+
+    // admit while the challenge is still stored in the current layout
+    let ix = ixs::create_challenge(
+        creator,
+        creator,
+        ID.to_string(),
+        ADMIT_COST,
+        TRIES_PER_ADMIT,
+        vec!["hello", "world"],
+    )
+    .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+    let ix = ixs::start_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+    let challenge = get_challenge(&mut context, ID).await;
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, challenger.pubkey(), None)
+            .expect("failed to create instruction");
+    process_ix(&mut context, ix, &[]).await;
+
+    // re-store the challenge in the legacy layout
+    let (challenge_pda, _) = challenge.pda();
+    let account = get_account(&mut context, &challenge_pda).await;
+    let data =
+        legacy_data(&Challenge::from_account_data(&account.data).unwrap());
+    context.set_account(
+        &challenge_pda,
+        &AccountSharedData::from(Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: challenge_id(),
+            ..Default::default()
+        }),
+    );
+
+    let ix = ixs::redeem_challenge(
+        creator,
+        &challenge,
+        challenger.pubkey(),
+        "hello",
+        vec![],
+    )
+    .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[&challenger])
+        .await
+        .expect_err("should not redeem before the challenge is migrated");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::ChallengeNeedsMigration)
+    );
+
+    let AdmitChallengerIx { ix, .. } =
+        ixs::admit_challenger(creator, &challenge, Pubkey::new_unique(), None)
+            .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("should not admit before the challenge is migrated");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::ChallengeNeedsMigration)
+    );
+
+    let ix = ixs::cancel_challenge(creator, ID.to_string())
+        .expect("failed to create instruction");
+    let err = try_process_ix(&mut context, ix, &[])
+        .await
+        .expect_err("should not cancel before the challenge is migrated");
+    assert_eq!(
+        ChallengeError::from_transaction_error(&err.unwrap()),
+        Some(ChallengeError::ChallengeNeedsMigration)
+    );
+}
@@ -0,0 +1,250 @@
+//! Sample game program that hosts a challenge and lets its player solve it by invoking
+//! the challenge program via [challenge::cpi].
+//! Both host and player are PDAs of the game which sign on their behalf.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use challenge::{
+    challenge_id,
+    cpi::{self, AdmitChallengerAccounts, RedeemAccounts},
+    outcome::RedeemOutcome,
+    state::SolutionNormalization,
+    verify::verify_solver,
+    Solution,
+};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::get_return_data,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+
+pub const CALLER_ID: Pubkey = Pubkey::new_from_array([42; 32]);
+
+const HOST_SEED: &[u8] = b"host";
+const PLAYER_SEED: &[u8] = b"player";
+
+/// Adds the caller to the programs that are loaded when the test starts.
+#[allow(unused)] // only used by some tests
+pub fn add_caller_program(program_test: &mut ProgramTest) {
+    program_test.add_program(
+        "cpi_caller",
+        CALLER_ID,
+        processor!(process_instruction),
+    );
+}
+
+#[allow(unused)] // only used by some tests
+pub fn host_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOST_SEED], &CALLER_ID)
+}
+
+#[allow(unused)] // only used by some tests
+pub fn player_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PLAYER_SEED], &CALLER_ID)
+}
+
+/// Optional accounts that are not provided are passed as the challenge program.
+fn optional_account<'a>(info: &AccountInfo<'a>) -> Option<AccountInfo<'a>> {
+    if info.key == &challenge_id() {
+        None
+    } else {
+        Some(info.clone())
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum CallerInstruction {
+    /// Creates and starts a challenge whose creator is the host.
+    /// Accounts are the same as for `CreateChallenge` with the host as payer and creator,
+    /// followed by the challenge program.
+    HostChallenge {
+        id: String,
+        admit_cost: u64,
+        tries_per_admit: u8,
+        solutions: Vec<Solution>,
+    },
+
+    /// Admits the player who pays the admit cost to the host.
+    /// Accounts are the same as for `AdmitChallenger` with the player as payer and
+    /// challenger, followed by the challenge program.
+    AdmitPlayer,
+
+    /// Redeems on behalf of the player and fails unless the solution was correct.
+    /// Accounts are the same as for `Redeem` with the player as payer and challenger,
+    /// followed by the challenge program.
+    RedeemForPlayer { solution: Solution },
+
+    /// Verifies that the wallet solved the challenge, i.e. before granting it a perk,
+    /// and fails otherwise.
+    /// Accounts are the challenge PDA and the challenger PDA of the wallet.
+    VerifySolver { wallet: Pubkey },
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CallerInstruction::try_from_slice(instruction_data)?;
+    let account_info_iter = &mut accounts.iter();
+
+    match ix {
+        CallerInstruction::HostChallenge {
+            id,
+            admit_cost,
+            tries_per_admit,
+            solutions,
+        } => {
+            msg!("Caller IX: host challenge");
+            let host = next_account_info(account_info_iter)?;
+            let _creator = next_account_info(account_info_iter)?;
+            let challenge_pda = next_account_info(account_info_iter)?;
+            let redeem_pda = next_account_info(account_info_iter)?;
+            let token_program = next_account_info(account_info_iter)?;
+            let system_program = next_account_info(account_info_iter)?;
+            let program_config = next_account_info(account_info_iter)?;
+            let challenge_program = next_account_info(account_info_iter)?;
+
+            let (_, bump) = host_pda();
+            let host_seeds: &[&[u8]] = &[HOST_SEED, &[bump]];
+
+            cpi::create_challenge(
+                challenge_program,
+                cpi::CreateChallengeAccounts {
+                    payer: host.clone(),
+                    creator: host.clone(),
+                    challenge_pda: challenge_pda.clone(),
+                    redeem_pda: redeem_pda.clone(),
+                    token_program: token_program.clone(),
+                    system_program: system_program.clone(),
+                    program_config: program_config.clone(),
+                },
+                id.clone(),
+                admit_cost,
+                tries_per_admit,
+                solutions,
+                vec![],
+                0,
+                None,
+                SolutionNormalization::default(),
+                None,
+                &[host_seeds],
+            )?;
+            cpi::start_challenge(
+                challenge_program,
+                cpi::StartChallengeAccounts {
+                    creator: host.clone(),
+                    challenge_pda: challenge_pda.clone(),
+                    program_config: program_config.clone(),
+                },
+                id,
+                &[host_seeds],
+            )
+        }
+        CallerInstruction::AdmitPlayer => {
+            msg!("Caller IX: admit player");
+            let player = next_account_info(account_info_iter)?;
+            let host = next_account_info(account_info_iter)?;
+            let challenge_pda = next_account_info(account_info_iter)?;
+            let _challenger = next_account_info(account_info_iter)?;
+            let challenger_pda = next_account_info(account_info_iter)?;
+            let system_program = next_account_info(account_info_iter)?;
+            let challenger_token_account =
+                next_account_info(account_info_iter)?;
+            let treasury = next_account_info(account_info_iter)?;
+            let program_config = next_account_info(account_info_iter)?;
+            let challenge_program = next_account_info(account_info_iter)?;
+
+            let (_, bump) = player_pda();
+            cpi::admit_challenger(
+                challenge_program,
+                AdmitChallengerAccounts {
+                    payer: player.clone(),
+                    creator: host.clone(),
+                    challenge_pda: challenge_pda.clone(),
+                    challenger: player.clone(),
+                    challenger_pda: challenger_pda.clone(),
+                    system_program: system_program.clone(),
+                    challenger_token_account: optional_account(
+                        challenger_token_account,
+                    ),
+                    treasury: optional_account(treasury),
+                    program_config: program_config.clone(),
+                    remaining_accounts: vec![],
+                },
+                vec![],
+                &[&[PLAYER_SEED, &[bump]]],
+            )
+        }
+        CallerInstruction::RedeemForPlayer { solution } => {
+            msg!("Caller IX: redeem for player");
+            let player = next_account_info(account_info_iter)?;
+            let challenge_pda = next_account_info(account_info_iter)?;
+            let _challenger = next_account_info(account_info_iter)?;
+            let challenger_pda = next_account_info(account_info_iter)?;
+            let redeem = next_account_info(account_info_iter)?;
+            let redeem_ata = next_account_info(account_info_iter)?;
+            let token_program = next_account_info(account_info_iter)?;
+            let associated_token_program =
+                next_account_info(account_info_iter)?;
+            let system_program = next_account_info(account_info_iter)?;
+            let pot = next_account_info(account_info_iter)?;
+            let hook_program = next_account_info(account_info_iter)?;
+            let program_config = next_account_info(account_info_iter)?;
+            let challenge_program = next_account_info(account_info_iter)?;
+
+            let (_, bump) = player_pda();
+            cpi::redeem(
+                challenge_program,
+                RedeemAccounts {
+                    payer: player.clone(),
+                    challenge_pda: challenge_pda.clone(),
+                    challenger: player.clone(),
+                    challenger_pda: challenger_pda.clone(),
+                    redeem: redeem.clone(),
+                    redeem_ata: redeem_ata.clone(),
+                    token_program: token_program.clone(),
+                    associated_token_program: associated_token_program.clone(),
+                    system_program: system_program.clone(),
+                    pot: optional_account(pot),
+                    hook_program: optional_account(hook_program),
+                    program_config: program_config.clone(),
+                    remaining_accounts: vec![],
+                },
+                solution,
+                &[&[PLAYER_SEED, &[bump]]],
+            )?;
+
+            let outcome = get_return_data().and_then(|(program_id, data)| {
+                RedeemOutcome::from_return_data(&program_id, &data)
+            });
+            match outcome {
+                Some(outcome) if outcome.is_correct() => {
+                    msg!("Player solved solution {}", outcome.solution_index);
+                    Ok(())
+                }
+                _ => {
+                    msg!("Player did not provide a correct solution");
+                    Err(ProgramError::InvalidArgument)
+                }
+            }
+        }
+        CallerInstruction::VerifySolver { wallet } => {
+            msg!("Caller IX: verify solver");
+            let challenge_pda = next_account_info(account_info_iter)?;
+            let challenger_pda = next_account_info(account_info_iter)?;
+
+            let status = verify_solver(challenge_pda, challenger_pda, &wallet)?;
+            if status.solved {
+                msg!("Wallet solved {} solutions", status.times_solved);
+                Ok(())
+            } else {
+                msg!("Wallet did not solve the challenge");
+                Err(ProgramError::InvalidArgument)
+            }
+        }
+    }
+}
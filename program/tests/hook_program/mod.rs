@@ -0,0 +1,62 @@
+//! Sample hook program that records each solve it is notified about into an account
+//! it owns, i.e. in place of unlocking a game item for the solver.
+
+use borsh::BorshSerialize;
+use challenge::{hook::PostSolveHook, verify::verify_solver};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, ProgramTest};
+
+pub const HOOK_ID: Pubkey = Pubkey::new_from_array([43; 32]);
+
+/// Size of the account the hook records the last solve into.
+pub const RECORD_SIZE: usize = 32 + 32 + 1;
+
+pub fn add_hook_program(program_test: &mut ProgramTest) {
+    program_test.add_program(
+        "hook_program",
+        HOOK_ID,
+        processor!(process_instruction),
+    );
+}
+
+/// Accounts are the ones passed by the challenge program followed by the record
+/// account owned by this program.
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let hook = PostSolveHook::from_instruction_data(instruction_data)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    msg!("Hook IX: solved {}", hook.solution_index);
+
+    let account_info_iter = &mut accounts.iter();
+    let challenge_pda = next_account_info(account_info_iter)?;
+    let challenger = next_account_info(account_info_iter)?;
+    let challenger_pda = next_account_info(account_info_iter)?;
+    let record = next_account_info(account_info_iter)?;
+
+    // the hook data could be sent by anyone, thus confirm the solve first
+    if hook.challenge_pda != *challenge_pda.key
+        || hook.challenger != *challenger.key
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let status = verify_solver(challenge_pda, challenger_pda, challenger.key)?;
+    if !status.solved_indexes.contains(&hook.solution_index) {
+        msg!("Err: challenger did not solve {}", hook.solution_index);
+        return Err(ProgramError::InvalidArgument);
+    }
+    if record.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    hook.serialize(&mut &mut record.try_borrow_mut_data()?.as_mut())?;
+    Ok(())
+}
@@ -1,12 +1,16 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use challenge::{
-    challenge_id,
-    state::{Challenge, HasPda, HasSize, Redeem},
+    challenge_id, program_data_address,
+    state::{Challenge, Challenger, HasPda, HasSize, Redeem},
     utils::hash_solutions,
 };
 use solana_program::{
-    borsh::try_from_slice_unchecked, program_option::COption,
-    program_pack::Pack, pubkey::Pubkey, rent::Rent,
+    borsh::try_from_slice_unchecked,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
 };
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{
@@ -50,6 +54,49 @@ pub async fn get_deserialized<T: BorshDeserialize>(
     (acc, value)
 }
 
+/// Fetches the challenge with the [id] created by the context's payer.
+#[allow(unused)]
+pub async fn get_challenge(
+    context: &mut ProgramTestContext,
+    id: &str,
+) -> Challenge {
+    let (challenge_pda, _) = Challenge::pda_for(&context.payer.pubkey(), id);
+    get_deserialized::<Challenge>(context, &challenge_pda)
+        .await
+        .1
+}
+
+/// Same as [get_challenge], but returns `None` if the challenge was not created yet.
+#[allow(unused)]
+pub async fn try_get_challenge(
+    context: &mut ProgramTestContext,
+    id: &str,
+) -> Option<Challenge> {
+    let (challenge_pda, _) = Challenge::pda_for(&context.payer.pubkey(), id);
+    let acc = context
+        .banks_client
+        .get_account(challenge_pda)
+        .await
+        .expect("get_account");
+    match acc {
+        Some(_) => Some(get_challenge(context, id).await),
+        None => None,
+    }
+}
+
+/// Fetches the account of the [challenger] admitted to the [challenge].
+#[allow(unused)]
+pub async fn get_challenger(
+    context: &mut ProgramTestContext,
+    challenge: &Challenge,
+    challenger: &Pubkey,
+) -> Challenger {
+    let challenger = Challenger::new(*challenger, challenge.pda().0, 0);
+    get_deserialized::<Challenger>(context, &challenger.pda().0)
+        .await
+        .1
+}
+
 #[allow(unused)]
 pub async fn dump_account<T: BorshDeserialize + std::fmt::Debug>(
     context: &mut ProgramTestContext,
@@ -104,6 +151,29 @@ pub fn add_pda_account<T: HasSize + HasPda + BorshSerialize>(
     account.into()
 }
 
+/// Adds the ProgramData account of the challenge program with the given
+/// [upgrade_authority] which is what the program checks to allow `InitConfig`.
+#[allow(unused)]
+pub fn add_program_data_account(
+    context: &mut ProgramTestContext,
+    upgrade_authority: &Pubkey,
+) {
+    let address = program_data_address();
+    let state = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(*upgrade_authority),
+    };
+    let space = UpgradeableLoaderState::size_of_programdata_metadata();
+    let account = AccountSharedData::new_data_with_space(
+        Rent::default().minimum_balance(space),
+        &state,
+        space,
+        &bpf_loader_upgradeable::id(),
+    )
+    .unwrap();
+    context.set_account(&address, &account);
+}
+
 #[allow(unused)]
 pub fn add_pack_account<T: Pack>(
     context: &mut ProgramTestContext,
@@ -155,19 +225,19 @@ pub fn add_challenge_with_solutions(
     solutions: Vec<&str>,
     authority: Option<Pubkey>,
 ) -> Account {
-    let solutions = hash_solutions(&solutions);
+    let solutions = hash_solutions(&solutions, &Default::default());
+    let authority = authority.unwrap_or_else(|| context.payer.pubkey());
     add_pda_account(
         context,
         &Challenge {
-            authority: authority.unwrap_or_else(|| context.payer.pubkey()),
+            authority,
+            bump: Challenge::pda_for(&authority, id).1,
             id: id.to_string(),
-            started: false,
-            finished: false,
             admit_cost: 200,
             tries_per_admit: 1,
             redeem: Pubkey::new_unique(),
-            solving: 0,
             solutions,
+            ..Default::default()
         },
     )
 }
@@ -179,19 +249,20 @@ pub fn add_started_challenge_with_solutions(
     solutions: Vec<&str>,
     authority: Option<Pubkey>,
 ) -> Account {
-    let solutions = hash_solutions(&solutions);
+    let solutions = hash_solutions(&solutions, &Default::default());
+    let authority = authority.unwrap_or_else(|| context.payer.pubkey());
     add_pda_account(
         context,
         &Challenge {
-            authority: authority.unwrap_or_else(|| context.payer.pubkey()),
+            authority,
+            bump: Challenge::pda_for(&authority, id).1,
             id: id.to_string(),
             started: true,
-            finished: false,
             admit_cost: 200,
             tries_per_admit: 1,
             redeem: Pubkey::new_unique(),
-            solving: 0,
             solutions,
+            ..Default::default()
         },
     )
 }
@@ -2,7 +2,7 @@ use borsh::BorshSerialize;
 use challenge::{
     challenge_id,
     ixs::ChallengeInstruction,
-    state::{HasPda, Redeem},
+    state::{HasPda, ProgramConfig, Redeem},
     Solution,
 };
 use solana_program::{
@@ -29,6 +29,7 @@ pub fn create_challenge_with_pda(
             AccountMeta::new(payer, true),
             AccountMeta::new_readonly(creator, false),
             AccountMeta::new(challenge_pda, false),
+            AccountMeta::new_readonly(ProgramConfig::pda().0, false),
         ],
         data: ChallengeInstruction::CreateChallenge {
             id,
@@ -36,6 +37,11 @@ pub fn create_challenge_with_pda(
             tries_per_admit,
             redeem,
             solutions,
+            fee_recipients: vec![],
+            capacity: 0,
+            hashing: None,
+            normalization: Default::default(),
+            soulbound: None,
         }
         .try_to_vec()?,
     };
@@ -3,9 +3,10 @@ use challenge::state::{Challenger, Redeem};
 use solana_program::{program_option::COption, pubkey::Pubkey};
 use solana_program_test::ProgramTestContext;
 use solana_sdk::account::Account;
+use spl_associated_token_account::get_associated_token_address;
 use spl_token::state::Mint;
 
-use super::get_unpacked;
+use super::{add_pack_account, get_unpacked};
 
 #[allow(unused)]
 pub async fn get_mint(
@@ -49,3 +50,26 @@ pub async fn verify_minted_when_redeeming(
         }
     );
 }
+
+/// Adds the associated token account of the [owner] for the [mint] holding [amount] tokens.
+#[allow(unused)]
+pub fn add_token_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let address = get_associated_token_address(owner, mint);
+    let token_account = spl_token::state::Account {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        delegate: COption::None,
+        state: spl_token::state::AccountState::Initialized,
+        is_native: COption::None,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+    add_pack_account(context, &address, &token_account, &spl_token::id());
+    address
+}
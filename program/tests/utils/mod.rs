@@ -4,8 +4,12 @@ use solana_program_test::ProgramTest;
 
 mod accounts;
 mod mints;
+mod tx;
 pub use accounts::*;
+#[allow(unused_imports)] // only used in some of the tests
 pub use mints::*;
+#[allow(unused_imports)] // only used in some of the tests
+pub use tx::*;
 pub mod ixs_custom;
 
 pub fn program_test() -> ProgramTest {
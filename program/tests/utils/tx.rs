@@ -0,0 +1,69 @@
+use challenge::events::{decode_events, ChallengeEvent};
+use solana_program::instruction::Instruction;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Creates a transaction for the [ix] which is paid and signed by the context's payer
+/// as well as signed by the [signers].
+#[allow(unused)]
+pub fn tx_for(
+    context: &ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Transaction {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend(signers);
+
+    Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    )
+}
+
+#[allow(unused)]
+pub async fn try_process_ix(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let tx = tx_for(context, ix, signers);
+    context.banks_client.process_transaction(tx).await
+}
+
+#[allow(unused)]
+pub async fn process_ix(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) {
+    try_process_ix(context, ix, signers)
+        .await
+        .expect("Failed to process transaction");
+}
+
+/// Processes the [ix] and returns the events the challenge program emitted.
+#[allow(unused)]
+pub async fn process_ix_logging_events(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Vec<ChallengeEvent> {
+    let tx = tx_for(context, ix, signers);
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .expect("Failed to process transaction");
+    result.result.expect("Transaction failed");
+
+    let log_messages = result
+        .metadata
+        .expect("Transaction has no metadata")
+        .log_messages;
+    decode_events(&log_messages)
+}
@@ -46,3 +46,21 @@ export function pdaForRedeem(challengePda: PublicKey): PublicKey {
   )
   return pda
 }
+
+// #[seeds("pot", challenge_pda("The PDA of the challenge"))]
+export function pdaForPot(challengePda: PublicKey): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('pot'), challengePda.toBuffer()],
+    PROGRAM_ID
+  )
+  return pda
+}
+
+// #[seeds("config")]
+export function pdaForProgramConfig(): PublicKey {
+  const [pda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('config')],
+    PROGRAM_ID
+  )
+  return pda
+}
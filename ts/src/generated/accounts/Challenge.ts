@@ -8,6 +8,12 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  SolutionNormalization,
+  solutionNormalizationBeet,
+} from '../types/SolutionNormalization'
+import { FeeRecipient, feeRecipientBeet } from '../types/FeeRecipient'
+import { SolutionHashing, solutionHashingBeet } from '../types/SolutionHashing'
 
 /**
  * Arguments used to create {@link Challenge}
@@ -15,14 +21,29 @@ import * as beetSolana from '@metaplex-foundation/beet-solana'
  * @category generated
  */
 export type ChallengeArgs = {
+  discriminator: number[] /* size: 8 */
   authority: web3.PublicKey
-  id: string
+  bump: number
+  potBump: number
   started: boolean
   finished: boolean
+  cancelled: boolean
+  escrow: boolean
   admitCost: beet.bignum
   triesPerAdmit: number
   redeem: web3.PublicKey
   solving: number
+  requiredAmount: beet.bignum
+  normalization: SolutionNormalization
+  soulbound: boolean
+  escrowedTries: number
+  id: string
+  admissionRoot: beet.COption<number[] /* size: 32 */>
+  requiredMint: beet.COption<web3.PublicKey>
+  feeRecipients: FeeRecipient[]
+  jackpot: beet.COption<number>
+  hookProgram: beet.COption<web3.PublicKey>
+  hashing: beet.COption<SolutionHashing>
   solutions: number[] /* size: 32 */[]
 }
 /**
@@ -34,14 +55,29 @@ export type ChallengeArgs = {
  */
 export class Challenge implements ChallengeArgs {
   private constructor(
+    readonly discriminator: number[] /* size: 8 */,
     readonly authority: web3.PublicKey,
-    readonly id: string,
+    readonly bump: number,
+    readonly potBump: number,
     readonly started: boolean,
     readonly finished: boolean,
+    readonly cancelled: boolean,
+    readonly escrow: boolean,
     readonly admitCost: beet.bignum,
     readonly triesPerAdmit: number,
     readonly redeem: web3.PublicKey,
     readonly solving: number,
+    readonly requiredAmount: beet.bignum,
+    readonly normalization: SolutionNormalization,
+    readonly soulbound: boolean,
+    readonly escrowedTries: number,
+    readonly id: string,
+    readonly admissionRoot: beet.COption<number[] /* size: 32 */>,
+    readonly requiredMint: beet.COption<web3.PublicKey>,
+    readonly feeRecipients: FeeRecipient[],
+    readonly jackpot: beet.COption<number>,
+    readonly hookProgram: beet.COption<web3.PublicKey>,
+    readonly hashing: beet.COption<SolutionHashing>,
     readonly solutions: number[] /* size: 32 */[]
   ) {}
 
@@ -50,14 +86,29 @@ export class Challenge implements ChallengeArgs {
    */
   static fromArgs(args: ChallengeArgs) {
     return new Challenge(
+      args.discriminator,
       args.authority,
-      args.id,
+      args.bump,
+      args.potBump,
       args.started,
       args.finished,
+      args.cancelled,
+      args.escrow,
       args.admitCost,
       args.triesPerAdmit,
       args.redeem,
       args.solving,
+      args.requiredAmount,
+      args.normalization,
+      args.soulbound,
+      args.escrowedTries,
+      args.id,
+      args.admissionRoot,
+      args.requiredMint,
+      args.feeRecipients,
+      args.jackpot,
+      args.hookProgram,
+      args.hashing,
       args.solutions
     )
   }
@@ -161,10 +212,14 @@ export class Challenge implements ChallengeArgs {
    */
   pretty() {
     return {
+      discriminator: this.discriminator,
       authority: this.authority.toBase58(),
-      id: this.id,
+      bump: this.bump,
+      potBump: this.potBump,
       started: this.started,
       finished: this.finished,
+      cancelled: this.cancelled,
+      escrow: this.escrow,
       admitCost: (() => {
         const x = <{ toNumber: () => number }>this.admitCost
         if (typeof x.toNumber === 'function') {
@@ -179,6 +234,27 @@ export class Challenge implements ChallengeArgs {
       triesPerAdmit: this.triesPerAdmit,
       redeem: this.redeem.toBase58(),
       solving: this.solving,
+      requiredAmount: (() => {
+        const x = <{ toNumber: () => number }>this.requiredAmount
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      normalization: this.normalization,
+      soulbound: this.soulbound,
+      escrowedTries: this.escrowedTries,
+      id: this.id,
+      admissionRoot: this.admissionRoot,
+      requiredMint: this.requiredMint,
+      feeRecipients: this.feeRecipients,
+      jackpot: this.jackpot,
+      hookProgram: this.hookProgram,
+      hashing: this.hashing,
       solutions: this.solutions,
     }
   }
@@ -193,14 +269,29 @@ export const challengeBeet = new beet.FixableBeetStruct<
   ChallengeArgs
 >(
   [
+    ['discriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['authority', beetSolana.publicKey],
-    ['id', beet.utf8String],
+    ['bump', beet.u8],
+    ['potBump', beet.u8],
     ['started', beet.bool],
     ['finished', beet.bool],
+    ['cancelled', beet.bool],
+    ['escrow', beet.bool],
     ['admitCost', beet.u64],
     ['triesPerAdmit', beet.u8],
     ['redeem', beetSolana.publicKey],
     ['solving', beet.u8],
+    ['requiredAmount', beet.u64],
+    ['normalization', solutionNormalizationBeet],
+    ['soulbound', beet.bool],
+    ['escrowedTries', beet.u32],
+    ['id', beet.utf8String],
+    ['admissionRoot', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['requiredMint', beet.coption(beetSolana.publicKey)],
+    ['feeRecipients', beet.array(feeRecipientBeet)],
+    ['jackpot', beet.coption(beet.u16)],
+    ['hookProgram', beet.coption(beetSolana.publicKey)],
+    ['hashing', beet.coption(solutionHashingBeet)],
     ['solutions', beet.array(beet.uniformFixedSizeArray(beet.u8, 32))],
   ],
   Challenge.fromArgs,
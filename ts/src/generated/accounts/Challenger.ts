@@ -6,8 +6,8 @@
  */
 
 import * as web3 from '@solana/web3.js'
-import * as beetSolana from '@metaplex-foundation/beet-solana'
 import * as beet from '@metaplex-foundation/beet'
+import * as beetSolana from '@metaplex-foundation/beet-solana'
 
 /**
  * Arguments used to create {@link Challenger}
@@ -15,10 +15,13 @@ import * as beet from '@metaplex-foundation/beet'
  * @category generated
  */
 export type ChallengerArgs = {
+  discriminator: number[] /* size: 8 */
   authority: web3.PublicKey
   challengePda: web3.PublicKey
   triesRemaining: number
   redeemed: boolean
+  solved: number[] /* size: 32 */
+  revoked: number[] /* size: 32 */
 }
 /**
  * Holds the data for the {@link Challenger} Account and provides de/serialization
@@ -29,10 +32,13 @@ export type ChallengerArgs = {
  */
 export class Challenger implements ChallengerArgs {
   private constructor(
+    readonly discriminator: number[] /* size: 8 */,
     readonly authority: web3.PublicKey,
     readonly challengePda: web3.PublicKey,
     readonly triesRemaining: number,
-    readonly redeemed: boolean
+    readonly redeemed: boolean,
+    readonly solved: number[] /* size: 32 */,
+    readonly revoked: number[] /* size: 32 */
   ) {}
 
   /**
@@ -40,10 +46,13 @@ export class Challenger implements ChallengerArgs {
    */
   static fromArgs(args: ChallengerArgs) {
     return new Challenger(
+      args.discriminator,
       args.authority,
       args.challengePda,
       args.triesRemaining,
-      args.redeemed
+      args.redeemed,
+      args.solved,
+      args.revoked
     )
   }
 
@@ -147,10 +156,13 @@ export class Challenger implements ChallengerArgs {
    */
   pretty() {
     return {
+      discriminator: this.discriminator,
       authority: this.authority.toBase58(),
       challengePda: this.challengePda.toBase58(),
       triesRemaining: this.triesRemaining,
       redeemed: this.redeemed,
+      solved: this.solved,
+      revoked: this.revoked,
     }
   }
 }
@@ -161,10 +173,13 @@ export class Challenger implements ChallengerArgs {
  */
 export const challengerBeet = new beet.BeetStruct<Challenger, ChallengerArgs>(
   [
+    ['discriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['authority', beetSolana.publicKey],
     ['challengePda', beetSolana.publicKey],
     ['triesRemaining', beet.u8],
     ['redeemed', beet.bool],
+    ['solved', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['revoked', beet.uniformFixedSizeArray(beet.u8, 32)],
   ],
   Challenger.fromArgs,
   'Challenger'
@@ -1,9 +1,19 @@
 export * from './Challenge'
 export * from './Challenger'
+export * from './Pot'
+export * from './ProgramConfig'
 export * from './Redeem'
 
 import { Challenge } from './Challenge'
 import { Challenger } from './Challenger'
+import { Pot } from './Pot'
+import { ProgramConfig } from './ProgramConfig'
 import { Redeem } from './Redeem'
 
-export const accountProviders = { Challenge, Challenger, Redeem }
+export const accountProviders = {
+  Challenge,
+  Challenger,
+  Pot,
+  ProgramConfig,
+  Redeem,
+}
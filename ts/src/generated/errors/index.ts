@@ -396,6 +396,930 @@ createErrorFromNameLookup.set(
   () => new InsufficientFundsError()
 )
 
+/**
+ * ChallengerNotAllowlisted: 'Challenger is not included in the allowlist of the challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengerNotAllowlistedError extends Error {
+  readonly code: number = 0x11c7bc
+  readonly name: string = 'ChallengerNotAllowlisted'
+  constructor() {
+    super('Challenger is not included in the allowlist of the challenge')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengerNotAllowlistedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7bc,
+  () => new ChallengerNotAllowlistedError()
+)
+createErrorFromNameLookup.set(
+  'ChallengerNotAllowlisted',
+  () => new ChallengerNotAllowlistedError()
+)
+
+/**
+ * RequiredTokenAccountMissing: 'Challenge requires challengers to hold a token, but no token account was provided'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RequiredTokenAccountMissingError extends Error {
+  readonly code: number = 0x11c7bd
+  readonly name: string = 'RequiredTokenAccountMissing'
+  constructor() {
+    super(
+      'Challenge requires challengers to hold a token, but no token account was provided'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RequiredTokenAccountMissingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7bd,
+  () => new RequiredTokenAccountMissingError()
+)
+createErrorFromNameLookup.set(
+  'RequiredTokenAccountMissing',
+  () => new RequiredTokenAccountMissingError()
+)
+
+/**
+ * ChallengerLacksRequiredToken: 'Challenger does not hold enough of the token required by the challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengerLacksRequiredTokenError extends Error {
+  readonly code: number = 0x11c7be
+  readonly name: string = 'ChallengerLacksRequiredToken'
+  constructor() {
+    super(
+      'Challenger does not hold enough of the token required by the challenge'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengerLacksRequiredTokenError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7be,
+  () => new ChallengerLacksRequiredTokenError()
+)
+createErrorFromNameLookup.set(
+  'ChallengerLacksRequiredToken',
+  () => new ChallengerLacksRequiredTokenError()
+)
+
+/**
+ * ExceedingMaxFeeRecipients: 'Amount of fee recipients exceeds maximum supported fee recipients'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceedingMaxFeeRecipientsError extends Error {
+  readonly code: number = 0x11c7bf
+  readonly name: string = 'ExceedingMaxFeeRecipients'
+  constructor() {
+    super('Amount of fee recipients exceeds maximum supported fee recipients')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceedingMaxFeeRecipientsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7bf,
+  () => new ExceedingMaxFeeRecipientsError()
+)
+createErrorFromNameLookup.set(
+  'ExceedingMaxFeeRecipients',
+  () => new ExceedingMaxFeeRecipientsError()
+)
+
+/**
+ * FeeRecipientsBasisPointsInvalid: 'The basis points of all fee recipients need to add up to 10,000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeeRecipientsBasisPointsInvalidError extends Error {
+  readonly code: number = 0x11c7c0
+  readonly name: string = 'FeeRecipientsBasisPointsInvalid'
+  constructor() {
+    super('The basis points of all fee recipients need to add up to 10,000')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeeRecipientsBasisPointsInvalidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7c0,
+  () => new FeeRecipientsBasisPointsInvalidError()
+)
+createErrorFromNameLookup.set(
+  'FeeRecipientsBasisPointsInvalid',
+  () => new FeeRecipientsBasisPointsInvalidError()
+)
+
+/**
+ * FeeRecipientMismatch: 'Provided fee recipient does not match the fee recipient of the challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FeeRecipientMismatchError extends Error {
+  readonly code: number = 0x11c7c1
+  readonly name: string = 'FeeRecipientMismatch'
+  constructor() {
+    super(
+      'Provided fee recipient does not match the fee recipient of the challenge'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FeeRecipientMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7c1, () => new FeeRecipientMismatchError())
+createErrorFromNameLookup.set(
+  'FeeRecipientMismatch',
+  () => new FeeRecipientMismatchError()
+)
+
+/**
+ * JackpotBasisPointsInvalid: 'The basis points of the jackpot share need to be between 1 and 10,000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class JackpotBasisPointsInvalidError extends Error {
+  readonly code: number = 0x11c7c2
+  readonly name: string = 'JackpotBasisPointsInvalid'
+  constructor() {
+    super(
+      'The basis points of the jackpot share need to be between 1 and 10,000'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, JackpotBasisPointsInvalidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7c2,
+  () => new JackpotBasisPointsInvalidError()
+)
+createErrorFromNameLookup.set(
+  'JackpotBasisPointsInvalid',
+  () => new JackpotBasisPointsInvalidError()
+)
+
+/**
+ * JackpotWithFeeRecipients: 'A challenge in jackpot mode cannot split the admit fee among fee recipients'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class JackpotWithFeeRecipientsError extends Error {
+  readonly code: number = 0x11c7c3
+  readonly name: string = 'JackpotWithFeeRecipients'
+  constructor() {
+    super(
+      'A challenge in jackpot mode cannot split the admit fee among fee recipients'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, JackpotWithFeeRecipientsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7c3,
+  () => new JackpotWithFeeRecipientsError()
+)
+createErrorFromNameLookup.set(
+  'JackpotWithFeeRecipients',
+  () => new JackpotWithFeeRecipientsError()
+)
+
+/**
+ * EscrowWithFeeRecipients: 'A challenge escrowing admit fees cannot split them among fee recipients'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EscrowWithFeeRecipientsError extends Error {
+  readonly code: number = 0x11c7c4
+  readonly name: string = 'EscrowWithFeeRecipients'
+  constructor() {
+    super(
+      'A challenge escrowing admit fees cannot split them among fee recipients'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EscrowWithFeeRecipientsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7c4,
+  () => new EscrowWithFeeRecipientsError()
+)
+createErrorFromNameLookup.set(
+  'EscrowWithFeeRecipients',
+  () => new EscrowWithFeeRecipientsError()
+)
+
+/**
+ * ChallengeDoesNotEscrow: 'Challenge does not escrow admit fees'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengeDoesNotEscrowError extends Error {
+  readonly code: number = 0x11c7c5
+  readonly name: string = 'ChallengeDoesNotEscrow'
+  constructor() {
+    super('Challenge does not escrow admit fees')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengeDoesNotEscrowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7c5, () => new ChallengeDoesNotEscrowError())
+createErrorFromNameLookup.set(
+  'ChallengeDoesNotEscrow',
+  () => new ChallengeDoesNotEscrowError()
+)
+
+/**
+ * ChallengeNotYetFinished: 'Challenge has not finished yet and its escrowed fees cannot be withdrawn'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengeNotYetFinishedError extends Error {
+  readonly code: number = 0x11c7c6
+  readonly name: string = 'ChallengeNotYetFinished'
+  constructor() {
+    super(
+      'Challenge has not finished yet and its escrowed fees cannot be withdrawn'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengeNotYetFinishedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7c6,
+  () => new ChallengeNotYetFinishedError()
+)
+createErrorFromNameLookup.set(
+  'ChallengeNotYetFinished',
+  () => new ChallengeNotYetFinishedError()
+)
+
+/**
+ * ChallengeCancelled: 'Challenge was cancelled and its escrowed fees are refunded to challengers'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengeCancelledError extends Error {
+  readonly code: number = 0x11c7c7
+  readonly name: string = 'ChallengeCancelled'
+  constructor() {
+    super(
+      'Challenge was cancelled and its escrowed fees are refunded to challengers'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengeCancelledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7c7, () => new ChallengeCancelledError())
+createErrorFromNameLookup.set(
+  'ChallengeCancelled',
+  () => new ChallengeCancelledError()
+)
+
+/**
+ * ChallengeNotCancelled: 'Challenge was not cancelled and thus does not refund challengers'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengeNotCancelledError extends Error {
+  readonly code: number = 0x11c7c8
+  readonly name: string = 'ChallengeNotCancelled'
+  constructor() {
+    super('Challenge was not cancelled and thus does not refund challengers')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengeNotCancelledError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7c8, () => new ChallengeNotCancelledError())
+createErrorFromNameLookup.set(
+  'ChallengeNotCancelled',
+  () => new ChallengeNotCancelledError()
+)
+
+/**
+ * NothingToRefund: 'Challenger has no unused tries to be refunded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NothingToRefundError extends Error {
+  readonly code: number = 0x11c7c9
+  readonly name: string = 'NothingToRefund'
+  constructor() {
+    super('Challenger has no unused tries to be refunded')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NothingToRefundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7c9, () => new NothingToRefundError())
+createErrorFromNameLookup.set(
+  'NothingToRefund',
+  () => new NothingToRefundError()
+)
+
+/**
+ * ProgramPaused: 'Program is paused and not processing instructions'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgramPausedError extends Error {
+  readonly code: number = 0x11c7ca
+  readonly name: string = 'ProgramPaused'
+  constructor() {
+    super('Program is paused and not processing instructions')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgramPausedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7ca, () => new ProgramPausedError())
+createErrorFromNameLookup.set('ProgramPaused', () => new ProgramPausedError())
+
+/**
+ * ProtocolFeeBasisPointsInvalid: 'Protocol fee cannot exceed 10,000 basis points'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProtocolFeeBasisPointsInvalidError extends Error {
+  readonly code: number = 0x11c7cb
+  readonly name: string = 'ProtocolFeeBasisPointsInvalid'
+  constructor() {
+    super('Protocol fee cannot exceed 10,000 basis points')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProtocolFeeBasisPointsInvalidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7cb,
+  () => new ProtocolFeeBasisPointsInvalidError()
+)
+createErrorFromNameLookup.set(
+  'ProtocolFeeBasisPointsInvalid',
+  () => new ProtocolFeeBasisPointsInvalidError()
+)
+
+/**
+ * ProgramConfigAccountMissing: 'Program config is required at its declared index, but was not provided'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgramConfigAccountMissingError extends Error {
+  readonly code: number = 0x11c7cc
+  readonly name: string = 'ProgramConfigAccountMissing'
+  constructor() {
+    super(
+      'Program config is required at its declared index, but was not provided'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgramConfigAccountMissingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7cc,
+  () => new ProgramConfigAccountMissingError()
+)
+createErrorFromNameLookup.set(
+  'ProgramConfigAccountMissing',
+  () => new ProgramConfigAccountMissingError()
+)
+
+/**
+ * IncorrectProgramId: 'Provided program id does not match this program's id'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectProgramIdError extends Error {
+  readonly code: number = 0x11c7cd
+  readonly name: string = 'IncorrectProgramId'
+  constructor() {
+    super("Provided program id does not match this program's id")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectProgramIdError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7cd, () => new IncorrectProgramIdError())
+createErrorFromNameLookup.set(
+  'IncorrectProgramId',
+  () => new IncorrectProgramIdError()
+)
+
+/**
+ * IncorrectPda: 'Provided PDA does not match the PDA derived for it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectPdaError extends Error {
+  readonly code: number = 0x11c7ce
+  readonly name: string = 'IncorrectPda'
+  constructor() {
+    super('Provided PDA does not match the PDA derived for it')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectPdaError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7ce, () => new IncorrectPdaError())
+createErrorFromNameLookup.set('IncorrectPda', () => new IncorrectPdaError())
+
+/**
+ * IncorrectRedeemMint: 'Provided redeem mint does not match the redeem mint of the challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectRedeemMintError extends Error {
+  readonly code: number = 0x11c7cf
+  readonly name: string = 'IncorrectRedeemMint'
+  constructor() {
+    super(
+      'Provided redeem mint does not match the redeem mint of the challenge'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectRedeemMintError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7cf, () => new IncorrectRedeemMintError())
+createErrorFromNameLookup.set(
+  'IncorrectRedeemMint',
+  () => new IncorrectRedeemMintError()
+)
+
+/**
+ * IncorrectAuthority: 'Provided authority does not match the authority of the account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectAuthorityError extends Error {
+  readonly code: number = 0x11c7d0
+  readonly name: string = 'IncorrectAuthority'
+  constructor() {
+    super('Provided authority does not match the authority of the account')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectAuthorityError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d0, () => new IncorrectAuthorityError())
+createErrorFromNameLookup.set(
+  'IncorrectAuthority',
+  () => new IncorrectAuthorityError()
+)
+
+/**
+ * ChallengerChallengeMismatch: 'Provided challenger belongs to a different challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengerChallengeMismatchError extends Error {
+  readonly code: number = 0x11c7d1
+  readonly name: string = 'ChallengerChallengeMismatch'
+  constructor() {
+    super('Provided challenger belongs to a different challenge')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengerChallengeMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7d1,
+  () => new ChallengerChallengeMismatchError()
+)
+createErrorFromNameLookup.set(
+  'ChallengerChallengeMismatch',
+  () => new ChallengerChallengeMismatchError()
+)
+
+/**
+ * IncorrectTreasury: 'Provided treasury does not match the treasury of the program config'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectTreasuryError extends Error {
+  readonly code: number = 0x11c7d2
+  readonly name: string = 'IncorrectTreasury'
+  constructor() {
+    super('Provided treasury does not match the treasury of the program config')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectTreasuryError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d2, () => new IncorrectTreasuryError())
+createErrorFromNameLookup.set(
+  'IncorrectTreasury',
+  () => new IncorrectTreasuryError()
+)
+
+/**
+ * IncorrectAccountOwner: 'Provided account is not owned by the expected program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectAccountOwnerError extends Error {
+  readonly code: number = 0x11c7d3
+  readonly name: string = 'IncorrectAccountOwner'
+  constructor() {
+    super('Provided account is not owned by the expected program')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectAccountOwnerError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d3, () => new IncorrectAccountOwnerError())
+createErrorFromNameLookup.set(
+  'IncorrectAccountOwner',
+  () => new IncorrectAccountOwnerError()
+)
+
+/**
+ * IncorrectTokenProgram: 'Provided token program does not match the token program of the redeem mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectTokenProgramError extends Error {
+  readonly code: number = 0x11c7d4
+  readonly name: string = 'IncorrectTokenProgram'
+  constructor() {
+    super(
+      'Provided token program does not match the token program of the redeem mint'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectTokenProgramError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d4, () => new IncorrectTokenProgramError())
+createErrorFromNameLookup.set(
+  'IncorrectTokenProgram',
+  () => new IncorrectTokenProgramError()
+)
+
+/**
+ * AccountDiscriminatorMismatch: 'Account data does not start with the expected discriminator'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountDiscriminatorMismatchError extends Error {
+  readonly code: number = 0x11c7d5
+  readonly name: string = 'AccountDiscriminatorMismatch'
+  constructor() {
+    super('Account data does not start with the expected discriminator')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountDiscriminatorMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7d5,
+  () => new AccountDiscriminatorMismatchError()
+)
+createErrorFromNameLookup.set(
+  'AccountDiscriminatorMismatch',
+  () => new AccountDiscriminatorMismatchError()
+)
+
+/**
+ * IncorrectHookProgram: 'Provided hook program does not match the hook program of the challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectHookProgramError extends Error {
+  readonly code: number = 0x11c7d6
+  readonly name: string = 'IncorrectHookProgram'
+  constructor() {
+    super(
+      'Provided hook program does not match the hook program of the challenge'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectHookProgramError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d6, () => new IncorrectHookProgramError())
+createErrorFromNameLookup.set(
+  'IncorrectHookProgram',
+  () => new IncorrectHookProgramError()
+)
+
+/**
+ * InvalidHookProgram: 'The challenge program cannot be its own hook program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidHookProgramError extends Error {
+  readonly code: number = 0x11c7d7
+  readonly name: string = 'InvalidHookProgram'
+  constructor() {
+    super('The challenge program cannot be its own hook program')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidHookProgramError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d7, () => new InvalidHookProgramError())
+createErrorFromNameLookup.set(
+  'InvalidHookProgram',
+  () => new InvalidHookProgramError()
+)
+
+/**
+ * ChallengeDataInvalid: 'Challenge account data does not match the challenge layout'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengeDataInvalidError extends Error {
+  readonly code: number = 0x11c7d8
+  readonly name: string = 'ChallengeDataInvalid'
+  constructor() {
+    super('Challenge account data does not match the challenge layout')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengeDataInvalidError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7d8, () => new ChallengeDataInvalidError())
+createErrorFromNameLookup.set(
+  'ChallengeDataInvalid',
+  () => new ChallengeDataInvalidError()
+)
+
+/**
+ * ExceedingMaxHashIterations: 'Solution hash iterations exceed the maximum supported iterations'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceedingMaxHashIterationsError extends Error {
+  readonly code: number = 0x11c7d9
+  readonly name: string = 'ExceedingMaxHashIterations'
+  constructor() {
+    super('Solution hash iterations exceed the maximum supported iterations')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceedingMaxHashIterationsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7d9,
+  () => new ExceedingMaxHashIterationsError()
+)
+createErrorFromNameLookup.set(
+  'ExceedingMaxHashIterations',
+  () => new ExceedingMaxHashIterationsError()
+)
+
+/**
+ * SolveNotFound: 'The challenger did not solve the solution to revoke'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SolveNotFoundError extends Error {
+  readonly code: number = 0x11c7da
+  readonly name: string = 'SolveNotFound'
+  constructor() {
+    super('The challenger did not solve the solution to revoke')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SolveNotFoundError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7da, () => new SolveNotFoundError())
+createErrorFromNameLookup.set('SolveNotFound', () => new SolveNotFoundError())
+
+/**
+ * SolveNotRevocable: 'The redeem mint does not have the challenge as its permanent delegate'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SolveNotRevocableError extends Error {
+  readonly code: number = 0x11c7db
+  readonly name: string = 'SolveNotRevocable'
+  constructor() {
+    super(
+      'The redeem mint does not have the challenge as its permanent delegate'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SolveNotRevocableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7db, () => new SolveNotRevocableError())
+createErrorFromNameLookup.set(
+  'SolveNotRevocable',
+  () => new SolveNotRevocableError()
+)
+
+/**
+ * CannotReopenSolution: 'Only the most recently solved solution of a challenge that was not cancelled can be reopened'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CannotReopenSolutionError extends Error {
+  readonly code: number = 0x11c7dc
+  readonly name: string = 'CannotReopenSolution'
+  constructor() {
+    super(
+      'Only the most recently solved solution of a challenge that was not cancelled can be reopened'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CannotReopenSolutionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7dc, () => new CannotReopenSolutionError())
+createErrorFromNameLookup.set(
+  'CannotReopenSolution',
+  () => new CannotReopenSolutionError()
+)
+
+/**
+ * IncorrectProgramData: 'Provided account is not the ProgramData account of this program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncorrectProgramDataError extends Error {
+  readonly code: number = 0x11c7dd
+  readonly name: string = 'IncorrectProgramData'
+  constructor() {
+    super('Provided account is not the ProgramData account of this program')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncorrectProgramDataError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7dd, () => new IncorrectProgramDataError())
+createErrorFromNameLookup.set(
+  'IncorrectProgramData',
+  () => new IncorrectProgramDataError()
+)
+
+/**
+ * NotUpgradeAuthority: 'Only the upgrade authority of the program can initialize the program config'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NotUpgradeAuthorityError extends Error {
+  readonly code: number = 0x11c7de
+  readonly name: string = 'NotUpgradeAuthority'
+  constructor() {
+    super(
+      'Only the upgrade authority of the program can initialize the program config'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NotUpgradeAuthorityError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x11c7de, () => new NotUpgradeAuthorityError())
+createErrorFromNameLookup.set(
+  'NotUpgradeAuthority',
+  () => new NotUpgradeAuthorityError()
+)
+
+/**
+ * ChallengerNeedsMigration: 'Challenger account is stored in a legacy layout and needs to be migrated first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengerNeedsMigrationError extends Error {
+  readonly code: number = 0x11c7df
+  readonly name: string = 'ChallengerNeedsMigration'
+  constructor() {
+    super(
+      'Challenger account is stored in a legacy layout and needs to be migrated first'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengerNeedsMigrationError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7df,
+  () => new ChallengerNeedsMigrationError()
+)
+createErrorFromNameLookup.set(
+  'ChallengerNeedsMigration',
+  () => new ChallengerNeedsMigrationError()
+)
+
+/**
+ * ChallengeNeedsMigration: 'Challenge account is stored in a legacy layout and needs to be migrated first'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ChallengeNeedsMigrationError extends Error {
+  readonly code: number = 0x11c7e0
+  readonly name: string = 'ChallengeNeedsMigration'
+  constructor() {
+    super(
+      'Challenge account is stored in a legacy layout and needs to be migrated first'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ChallengeNeedsMigrationError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x11c7e0,
+  () => new ChallengeNeedsMigrationError()
+)
+createErrorFromNameLookup.set(
+  'ChallengeNeedsMigration',
+  () => new ChallengeNeedsMigrationError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
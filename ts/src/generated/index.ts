@@ -2,6 +2,7 @@ import { PublicKey } from '@solana/web3.js'
 export * from './accounts'
 export * from './errors'
 export * from './instructions'
+export * from './types'
 
 /**
  * Program address
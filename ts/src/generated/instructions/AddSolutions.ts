@@ -40,6 +40,7 @@ export const AddSolutionsStruct = new beet.FixableBeetArgsStruct<
  * @property [_writable_, **signer**] payer pays for the transaction
  * @property [**signer**] creator challenge authority
  * @property [_writable_] challengePda PDA for the challenge
+ * @property [] programConfig PDA of the program config
  * @category Instructions
  * @category AddSolutions
  * @category generated
@@ -49,6 +50,7 @@ export type AddSolutionsInstructionAccounts = {
   creator: web3.PublicKey
   challengePda: web3.PublicKey
   systemProgram?: web3.PublicKey
+  programConfig: web3.PublicKey
 }
 
 export const addSolutionsInstructionDiscriminator = 1
@@ -93,6 +95,11 @@ export function createAddSolutionsInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.programConfig,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -16,13 +16,14 @@ import * as beet from '@metaplex-foundation/beet'
  */
 export type AdmitChallengerInstructionArgs = {
   challengePda: web3.PublicKey
+  admissionProof: number[] /* size: 32 */[]
 }
 /**
  * @category Instructions
  * @category AdmitChallenger
  * @category generated
  */
-export const AdmitChallengerStruct = new beet.BeetArgsStruct<
+export const AdmitChallengerStruct = new beet.FixableBeetArgsStruct<
   AdmitChallengerInstructionArgs & {
     instructionDiscriminator: number
   }
@@ -30,6 +31,7 @@ export const AdmitChallengerStruct = new beet.BeetArgsStruct<
   [
     ['instructionDiscriminator', beet.u8],
     ['challengePda', beetSolana.publicKey],
+    ['admissionProof', beet.array(beet.uniformFixedSizeArray(beet.u8, 32))],
   ],
   'AdmitChallengerInstructionArgs'
 )
@@ -38,9 +40,12 @@ export const AdmitChallengerStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_, **signer**] payer pays for the transaction
  * @property [_writable_] creator challenge authority
- * @property [] challengePda PDA for the challenge
+ * @property [_writable_] challengePda PDA for the challenge
  * @property [] challenger challenger account which receives the redeemed token
  * @property [_writable_] challengerPda PDA for the challenger
+ * @property [] challengerTokenAccount (optional) token account of the challenger holding the token required by the challenge
+ * @property [_writable_] treasury (optional) receives the protocol fee
+ * @property [] programConfig PDA of the program config
  * @category Instructions
  * @category AdmitChallenger
  * @category generated
@@ -52,6 +57,9 @@ export type AdmitChallengerInstructionAccounts = {
   challenger: web3.PublicKey
   challengerPda: web3.PublicKey
   systemProgram?: web3.PublicKey
+  challengerTokenAccount?: web3.PublicKey
+  treasury?: web3.PublicKey
+  programConfig: web3.PublicKey
 }
 
 export const admitChallengerInstructionDiscriminator = 3
@@ -88,7 +96,7 @@ export function createAdmitChallengerInstruction(
     },
     {
       pubkey: accounts.challengePda,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -106,6 +114,21 @@ export function createAdmitChallengerInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.challengerTokenAccount ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.treasury ?? programId,
+      isWritable: accounts.treasury != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.programConfig,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -9,6 +9,13 @@ import * as splToken from '@solana/spl-token'
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import { FeeRecipient, feeRecipientBeet } from '../types/FeeRecipient'
+import { SolutionHashing, solutionHashingBeet } from '../types/SolutionHashing'
+import {
+  SolutionNormalization,
+  solutionNormalizationBeet,
+} from '../types/SolutionNormalization'
+import { SoulboundRedeem, soulboundRedeemBeet } from '../types/SoulboundRedeem'
 
 /**
  * @category Instructions
@@ -21,6 +28,11 @@ export type CreateChallengeInstructionArgs = {
   triesPerAdmit: number
   redeem: web3.PublicKey
   solutions: number[] /* size: 32 */[]
+  feeRecipients: FeeRecipient[]
+  capacity: number
+  hashing: beet.COption<SolutionHashing>
+  normalization: SolutionNormalization
+  soulbound: beet.COption<SoulboundRedeem>
 }
 /**
  * @category Instructions
@@ -39,6 +51,11 @@ export const CreateChallengeStruct = new beet.FixableBeetArgsStruct<
     ['triesPerAdmit', beet.u8],
     ['redeem', beetSolana.publicKey],
     ['solutions', beet.array(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['feeRecipients', beet.array(feeRecipientBeet)],
+    ['capacity', beet.u8],
+    ['hashing', beet.coption(solutionHashingBeet)],
+    ['normalization', solutionNormalizationBeet],
+    ['soulbound', beet.coption(soulboundRedeemBeet)],
   ],
   'CreateChallengeInstructionArgs'
 )
@@ -49,6 +66,7 @@ export const CreateChallengeStruct = new beet.FixableBeetArgsStruct<
  * @property [] creator challenge authority
  * @property [_writable_] challengePda PDA for the challenge
  * @property [_writable_] redeemPda PDA of token to redeem for correct solution
+ * @property [] programConfig PDA of the program config
  * @category Instructions
  * @category CreateChallenge
  * @category generated
@@ -60,6 +78,7 @@ export type CreateChallengeInstructionAccounts = {
   redeemPda: web3.PublicKey
   tokenProgram?: web3.PublicKey
   systemProgram?: web3.PublicKey
+  programConfig: web3.PublicKey
 }
 
 export const createChallengeInstructionDiscriminator = 0
@@ -114,6 +133,11 @@ export function createCreateChallengeInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.programConfig,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
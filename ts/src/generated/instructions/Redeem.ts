@@ -38,11 +38,14 @@ export const RedeemStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_, **signer**] payer pays for the transaction
  * @property [_writable_] challengePda PDA for the challenge
- * @property [**signer**] challenger challenger account which receives the redeemed token
+ * @property [_writable_, **signer**] challenger challenger account which receives the redeemed token
  * @property [_writable_] challengerPda PDA for the challenger
  * @property [_writable_] redeem PDA of token to redeem for correct solution
  * @property [_writable_] redeemAta ATA for redeem PDA and challenger
  * @property [] associatedTokenProgram Associated Token Program
+ * @property [_writable_] pot (optional) PDA of the pot holding the admit fees of a challenge in jackpot mode
+ * @property [] hookProgram (optional) the hook program of the challenge
+ * @property [] programConfig PDA of the program config
  * @category Instructions
  * @category Redeem
  * @category generated
@@ -57,6 +60,9 @@ export type RedeemInstructionAccounts = {
   tokenProgram?: web3.PublicKey
   associatedTokenProgram: web3.PublicKey
   systemProgram?: web3.PublicKey
+  pot?: web3.PublicKey
+  hookProgram?: web3.PublicKey
+  programConfig: web3.PublicKey
 }
 
 export const redeemInstructionDiscriminator = 4
@@ -93,7 +99,7 @@ export function createRedeemInstruction(
     },
     {
       pubkey: accounts.challenger,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
@@ -126,6 +132,21 @@ export function createRedeemInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.pot ?? programId,
+      isWritable: accounts.pot != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.hookProgram ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.programConfig,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -37,6 +37,7 @@ export const StartChallengeStruct = new beet.FixableBeetArgsStruct<
  *
  * @property [**signer**] creator challenge authority
  * @property [_writable_] challengePda PDA for the challenge
+ * @property [] programConfig PDA of the program config
  * @category Instructions
  * @category StartChallenge
  * @category generated
@@ -44,6 +45,7 @@ export const StartChallengeStruct = new beet.FixableBeetArgsStruct<
 export type StartChallengeInstructionAccounts = {
   creator: web3.PublicKey
   challengePda: web3.PublicKey
+  programConfig: web3.PublicKey
 }
 
 export const startChallengeInstructionDiscriminator = 2
@@ -78,6 +80,11 @@ export function createStartChallengeInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.programConfig,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
@@ -1,5 +1,18 @@
 export * from './AddSolutions'
 export * from './AdmitChallenger'
+export * from './CancelChallenge'
+export * from './ClaimRefund'
 export * from './CreateChallenge'
+export * from './InitConfig'
+export * from './MigrateChallenge'
+export * from './MigrateChallenger'
 export * from './Redeem'
+export * from './RevokeSolve'
+export * from './SetAdmissionRoot'
+export * from './SetEscrow'
+export * from './SetHookProgram'
+export * from './SetJackpot'
+export * from './SetRequiredToken'
 export * from './StartChallenge'
+export * from './UpdateConfig'
+export * from './WithdrawEscrow'
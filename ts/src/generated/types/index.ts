@@ -0,0 +1,4 @@
+export * from './FeeRecipient'
+export * from './SolutionHashing'
+export * from './SolutionNormalization'
+export * from './SoulboundRedeem'
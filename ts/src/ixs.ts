@@ -1,6 +1,15 @@
-import { ASSOCIATED_TOKEN_PROGRAM_ID } from '@solana/spl-token'
-import { PublicKey } from '@solana/web3.js'
-import { pdaForChallenge, pdaForChallenger, pdaForRedeem } from './common/pda'
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token'
+import { AccountMeta, PublicKey, TransactionInstruction } from '@solana/web3.js'
+import {
+  pdaForChallenge,
+  pdaForChallenger,
+  pdaForPot,
+  pdaForProgramConfig,
+  pdaForRedeem,
+} from './common/pda'
 import { hashSolution, doubleHashSolutions } from './common/solution'
 import {
   AddSolutionsInstructionArgs,
@@ -15,6 +24,19 @@ import {
 } from './generated'
 import { Redeem } from './state/redeem'
 
+/**
+ * Appends accounts that are not declared by the instruction, i.e. fee
+ * recipients or the accounts passed through to a hook, after the declared
+ * ones.
+ */
+function appendRemainingAccounts(
+  ix: TransactionInstruction,
+  keys: AccountMeta[]
+) {
+  ix.keys.push(...keys)
+  return ix
+}
+
 // -----------------
 // Create Challenge
 // -----------------
@@ -35,6 +57,7 @@ export function createChallenge(
     creator,
     challengePda,
     redeemPda,
+    programConfig: pdaForProgramConfig(),
   }
   let args: CreateChallengeInstructionArgs = {
     id,
@@ -42,6 +65,16 @@ export function createChallenge(
     triesPerAdmit,
     redeem: redeemPda,
     solutions: hashedSolutions,
+    feeRecipients: [],
+    capacity: hashedSolutions.length,
+    hashing: null,
+    normalization: {
+      nfkc: false,
+      caseFold: false,
+      trimWhitespace: false,
+      collapseWhitespace: false,
+    },
+    soulbound: null,
   }
   return createCreateChallengeInstruction(accounts, args)
 }
@@ -49,6 +82,10 @@ export function createChallenge(
 // -----------------
 // Add Solutions
 // -----------------
+/**
+ * Only works for challenges without solution hashing and with the default
+ * normalization, the solutions added to any other challenge never match.
+ */
 export function addSolutions(
   payer: PublicKey,
   creator: PublicKey,
@@ -62,6 +99,7 @@ export function addSolutions(
     payer,
     creator,
     challengePda,
+    programConfig: pdaForProgramConfig(),
   }
   let args: AddSolutionsInstructionArgs = {
     id,
@@ -78,6 +116,7 @@ export function startChallenge(creator: PublicKey, id: string) {
   const accounts = {
     creator,
     challengePda,
+    programConfig: pdaForProgramConfig(),
   }
   return createStartChallengeInstruction(accounts, { id })
 }
@@ -85,11 +124,25 @@ export function startChallenge(creator: PublicKey, id: string) {
 // -----------------
 // Admit Challenger
 // -----------------
+export type AdmitChallengerOpts = {
+  /** Proof that the challenger is included in the challenge's allowlist */
+  admissionProof?: number[][]
+  /** Token account holding the token the challenge requires */
+  challengerTokenAccount?: PublicKey
+  /** Fee recipients in the order in which they are stored on the challenge */
+  feeRecipients?: PublicKey[]
+  /** Set if the challenge escrows admit fees or is in jackpot mode */
+  escrowsAdmitFees?: boolean
+  /** Receives the protocol fee if the program charges one */
+  treasury?: PublicKey
+}
+
 export function admitChallenger(
   payer: PublicKey,
   creator: PublicKey,
   challengeId: string,
-  challenger: PublicKey
+  challenger: PublicKey,
+  opts: AdmitChallengerOpts = {}
 ) {
   const challengePda = pdaForChallenge(creator, challengeId)
   const challengerPda = pdaForChallenger(challengePda, challenger)
@@ -100,23 +153,56 @@ export function admitChallenger(
     challengePda,
     challenger,
     challengerPda,
+    challengerTokenAccount: opts.challengerTokenAccount,
+    treasury: opts.treasury,
+    programConfig: pdaForProgramConfig(),
   }
-  return createAdmitChallengerInstruction(accounts, { challengePda })
+  const ix = createAdmitChallengerInstruction(accounts, {
+    challengePda,
+    admissionProof: opts.admissionProof ?? [],
+  })
+
+  // Admit fees go to the pot or the fee recipients
+  const keys: AccountMeta[] = opts.escrowsAdmitFees
+    ? [{ pubkey: pdaForPot(challengePda), isWritable: true, isSigner: false }]
+    : (opts.feeRecipients ?? []).map((pubkey) => ({
+        pubkey,
+        isWritable: true,
+        isSigner: false,
+      }))
+  return appendRemainingAccounts(ix, keys)
 }
 
 // -----------------
 // Redeem
 // -----------------
+export type RedeemOpts = {
+  /** Token-2022 if the redeem mint of the challenge is soulbound */
+  tokenProgram?: PublicKey
+  /** Set if the challenge is in jackpot mode */
+  pot?: boolean
+  /** The hook program of the challenge */
+  hookProgram?: PublicKey
+  /** The accounts passed through to the hook program */
+  hookAccounts?: AccountMeta[]
+}
+
+/**
+ * Only works for challenges without solution hashing and with the default
+ * normalization, any other challenge rejects the solution.
+ */
 export async function redeem(
   payer: PublicKey,
   creator: PublicKey,
   challengeId: string,
   challenger: PublicKey,
-  solution: string
+  solution: string,
+  opts: RedeemOpts = {}
 ) {
   const challengePda = pdaForChallenge(creator, challengeId)
   const challengerPda = pdaForChallenger(challengePda, challenger)
   const redeem = Redeem.forChallengeWith(creator, challengeId)
+  const tokenProgram = opts.tokenProgram ?? TOKEN_PROGRAM_ID
 
   const accounts: RedeemInstructionAccounts = {
     payer,
@@ -124,9 +210,14 @@ export async function redeem(
     challenger,
     challengerPda,
     redeem: redeem.pda,
-    redeemAta: await redeem.ata(challenger),
+    redeemAta: await redeem.ata(challenger, tokenProgram),
+    tokenProgram,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    pot: opts.pot ? pdaForPot(challengePda) : undefined,
+    hookProgram: opts.hookProgram,
+    programConfig: pdaForProgramConfig(),
   }
   const hashedSolution = hashSolution(solution)
-  return createRedeemInstruction(accounts, { solution: hashedSolution })
+  const ix = createRedeemInstruction(accounts, { solution: hashedSolution })
+  return appendRemainingAccounts(ix, opts.hookAccounts ?? [])
 }
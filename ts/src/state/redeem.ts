@@ -1,4 +1,7 @@
-import { getAssociatedTokenAddress } from '@solana/spl-token'
+import {
+  getAssociatedTokenAddress,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token'
 import { PublicKey } from '@solana/web3.js'
 import { pdaForChallenge, pdaForRedeem } from '../common/pda'
 import { HasPda } from '../framework/types'
@@ -24,7 +27,12 @@ export class Redeem implements HasPda {
     return pdaForRedeem(this._inner.challengePda)
   }
 
-  ata(recvr: PublicKey): Promise<PublicKey> {
-    return getAssociatedTokenAddress(this._inner.pda, recvr)
+  ata(recvr: PublicKey, tokenProgram = TOKEN_PROGRAM_ID): Promise<PublicKey> {
+    return getAssociatedTokenAddress(
+      this._inner.pda,
+      recvr,
+      false,
+      tokenProgram
+    )
   }
 }